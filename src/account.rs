@@ -0,0 +1,374 @@
+pub mod account{
+
+    use crate::database::database::TodoDatabase;
+    use crate::user::user::User;
+    use crate::user_authentication::user_authentication::AuthError;
+    use crate::line_editor::line_editor::LineEditor;
+    use crate::two_factor::two_factor;
+
+    /*
+        This enum sets the input box the user selects on the account screen
+
+        Members:
+            Username: the new username, enter changes the username
+            CurrentPassword: the password now, needed to change the password
+            NewPassword: the new password
+            ConfirmPassword: the new password again, enter changes the password
+            ForgetSessions: a button, enter forgets every remembered login
+            TwoFactor: a code from the authenticator app, enter starts, finishes or stops two-factor authentication
+            DeleteConfirm: the username typed again, enter deletes the account
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum AccountField{
+        Username,
+        CurrentPassword,
+        NewPassword,
+        ConfirmPassword,
+        ForgetSessions,
+        TwoFactor,
+        DeleteConfirm,
+    }
+
+    // every field in the order next selects them
+    const ACCOUNT_FIELDS: [AccountField; 7] = [
+        AccountField::Username,
+        AccountField::CurrentPassword,
+        AccountField::NewPassword,
+        AccountField::ConfirmPassword,
+        AccountField::ForgetSessions,
+        AccountField::TwoFactor,
+        AccountField::DeleteConfirm,
+    ];
+
+    impl AccountField{
+        /*
+            Returns: Vec<AccountField>, every field in the order next selects them
+        */
+        pub fn all() -> Vec<AccountField>{
+            ACCOUNT_FIELDS.to_vec()
+        }
+
+        /*
+            Returns: true if the field is an input box, false for a button
+        */
+        pub fn is_input(self) -> bool{
+            self != AccountField::ForgetSessions
+        }
+
+        /*
+            Returns: true if the field holds a password and is masked
+        */
+        pub fn is_password(self) -> bool{
+            matches!(self, AccountField::CurrentPassword | AccountField::NewPassword | AccountField::ConfirmPassword)
+        }
+    }
+
+    /*
+        This struct stores the input boxes of the account screen
+
+        Members:
+            username_input: LineEditor, the new username
+            current_input: LineEditor, the current password
+            password_input: LineEditor, the new password
+            confirm_input: LineEditor, the new password again
+            delete_input: LineEditor, the username typed to confirm deleting the account
+            totp_input: LineEditor, a code from the authenticator app
+            show_password: bool, the password boxes show the typed chars instead of a mask
+            sessions: u32, remembered logins of the user, counted when the screen is opened
+            two_factor: bool, the user turned on two-factor authentication
+            totp_secret: Option<String>, a new secret as base32 waiting for a code to turn two-factor authentication on
+            totp_uri: String, the otpauth URI of totp_secret for authenticator apps
+            recovery_codes: Vec<String>, the recovery codes, only shown right after two-factor authentication is turned on
+            recovery_left: u32, unused recovery codes of the user
+            message: String, feedback shown to the user
+            index: usize, the selected field in ACCOUNT_FIELDS
+    */
+    pub struct AccountForm{
+        pub username_input: LineEditor,
+        pub current_input: LineEditor,
+        pub password_input: LineEditor,
+        pub confirm_input: LineEditor,
+        pub delete_input: LineEditor,
+        pub totp_input: LineEditor,
+        pub show_password: bool,
+        pub sessions: u32,
+        pub two_factor: bool,
+        pub totp_secret: Option<String>,
+        pub totp_uri: String,
+        pub recovery_codes: Vec<String>,
+        pub recovery_left: u32,
+        pub message: String,
+        index: usize,
+    }
+
+    impl AccountForm{
+        /*
+            Returns: an AccountForm with empty input boxes and the username box selected
+        */
+        pub fn new() -> AccountForm{
+            AccountForm{
+                username_input: LineEditor::new(),
+                current_input: LineEditor::new(),
+                password_input: LineEditor::new(),
+                confirm_input: LineEditor::new(),
+                delete_input: LineEditor::new(),
+                totp_input: LineEditor::new(),
+                show_password: false,
+                sessions: 0,
+                two_factor: false,
+                totp_secret: None,
+                totp_uri: String::new(),
+                recovery_codes: Vec::new(),
+                recovery_left: 0,
+                message: String::from("Change your username or password, or delete your account"),
+                index: 0,
+            }
+        }
+
+        /*
+            Returns: AccountField, the selected input box
+        */
+        pub fn selected_field(&self) -> AccountField{
+            ACCOUNT_FIELDS[self.index]
+        }
+
+        /*
+            This method selects the input box below the current box, wrapping to the top
+        */
+        pub fn next_field(&mut self){
+            self.index = (self.index + 1) % ACCOUNT_FIELDS.len();
+            self.hide_password();
+        }
+
+        /*
+            This method selects the input box above the current box, wrapping to the bottom
+        */
+        pub fn previous_field(&mut self){
+            self.index = (self.index + ACCOUNT_FIELDS.len() - 1) % ACCOUNT_FIELDS.len();
+            self.hide_password();
+        }
+
+        /*
+            Pram: field: AccountField, the input box
+            Returns: Option<&LineEditor>, the text typed in the box, None for a button
+        */
+        pub fn input(&self, field: AccountField) -> Option<&LineEditor>{
+            match field{
+                AccountField::Username =>{Some(&self.username_input)},
+                AccountField::CurrentPassword =>{Some(&self.current_input)},
+                AccountField::NewPassword =>{Some(&self.password_input)},
+                AccountField::ConfirmPassword =>{Some(&self.confirm_input)},
+                AccountField::TwoFactor =>{Some(&self.totp_input)},
+                AccountField::DeleteConfirm =>{Some(&self.delete_input)},
+                AccountField::ForgetSessions =>{None},
+            }
+        }
+
+        /*
+            Returns: Option<&mut LineEditor>, the selected input box, None if a button is selected
+        */
+        pub fn selected_input(&mut self) -> Option<&mut LineEditor>{
+            match self.selected_field(){
+                AccountField::Username =>{Some(&mut self.username_input)},
+                AccountField::CurrentPassword =>{Some(&mut self.current_input)},
+                AccountField::NewPassword =>{Some(&mut self.password_input)},
+                AccountField::ConfirmPassword =>{Some(&mut self.confirm_input)},
+                AccountField::TwoFactor =>{Some(&mut self.totp_input)},
+                AccountField::DeleteConfirm =>{Some(&mut self.delete_input)},
+                AccountField::ForgetSessions =>{None},
+            }
+        }
+
+        /*
+            This method shows or hides the typed passwords, only while a password box is selected
+        */
+        pub fn toggle_password(&mut self){
+            if self.selected_field().is_password(){
+                self.show_password = !self.show_password;
+            }
+        }
+
+        /*
+            This method empties every input box and selects the username box
+        */
+        pub fn clear(&mut self){
+            *self = AccountForm::new();
+        }
+
+        /*
+            This method hides the passwords once a password box is no longer selected
+        */
+        fn hide_password(&mut self){
+            if !self.selected_field().is_password(){
+                self.show_password = false;
+            }
+        }
+    }
+
+    impl Default for AccountForm{
+        fn default() -> AccountForm{
+            AccountForm::new()
+        }
+    }
+
+    /*
+        This function changes the username of the user logged in
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user: &mut User, the user logged in
+            username: &str, the new username
+
+        Returns: Result<(), AuthError>
+            Ok(()): the username was changed
+            Err(AuthError): the username is empty, another account has it, or the database failed
+    */
+    pub fn change_username(database: &TodoDatabase, user: &mut User, username: &str) -> Result<(), AuthError>{
+        if username.is_empty(){
+            return Err(AuthError::EmptyInput);
+        }
+
+        // usernames are unique, the UNIQUE constraint catches another account taking it first
+        match database.update_username(user.get_user_id(), username){
+            Ok(()) =>{},
+            Err(rusqlite::Error::SqliteFailure(err, _)) if err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE =>{
+                return Err(AuthError::UsernameTaken(String::from(username)));
+            },
+            Err(err) =>{return Err(AuthError::Database(err));},
+        }
+        user.change_username(String::from(username));
+        Ok(())
+    }
+
+    /*
+        This function changes the password of the user logged in after checking the current password.
+        Every remembered login is forgotten so the old password cannot be used through them.
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user: &mut User, the user logged in
+            current: &str, the password now
+            password: &str, the new password
+            confirm: &str, the new password again
+
+        Returns: Result<(), AuthError>
+            Ok(()): the password was changed
+            Err(AuthError): a box is empty, the current password is wrong, the new passwords differ, or the database failed
+    */
+    pub fn change_password(database: &TodoDatabase, user: &mut User, current: &str, password: &str, confirm: &str) -> Result<(), AuthError>{
+        if current.is_empty() || password.is_empty(){
+            return Err(AuthError::EmptyInput);
+        }
+
+        // check against the stored password, not the copy made at login
+        let stored: Option<String> = database.get_user_by_name(&user.get_username())?.map(|user| user.password);
+        if stored.as_deref() != Some(current){
+            return Err(AuthError::WrongPassword(user.get_username()));
+        }
+
+        if password != confirm{
+            return Err(AuthError::PasswordMismatch);
+        }
+
+        database.update_password(user.get_user_id(), password)?;
+        database.remove_user_sessions(user.get_user_id())?;
+        user.change_password(String::from(password));
+        Ok(())
+    }
+
+    /*
+        This function forgets every remembered login of the user logged in, on every machine
+
+        Prams:
+            database: &TodoDatabase, the database the sessions are stored in
+            user: &User, the user logged in
+
+        Returns: Result<(), AuthError>
+            Ok(()): the sessions were forgotten
+            Err(AuthError): the database failed
+    */
+    pub fn forget_sessions(database: &TodoDatabase, user: &User) -> Result<(), AuthError>{
+        database.remove_user_sessions(user.get_user_id())?;
+        Ok(())
+    }
+
+    /*
+        This function loads whether the user logged in uses two-factor authentication into the account screen
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user: &User, the user logged in
+            form: &mut AccountForm, the account screen
+
+        Returns: Result<(), AuthError>, Err if the database failed
+    */
+    pub fn load_two_factor(database: &TodoDatabase, user: &User, form: &mut AccountForm) -> Result<(), AuthError>{
+        form.two_factor = database.get_two_factor(user.get_user_id())?.is_some();
+        form.recovery_left = database.count_recovery_codes(user.get_user_id())?;
+        Ok(())
+    }
+
+    /*
+        This function takes the next step of two-factor authentication for the user logged in.
+        When it is off the first enter shows a new secret, the next enter turns it on with a code made from the secret.
+        When it is on a code or recovery code turns it off.
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user: &User, the user logged in
+            form: &mut AccountForm, the account screen holding the new secret
+            code: &str, the code typed
+            time: u64, seconds since the unix epoch
+
+        Returns: Result<String, AuthError>
+            Ok(String): a message for the user
+            Err(AuthError): the code is wrong, or the database failed
+    */
+    pub fn update_two_factor(database: &TodoDatabase, user: &User, form: &mut AccountForm, code: &str, time: u64) -> Result<String, AuthError>{
+        if form.two_factor{
+            two_factor::disable(database, user.get_user_id(), code, time)?;
+            form.two_factor = false;
+            form.recovery_codes.clear();
+            form.recovery_left = 0;
+            return Ok(String::from("Two-factor authentication turned off"));
+        }
+
+        match form.totp_secret.clone(){
+            None =>{
+                let secret: String = two_factor::new_secret();
+                form.totp_uri = two_factor::otpauth_uri(&secret, &user.get_username())?;
+                form.totp_secret = Some(secret);
+                Ok(String::from("Add the secret to your authenticator app, then type the code it shows and press Enter"))
+            },
+            Some(secret) =>{
+                let codes: Vec<String> = two_factor::enable(database, user.get_user_id(), &secret, code, time)?;
+                form.totp_secret = None;
+                form.totp_uri.clear();
+                form.two_factor = true;
+                form.recovery_left = codes.len() as u32;
+                form.recovery_codes = codes;
+                Ok(String::from("Two-factor authentication turned on, write down the recovery codes, each one works once"))
+            },
+        }
+    }
+
+    /*
+        This function deletes the user logged in with all of their lists and items
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user: &User, the user logged in
+            confirm: &str, must be the username to delete the account
+
+        Returns: Result<(), AuthError>
+            Ok(()): the account was deleted
+            Err(AuthError): the username was not typed, or the database failed
+    */
+    pub fn delete_account(database: &TodoDatabase, user: &User, confirm: &str) -> Result<(), AuthError>{
+        if confirm != user.get_username(){
+            return Err(AuthError::DeleteNotConfirmed(user.get_username()));
+        }
+        database.remove_user(user.get_user_id())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+pub mod agenda{
+
+    use chrono::{Datelike, Duration, NaiveDate};
+    use crate::todo::todo::TodoList;
+
+    // days after today that are part of this week, today and tomorrow included
+    const WEEK_DAYS: i64 = 7;
+
+    /*
+        This enum is the group an agenda item is listed under
+
+        Members:
+            Overdue: due before today
+            Today: due today
+            Tomorrow: due tomorrow
+            ThisWeek: due in the rest of the next 7 days
+            Later: due after this week
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum AgendaGroup{
+        Overdue,
+        Today,
+        Tomorrow,
+        ThisWeek,
+        Later,
+    }
+
+    impl AgendaGroup{
+        /*
+            This method finds the group of a due date
+
+            Prams:
+                due: NaiveDate, when the item is due
+                today: NaiveDate, the current day
+
+            Returns: AgendaGroup
+        */
+        pub fn from_due(due: NaiveDate, today: NaiveDate) -> AgendaGroup{
+            match (due - today).num_days(){
+                days if days < 0 => AgendaGroup::Overdue,
+                0 => AgendaGroup::Today,
+                1 => AgendaGroup::Tomorrow,
+                days if days < WEEK_DAYS => AgendaGroup::ThisWeek,
+                _ => AgendaGroup::Later,
+            }
+        }
+
+        /*
+            Returns: the name shown above the group
+        */
+        pub fn get_name(self) -> &'static str{
+            match self{
+                AgendaGroup::Overdue => "Overdue",
+                AgendaGroup::Today => "Today",
+                AgendaGroup::Tomorrow => "Tomorrow",
+                AgendaGroup::ThisWeek => "This Week",
+                AgendaGroup::Later => "Later",
+            }
+        }
+    }
+
+    /*
+        This structure holds one item of the agenda
+
+        Members:
+            list_index: usize, index of the list in todo_lists
+            item_index: usize, index of the item in the list
+            list_name: String, name of the list the item belongs to
+            item_name: String, name of the item
+            due: NaiveDate, when the item is due
+            group: AgendaGroup, the group the item is listed under
+    */
+    pub struct AgendaEntry{
+        pub list_index: usize,
+        pub item_index: usize,
+        pub list_name: String,
+        pub item_name: String,
+        pub due: NaiveDate,
+        pub group: AgendaGroup,
+    }
+
+    /*
+        This function lists the open items with a due date of every list, soonest first
+
+        Prams:
+            todo_lists: the lists to look through
+            today: NaiveDate, the current day
+
+        Returns: Vec<AgendaEntry>, sorted by due date, ties keep list order
+    */
+    pub fn build_agenda(todo_lists: &[TodoList], today: NaiveDate) -> Vec<AgendaEntry>{
+
+        let mut entries: Vec<AgendaEntry> = Vec::new();
+        for (list_index, todo_list) in todo_lists.iter().enumerate(){
+            for (item_index, item) in todo_list.list.iter().enumerate(){
+                if let (Some(due), false) = (item.get_due(), item.get_complete()){
+                    entries.push(AgendaEntry{
+                        list_index,
+                        item_index,
+                        list_name: todo_list.get_name(),
+                        item_name: item.get_item_name(),
+                        due,
+                        group: AgendaGroup::from_due(due, today),
+                    });
+                }
+            }
+        }
+
+        // stable so items due the same day keep list order
+        entries.sort_by_key(|entry| entry.due);
+        entries
+    }
+
+    /*
+        This function lays out a month as weeks starting on Monday
+
+        Pram: NaiveDate, any day in the month
+        Returns: Vec<[Option<NaiveDate>; 7]>, one row per week, None for days of other months
+    */
+    pub fn month_weeks(day: NaiveDate) -> Vec<[Option<NaiveDate>; 7]>{
+
+        let first: NaiveDate = day.with_day(1).unwrap_or(day);
+        let mut weeks: Vec<[Option<NaiveDate>; 7]> = Vec::new();
+        let mut week: [Option<NaiveDate>; 7] = [None; 7];
+
+        let mut current: NaiveDate = first;
+        while current.month() == first.month(){
+            let weekday: usize = current.weekday().num_days_from_monday() as usize;
+            week[weekday] = Some(current);
+            // sunday ends the week
+            if weekday == 6{
+                weeks.push(week);
+                week = [None; 7];
+            }
+            current += Duration::days(1);
+        }
+        if week.iter().any(|day| day.is_some()){
+            weeks.push(week);
+        }
+
+        weeks
+    }
+
+    /*
+        This function moves a day by some months, keeping the day of the month when it exists
+
+        Prams:
+            day: NaiveDate, the day to move
+            months: i32, months to move, negative moves back
+
+        Returns: NaiveDate, clamped to the last day of the new month
+    */
+    pub fn add_months(day: NaiveDate, months: i32) -> NaiveDate{
+        let month_index: i32 = day.year() * 12 + day.month0() as i32 + months;
+        let (year, month) = (month_index.div_euclid(12), month_index.rem_euclid(12) as u32 + 1);
+
+        // the last day that exists in the new month
+        let mut day_of_month: u32 = day.day();
+        loop{
+            if let Some(date) = NaiveDate::from_ymd_opt(year, month, day_of_month){
+                return date;
+            }
+            if day_of_month <= 28{
+                return day;
+            }
+            day_of_month -= 1;
+        }
+    }
+}
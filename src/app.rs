@@ -0,0 +1,298 @@
+pub mod app{
+
+    use crossterm::event::{Event, KeyCode, MouseButton, MouseEventKind};
+    use std::io;
+    use tui::{backend::Backend, Terminal};
+    use crate::render::render_ui;
+    use crate::render_authenitcation::render_user_authentication;
+    use crate::user_authentication::user_authentication::{Authentication, AuthenticationState};
+    use crate::user::user::User;
+    use crate::app_state::app_state::{State, ActionState};
+    use crate::keymap::keymap::{Keymap, KeyPress, KeyResult, Action};
+    use crate::theme::theme::Theme;
+    use crate::event_source::event_source::EventSource;
+    use crate::database::database::TodoDatabase;
+    use crate::session::session;
+    use chrono::Local;
+    use std::path::Path;
+
+    /*
+        This function runs user authentication
+
+        Prams:
+            terminal: the terminal to display app in
+            user_authenticate: Authentication state
+            events: EventSource, where key presses are read from
+            keymap: Keymap, maps keys to actions
+            theme: Theme, the colors to draw with
+
+        Returns: Result<Ok(Option<User>), Err>
+            Ok(Option<User>): app ran successfully and option was returned, also when events run out
+            Err: app failed and there was an error 
+    */
+    pub fn run_user_authentication<B: Backend, E: EventSource>(terminal: &mut Terminal<B>, mut user_authenticate: Authentication, events: &mut E, keymap: &mut Keymap, theme: &Theme)-> io::Result<Option<User>>{
+        // continually render app
+        loop {
+            // draw widgets
+            terminal.draw(|f| render_user_authentication(f, &mut user_authenticate, theme))?;
+
+            // handel input keys, calling user_authenticate methods
+            let event: Event = match events.next_event()?{
+                Some(event) =>{event},
+                None =>{return Ok(user_authenticate.get_user())},
+            };
+            match event {
+                // any key proceeds once logged in
+                Event::Key(_) if matches!(user_authenticate.authentication_state, AuthenticationState::LoggedIn) => {
+                    return Ok(user_authenticate.get_user());
+                },
+                Event::Key(key) => {
+                    let context = user_authenticate.get_key_context();
+                    match keymap.resolve(context, KeyPress::from_event(key)){
+                        KeyResult::Action(action) => match action {
+                            Action::Quit => {return Ok(user_authenticate.get_user())},
+                            Action::Input => {user_authenticate.user_input();},
+                            Action::Back => {user_authenticate.default();},
+                            Action::Prev => {user_authenticate.previous_index();},
+                            Action::Next => {user_authenticate.next_index();},
+                            Action::Select => {user_authenticate.process_enter();},
+                            Action::RevealPassword => {user_authenticate.toggle_password();},
+                            Action::RememberMe => {user_authenticate.toggle_remember();},
+                            _ =>{user_authenticate.edit_input(action);},
+                        },
+                        // unbound chars are typed into the selected input box
+                        KeyResult::Unbound(KeyPress{code: KeyCode::Char(c), ctrl: false, alt: false}) if context.takes_text() => {
+                            user_authenticate.add_input(c);
+                        },
+                        _ =>{},
+                    }
+                },
+                Event::Paste(text) if user_authenticate.get_key_context().takes_text() => {
+                    user_authenticate.paste(&text);
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /*
+        This function runs the login page then the todo app, again after each logout,
+        until the app is quit or the events run out.
+        A remembered login in the session file skips the login page, logging out forgets it.
+
+        Prams:
+            terminal: the terminal to display app in
+            database: TodoDatabase, shared by every user that logs in
+            session_file: Option<&Path>, where a remembered login is saved, None to never remember logins
+            events: EventSource, where key presses and mouse events are read from
+            keymap: Keymap, maps keys to actions
+            theme: Theme, the colors to draw with
+
+        Returns: Result<Ok(TodoDatabase), Err>
+            Ok(TodoDatabase): app ran successfully, the database is given back
+            Err: app failed and there was an error 
+    */
+    pub fn run_sessions<B: Backend, E: EventSource>(terminal: &mut Terminal<B>, mut database: TodoDatabase, session_file: Option<&Path>, events: &mut E, keymap: &mut Keymap, theme: &Theme) -> io::Result<TodoDatabase>{
+        loop {
+            // a session file that cannot be read shows the login page
+            let remembered: Option<User> = session_file
+                .and_then(|path| session::resume_file(&database, path, Local::now().naive_local()).ok())
+                .flatten();
+
+            let user: User = match remembered{
+                Some(user) =>{user},
+                None =>{
+                    let mut authen = Authentication::new(&database);
+                    authen.session_file = session_file.map(Path::to_path_buf);
+                    match run_user_authentication(terminal, authen, events, keymap, theme)?{
+                        Some(user) =>{user},
+                        None =>{return Ok(database);},
+                    }
+                },
+            };
+
+            // the user's lists are loaded again on each login
+            let mut state = State::new(user, database);
+            run_app(terminal, &mut state, events, keymap, theme)?;
+            let logged_out: bool = state.logged_out();
+            database = state.database;
+            if !logged_out{
+                return Ok(database);
+            }
+
+            // the next user of the terminal must log in
+            if let Some(path) = session_file{
+                if let Err(err) = session::forget_file(&database, path){
+                    println!("{}", err);
+                }
+            }
+        }
+    }
+
+    /*
+        This function runs todo app
+
+        Prams:
+            terminal: the terminal to display app in
+            state: State for the todo app
+            events: EventSource, where key presses and mouse events are read from
+            keymap: Keymap, maps keys to actions
+            theme: Theme, the colors to draw with
+
+        Returns: Result<Ok(), Err>
+            Ok(): app ran successfully, also when events run out, state.logged_out() is true if the user logged out
+            Err: app failed and there was an error 
+    */
+    pub fn run_app<B: Backend, E: EventSource>(terminal: &mut Terminal<B>, state: &mut State, events: &mut E, keymap: &mut Keymap, theme: &Theme) -> io::Result<()> {
+        // continually render app
+        loop {
+            // draw widgets
+            terminal.draw(|f| render_ui(f, state, keymap, theme))?;
+
+            // handel input keys and mouse, calling state methods
+            let event: Event = match events.next_event()?{
+                Some(event) =>{event},
+                None =>{
+                    state.save_selection();
+                    return Ok(());
+                },
+            };
+            match event {
+                // any key closes the help overlay
+                Event::Key(_) if state.show_help => {
+                    keymap.clear_pending();
+                    state.toggle_help();
+                },
+                Event::Key(key) => {
+                    let context = state.get_key_context();
+                    match keymap.resolve(context, KeyPress::from_event(key)){
+                        KeyResult::Action(action) => {
+                            let quit: bool = run_app_action(state, action);
+                            if quit{
+                                // select the same list and item on the next login
+                                state.save_selection();
+                                return Ok(());
+                            }
+                        },
+                        // unbound chars are typed into the input box or search
+                        KeyResult::Unbound(KeyPress{code: KeyCode::Char(c), ctrl: false, alt: false}) if context.takes_text() => {
+                            match state.action_state{
+                                ActionState::Search => {state.add_search_input(c);},
+                                _ => {state.add_input(c);},
+                            }
+                        },
+                        _ => {},
+                    }
+                },
+                // pasted text is typed into the input box or search
+                Event::Paste(text) if state.get_key_context().takes_text() => {
+                    keymap.clear_pending();
+                    state.paste(&text);
+                },
+                Event::Mouse(mouse) => {
+                    // the mouse may change the state, drop half typed key sequences
+                    keymap.clear_pending();
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => {state.click(mouse.column, mouse.row);},
+                        MouseEventKind::ScrollDown => {state.scroll(mouse.column, mouse.row, true);},
+                        MouseEventKind::ScrollUp => {state.scroll(mouse.column, mouse.row, false);},
+                        _ => {},
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+
+    /*
+        This function runs an action in the todo app
+
+        Prams:
+            state: State for the todo app
+            action: Action, the action bound to the keys pressed
+
+        Returns: bool, true if the app should exit
+    */
+    pub fn run_app_action(state: &mut State, action: Action) -> bool {
+        let searching: bool = state.action_state == ActionState::Search;
+        let dashboard: bool = state.action_state == ActionState::Dashboard;
+        let board: bool = state.action_state == ActionState::Board;
+        let agenda: bool = state.action_state == ActionState::Agenda;
+        let account: bool = state.action_state == ActionState::Account;
+        let assigned: bool = state.action_state == ActionState::Assigned;
+        let sharing: bool = state.action_state == ActionState::Share;
+        // the message only describes the action before
+        state.message.clear();
+        match action {
+            Action::Quit => {return true;},
+            // the list shared is still selected
+            Action::Back if sharing => {state.navigate_state();},
+            Action::Back => {state.defalut_state();},
+            Action::Input => {state.capture_input_state();},
+            Action::Navigate => {state.navigate_state();},
+            Action::Search => {state.search_state();},
+            Action::Dashboard => {state.dashboard_state();},
+            Action::Board => {state.board_state();},
+            Action::Agenda => {state.agenda_state();},
+            Action::Account => {state.account_state();},
+            Action::Assigned => {state.assigned_state();},
+            Action::Assign => {state.cycle_assignee();},
+            Action::Share => {state.share_state();},
+            Action::Logout => {
+                state.logout();
+                return true;
+            },
+            Action::Next if account => {state.account.next_field();},
+            Action::Prev if account => {state.account.previous_field();},
+            // deleting the account logs the user out
+            Action::Select if account => {return state.account_select();},
+            Action::Next if searching => {state.next_search_result();},
+            Action::Next if dashboard => {state.scroll_dashboard(true);},
+            Action::Next if board => {state.move_board_card(true);},
+            Action::Next if agenda => {state.move_agenda(true);},
+            Action::Next if assigned => {state.move_assigned(true);},
+            Action::Next => {state.next_list_item();},
+            Action::Prev if searching => {state.previous_search_result();},
+            Action::Prev if dashboard => {state.scroll_dashboard(false);},
+            Action::Prev if board => {state.move_board_card(false);},
+            Action::Prev if agenda => {state.move_agenda(false);},
+            Action::Prev if assigned => {state.move_assigned(false);},
+            Action::Prev => {state.previous_list_item();},
+            Action::Switch if agenda => {state.switch_agenda_focus();},
+            Action::Switch if sharing => {state.share.toggle_access();},
+            Action::Switch => {state.left_right_key();},
+            Action::Select if searching => {state.select_search_result();},
+            Action::Select if agenda => {state.select_agenda_entry();},
+            Action::Select if assigned => {state.select_assigned_entry();},
+            Action::Select if sharing => {state.share_select();},
+            Action::Select => {state.handel_enter();},
+            Action::Add => {state.add();},
+            Action::Delete => {state.delete();},
+            Action::Toggle => {state.check_off();},
+            Action::Backspace if searching => {state.remove_search_input();},
+            Action::Backspace => {state.remove_input();},
+            Action::PageUp if agenda => {state.move_calendar_month(-1);},
+            Action::PageUp => {state.page_up();},
+            Action::PageDown if agenda => {state.move_calendar_month(1);},
+            Action::PageDown => {state.page_down();},
+            Action::First => {state.first_list_item();},
+            Action::Last => {state.last_list_item();},
+            Action::Filter => {state.cycle_filter();},
+            Action::Sort => {state.cycle_sort();},
+            Action::PriorityUp => {state.change_priority(true);},
+            Action::PriorityDown => {state.change_priority(false);},
+            Action::Help => {state.toggle_help();},
+            Action::PrevColumn if agenda => {state.move_calendar_day(-1);},
+            Action::PrevColumn => {state.change_board_column(false);},
+            Action::NextColumn if agenda => {state.move_calendar_day(1);},
+            Action::NextColumn => {state.change_board_column(true);},
+            Action::MoveLeft => {state.move_card(false);},
+            Action::MoveRight => {state.move_card(true);},
+            Action::CursorLeft | Action::CursorRight | Action::LineStart | Action::LineEnd | Action::DeleteWord | Action::DeleteChar => {state.edit_input(action);},
+            Action::RevealPassword => {state.account.toggle_password();},
+            // only used on the login screen
+            Action::RememberMe => {},
+        }
+        false
+    }
+}
@@ -2,10 +2,32 @@
 
 pub mod app_state{
 
-    use crate::todo::todo::TodoList;
+    use crate::todo::todo::{self, TodoList};
+    use crate::todo_item::todo_item::{self, TodoItem, MAX_PRIORITY};
     use crate::user::user::User;
-    use crate::database::database::TodoDatabase;
+    use crate::database::database::{TodoDatabase, QuerySearch};
+    use crate::search::search::{self, SearchResult};
+    use crate::item_view::item_view::ItemFilter;
     use rand;
+    use crate::mouse::mouse::{ScreenLayout, MouseTarget};
+    use crate::keymap::keymap::{Action, KeyContext};
+    use crate::line_editor::line_editor::LineEditor;
+    use crate::account::account::{self, AccountForm, AccountField};
+    use crate::stats::stats::Stats;
+    use crate::agenda::agenda::{self, AgendaEntry};
+    use crate::sharing::sharing::{self, ListAccess, ShareForm};
+    use crate::assignment::assignment::{self, AssignedEntry};
+    use chrono::{Local, NaiveDate};
+    use tui::widgets::ListState;
+    use std::cmp::Reverse;
+    use std::collections::HashMap;
+    use std::time::{Duration, Instant};
+
+    // above this many items search finds candidates in the database
+    const DATABASE_SEARCH_THRESHOLD: usize = 1000;
+
+    // two clicks on the same row within this time are a double click
+    const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(400);
 
     /*
         This enum sets the input the user selects
@@ -28,12 +50,26 @@ pub mod app_state{
         Members:
             CaptureInput: user can enter input to add list or item
             Navigate: user can navigate all lists and items
-            Default: user can enter CaptureInput, Navigate, or exit the app
+            Search: user can search the items of all lists
+            Dashboard: user can see the progress of all lists
+            Board: user can move the items of the selected list between columns
+            Agenda: user can see the items of all lists by due date
+            Account: user can change their username or password, or delete their account
+            Assigned: user can see the items of all lists assigned to them
+            Share: user can share the selected list with another user or stop sharing it
+            Default: user can enter CaptureInput, Navigate, Search, Dashboard, Board, Agenda, Account, Assigned, or exit the app
     */
     #[derive(PartialEq)]
     pub enum ActionState{
         CaptureInput,
         Navigate,
+        Search,
+        Dashboard,
+        Board,
+        Agenda,
+        Account,
+        Assigned,
+        Share,
         Default,
     }
     /*
@@ -58,11 +94,36 @@ pub mod app_state{
             user: User, the user logged in
             database: TodoDatabase, the databse to add, remove, and update
             todo_lists: Vec<TodoList>, all of the user's todo lists
-            input_list: String, store input for list name
-            input_item: String, store input for item name
-            list_index: usize, the list index that is currently selected 
-            item_index: usize, the item index that is currently selected
+            input_list: LineEditor, store input for list name
+            input_item: LineEditor, store input for item name
+            list_index: Option<usize>, the list index that is currently selected, None only when there are no lists
+            item_index: Option<usize>, the position of the selected item in the shown items, None only when no item is shown
+            item_cursors: HashMap<u32, usize>, the item position last selected in each list, by list_id
+            search_input: LineEditor, store input for search
+            search_results: Vec<SearchResult>, items matching search_input
+            search_index: usize, the search result that is currently selected
+            list_scroll: ListState, scroll position of the lists pane
+            item_scroll: ListState, scroll position of the items pane
+            search_scroll: ListState, scroll position of the search results
+            page_size: usize, rows moved by PageUp and PageDown, set when drawn
+            layout: ScreenLayout, where panes were last drawn, used for the mouse
+            stats: Option<Stats>, dashboard numbers, loaded when the dashboard is opened
+            stats_offset: usize, the first list shown on the dashboard
+            board_column: usize, the selected board column
+            board_index: usize, the position of the selected card in the column
+            agenda: Vec<AgendaEntry>, open items with a due date, built when the agenda is opened
+            agenda_index: usize, the selected agenda item
+            calendar_day: NaiveDate, the selected day of the calendar
+            calendar_selected: bool, up and down move the calendar instead of the agenda items
+            account: AccountForm, the input boxes of the account screen
+            assigned: Vec<AssignedEntry>, items assigned to the user, built when the view is opened
+            assigned_index: usize, the selected assigned item
+            share: ShareForm, the username and access of the share screen
+            message: String, the result of the last action, e.g. why a shared list cannot be changed
+            logged_out: bool, the user logged out or deleted their account, the lists are cleared and nothing more is saved
+            last_click: Option<(MouseTarget, Instant)>, used to find double clicks
             action_state: ActionState, action state for app
+            show_help: bool, the help overlay is drawn over the app
             input_box: InputBox, the selected input box
             selected_list: SelectedList, the selected list
 
@@ -71,14 +132,38 @@ pub mod app_state{
         pub user: User,
         pub database: TodoDatabase,
         pub todo_lists: Vec<TodoList>,
-        pub input_list: String,
-        pub input_item: String,
-        pub list_index: usize,
-        pub item_index: usize,
+        pub input_list: LineEditor,
+        pub input_item: LineEditor,
+        pub search_input: LineEditor,
+        pub search_results: Vec<SearchResult>,
+        pub search_index: usize,
+        pub list_scroll: ListState,
+        pub item_scroll: ListState,
+        pub search_scroll: ListState,
+        pub page_size: usize,
+        pub layout: ScreenLayout,
+        pub stats: Option<Stats>,
+        pub stats_offset: usize,
+        pub board_column: usize,
+        pub board_index: usize,
+        pub agenda: Vec<AgendaEntry>,
+        pub agenda_index: usize,
+        pub calendar_day: NaiveDate,
+        pub calendar_selected: bool,
         pub action_state: ActionState,
-        pub footer_meaage: String,
+        pub show_help: bool,
+        pub account: AccountForm,
+        pub assigned: Vec<AssignedEntry>,
+        pub assigned_index: usize,
+        pub share: ShareForm,
+        pub message: String,
+        logged_out: bool,
         input_box: InputBox,
         selected_list: SelectedList,
+        list_index: Option<usize>,
+        item_index: Option<usize>,
+        item_cursors: HashMap<u32, usize>,
+        last_click: Option<(MouseTarget, Instant)>,
 
     }
     impl State{
@@ -95,19 +180,45 @@ pub mod app_state{
             // load user data
             let user_data: Vec<TodoList> = database.load_user_data(user.get_user_id());
 
-            State {
+            let mut state = State {
                 user,
                 database,
                 todo_lists: user_data,
-                list_index: 0,
-                item_index: 0,
-                input_list: String::new(),
-                input_item: String::new(),
-                footer_meaage: String::from("Press 1 to enter input \nPress 2 to navigate\n Press q to exit app"),
+                list_index: None,
+                item_index: None,
+                item_cursors: HashMap::new(),
+                input_list: LineEditor::new(),
+                input_item: LineEditor::new(),
+                search_input: LineEditor::new(),
+                search_results: Vec::new(),
+                search_index: 0,
+                list_scroll: ListState::default(),
+                item_scroll: ListState::default(),
+                search_scroll: ListState::default(),
+                page_size: 10,
+                layout: ScreenLayout::default(),
+                stats: None,
+                stats_offset: 0,
+                board_column: 0,
+                board_index: 0,
+                agenda: Vec::new(),
+                agenda_index: 0,
+                calendar_day: Local::now().date_naive(),
+                calendar_selected: false,
+                last_click: None,
+                show_help: false,
+                account: AccountForm::new(),
+                assigned: Vec::new(),
+                assigned_index: 0,
+                share: ShareForm::new(),
+                message: String::new(),
+                logged_out: false,
                 selected_list: SelectedList::Default,
                 action_state: ActionState::Default,
                 input_box: InputBox::Default,
-            }
+            };
+            state.load_selection();
+            state
         }
 
         /*
@@ -132,30 +243,132 @@ pub mod app_state{
         */
         pub fn add_list(&mut self){
 
-            // get list name
-            let list_name: String = self.get_input();
+            // get list name and board columns
+            let input: String = self.get_input();
 
-            // empty string is not allowed
-            if list_name != String::from(""){
-                let mut list_id: u32;
-                loop{
-                    // generate a random list_id
-                    list_id = rand::random::<u32>();
-                    if list_id == u32::MAX {continue;}
-                    // insert into database, err if the list_id exists
-                    match self.database.insert_into_list(list_name.clone(), list_id, self.user.get_user_id()){
-                        Ok(_res)=>{break;},
-                        Err(err)=>{
-                            match err.sqlite_error_code().unwrap(){
-                                rusqlite::ErrorCode::ConstraintViolation => { continue;}
-                                _ =>{panic!("{}", err)}
-                            }
-                        },
-                    };
+            let (list_name, columns) = todo::parse_list_input(&input);
+
+            // only columns changes the columns of the selected list
+            if list_name.is_empty(){
+                if let Some(columns) = columns{
+                    self.set_list_columns(columns);
+                }
+                return;
+            }
+
+            let mut list_id: u32;
+            loop{
+                // generate a random list_id
+                list_id = rand::random::<u32>();
+                if list_id == u32::MAX {continue;}
+                // insert into database, err if the list_id exists
+                match self.database.insert_into_list(list_name.clone(), list_id, self.user.get_user_id()){
+                    Ok(_res)=>{break;},
+                    Err(err)=>{
+                        match err.sqlite_error_code().unwrap(){
+                            rusqlite::ErrorCode::ConstraintViolation => { continue;}
+                            _ =>{panic!("{}", err)}
+                        }
+                    },
+                };
+            }
+            // add the new list to todo_lists
+            self.todo_lists.push(TodoList::new(list_name, list_id));
+            if let Some(columns) = columns{
+                self.select_list(self.todo_lists.len() - 1);
+                self.set_list_columns(columns);
+            }
+            self.fix_selection();
+        }
+
+        /*
+            This method shares the selected list with another user or stops sharing it
+
+            Prams
+                command: &ShareCommand, who to share with and how
+        */
+        fn share_list(&mut self, command: &sharing::ShareCommand){
+            let todo_list: &TodoList = match self.current_list(){
+                Some(todo_list) =>{todo_list},
+                None =>{
+                    self.message = String::from("Select a list to share");
+                    return;
+                },
+            };
+            self.message = match sharing::share_list(&self.database, &self.user, todo_list, command){
+                Ok(message) =>{message},
+                Err(err) =>{err.to_string()},
+            };
+            self.refresh_members();
+        }
+
+        /*
+            This method loads the users with access to each list again,
+            after a list is shared or a username changes
+        */
+        fn refresh_members(&mut self){
+            for todo_list in self.todo_lists.iter_mut(){
+                todo_list.set_members(self.database.get_list_members(todo_list.get_list_id()).unwrap_or_default());
+            }
+        }
+
+        /*
+            This method checks that the items of a list can be changed,
+            if not the reason is shown to the user
+
+            Prams
+                list_index: usize, the list to change
+
+            Returns: bool, true if the user can add, remove or change items of the list
+        */
+        fn can_write(&mut self, list_index: usize) -> bool{
+            let todo_list: &TodoList = &self.todo_lists[list_index];
+            if todo_list.can_write(){
+                return true;
+            }
+            self.message = format!("{} is shared read only by {}", todo_list.get_name(), todo_list.get_owner().unwrap_or_default());
+            false
+        }
+
+        /*
+            This method sets the board columns of the selected list and stores
+            every item that had to move to a new column
+
+            Prams
+                columns: Vec<String>, the column names, the last is the done column
+        */
+        fn set_list_columns(&mut self, columns: Vec<String>){
+
+            let user_id: u32 = self.user.get_user_id();
+            let todo_list: &mut TodoList = match self.list_index.and_then(|index| self.todo_lists.get_mut(index)){
+                Some(todo_list) =>{todo_list},
+                None =>{return;},
+            };
+
+            // only the owner changes the columns
+            if todo_list.get_access() != ListAccess::Owner{
+                self.message = format!("Only the owner can change the columns of {}", todo_list.get_title());
+                return;
+            }
+
+            let before: Vec<u32> = todo_list.list.iter().map(|item| item.get_status()).collect();
+            if !todo_list.set_columns(columns){
+                return;
+            }
+
+            let list_id: u32 = todo_list.get_list_id();
+            if let Err(err) = self.database.update_list_columns(list_id, user_id, todo_list.get_columns()){
+                println!("{}", err);
+            }
+            for (item, status) in todo_list.list.iter().zip(before){
+                if item.get_status() != status{
+                    if let Err(err) = self.database.update_item_status(item.get_item_id(), list_id, item.get_status(), item.get_complete() as u32){
+                        println!("{}", err);
+                    }
                 }
-                // add the new list to todo_lists
-                self.todo_lists.push(TodoList::new(list_name, list_id));  
             }
+            self.board_column = self.board_column.min(todo_list.get_columns().len() - 1);
+            self.board_index = 0;
         }
 
         /*
@@ -163,14 +376,24 @@ pub mod app_state{
         */
         pub fn add_item(&mut self){
 
-            //get item name
-            let item_name: String = self.get_input();
+            //get item name, due date and priority
+            let input: String = self.get_input();
+            let (item_name, due, priority) = todo_item::parse_item_input(&input);
+
+            // there must be a list to add to
+            let list_index: usize = match self.list_index{
+                Some(index) =>{index},
+                None =>{return;},
+            };
+            if !self.can_write(list_index){
+                return;
+            }
 
             // empty string is not allowed
             if item_name != String::from(""){
                 let mut item_id: u32;
                 // get the current list_id
-                let list_id = self.todo_lists[self.list_index].get_list_id();
+                let list_id = self.todo_lists[list_index].get_list_id();
 
                 loop{
                     // generate random item_id 
@@ -178,7 +401,15 @@ pub mod app_state{
                     if item_id == u32::MAX {continue;}
                     // insert item into databse, err if the item_id already exists
                     match self.database.insert_into_items(item_name.clone(), item_id, list_id, 0){
-                        Ok(_res)=>{break;},
+                        Ok(_res)=>{
+                            // store due date and priority
+                            if due.is_some() || priority > 0{
+                                if let Err(err) = self.database.update_item_details(item_id, list_id, due, priority){
+                                    println!("{}", err);
+                                }
+                            }
+                            break;
+                        },
                         Err(err) =>{
                             match err.sqlite_error_code().unwrap(){
                                 rusqlite::ErrorCode::ConstraintViolation => { continue;}
@@ -188,7 +419,11 @@ pub mod app_state{
                     };
                 }
                 // add the item to the current list
-                self.todo_lists[self.list_index].add(item_name, item_id);
+                let mut item = TodoItem::new(item_name, item_id);
+                item.set_due(due);
+                item.set_priority(priority);
+                self.todo_lists[list_index].list.push(item);
+                self.fix_selection();
             }
         }
 
@@ -214,16 +449,30 @@ pub mod app_state{
         */
         fn delete_list(&mut self){
 
-            let n: usize = self.todo_lists.len();
-
-            // Must be at lease 1 item in todo_lists
-            if n > 0{
-                let list_id = self.todo_lists[self.list_index].get_list_id();
+            // Must be a selected list in todo_lists
+            if let Some(list_index) = self.list_index{
+                let list_id = self.todo_lists[list_index].get_list_id();
                 let user_id = self.user.get_user_id();
+
+                // a shared list is only removed for this user, the owner keeps it
+                if self.todo_lists[list_index].get_access() != ListAccess::Owner{
+                    match self.database.unshare_list(list_id, user_id){
+                        Ok(()) =>{
+                            self.message = format!("Left {}", self.todo_lists[list_index].get_title());
+                            self.todo_lists.remove(list_index);
+                            self.item_cursors.remove(&list_id);
+                        },
+                        Err(err) =>{self.message = err.to_string();},
+                    };
+                    self.restore_cursor();
+                    return;
+                }
+
                 // delete all list items from current list
-                let remove_res = self.todo_lists[self.list_index].delete_list_items();
+                let remove_res = self.todo_lists[list_index].delete_list_items();
                 // remove the list from todo_lists
-                self.todo_lists.remove(self.list_index);
+                self.todo_lists.remove(list_index);
+                self.item_cursors.remove(&list_id);
 
                 // Remove list from databse
                 if remove_res{
@@ -234,9 +483,7 @@ pub mod app_state{
                 }
 
                 // set previous if last index in todo_lists removed
-                if n-1 > 0 && n-1 == self.list_index{
-                    self.previous_list_item();
-                } 
+                self.restore_cursor();
             }
         }
 
@@ -245,27 +492,31 @@ pub mod app_state{
         */
         fn delete_item(&mut self){
 
-            let n: usize = self.todo_lists[self.list_index].get_list_len();
-            // Must be at least One time in the list
-            if n > 0{
-                let list_id = self.todo_lists[self.list_index].get_list_id();
-                let item_id = self.todo_lists[self.list_index].get_item_id(self.item_index);
-                // remove item from list
-                let remove_res = self.todo_lists[self.list_index].remove_index(self.item_index);
+            // Must be at least One shown item in the list
+            let (list_index, index) = match self.selected_indexes(){
+                Some(indexes) =>{indexes},
+                None =>{return;},
+            };
 
-                // Remove item from database
-                if remove_res{
-                    match self.database.remove_item(item_id, list_id){
-                        Ok(()) =>{},
-                        Err(err) =>{println!("{}", err)},
-                    };
-                }
+            if !self.can_write(list_index){
+                return;
+            }
+
+            let list_id = self.todo_lists[list_index].get_list_id();
+            let item_id = self.todo_lists[list_index].get_item_id(index);
+            // remove item from list
+            let remove_res = self.todo_lists[list_index].remove_index(index);
 
-                // set previous if last index in list removed
-                if n-1 > 0 && n-1 == self.item_index{
-                    self.previous_list_item();
-                } 
+            // Remove item from database
+            if remove_res{
+                match self.database.remove_item(item_id, list_id){
+                    Ok(()) =>{},
+                    Err(err) =>{println!("{}", err)},
+                };
             }
+
+            // set previous if last index in list removed
+            self.fix_selection();
         }
 
         /*
@@ -275,26 +526,30 @@ pub mod app_state{
             // Only allowed on SelectedList::Items
             if self.selected_list == SelectedList::Items{
 
+                let (list_index, index) = match self.selected_indexes(){
+                    Some(indexes) =>{indexes},
+                    None =>{return;},
+                };
+                if !self.can_write(list_index){
+                    return;
+                }
+
                 // set item complete
-                self.todo_lists[self.list_index].set_item_complete(self.item_index);
+                self.todo_lists[list_index].set_item_complete(index);
 
-                let item_id = self.todo_lists[self.list_index].get_item_id(self.item_index);
-                let list_id = self.todo_lists[self.list_index].get_list_id();
+                let item_id = self.todo_lists[list_index].get_item_id(index);
+                let list_id = self.todo_lists[list_index].get_list_id();
 
-                // update item complete with date and time
-                if self.todo_lists[self.list_index].get_item_complete_status(self.item_index){
-                    match self.database.update_item(item_id, list_id, 1){
-                        Ok(()) =>{},
-                        Err(err) =>{println!("{}", err)},
-                    };
-                }
-                else{
-                    // item was marked not complete
-                    match self.database.update_item(item_id, list_id, 0){
-                        Ok(()) =>{},
-                        Err(err) =>{println!("{}", err)},
-                    };
-                }
+                // the item may now be hidden by the filter
+                self.fix_selection();
+
+                // update item complete and board column
+                let complete: u32 = self.todo_lists[list_index].get_item_complete_status(index) as u32;
+                let status: u32 = self.todo_lists[list_index].list[index].get_status();
+                match self.database.update_item_status(item_id, list_id, status, complete){
+                    Ok(()) =>{},
+                    Err(err) =>{println!("{}", err)},
+                };
             }
         }
 
@@ -302,11 +557,11 @@ pub mod app_state{
             This method gets the input from input boxes
         */
         pub fn get_input(&mut self) -> String{
-            // if InputBox::AddList drain input_list
-            // if InputBox::AddItem drain input_item
+            // if InputBox::AddList take input_list
+            // if InputBox::AddItem take input_item
             match self.input_box{
-                InputBox::AddList =>{return self.input_list.drain(..).collect();},
-                InputBox::AddItem =>{return self.input_item.drain(..).collect();},
+                InputBox::AddList =>{return self.input_list.take();},
+                InputBox::AddItem =>{return self.input_item.take();},
                 _ => { return String::from("");},
             } 
         }
@@ -318,9 +573,12 @@ pub mod app_state{
             self.selected_list = SelectedList::Default;
             self.input_box = InputBox::Default; 
             self.action_state =  ActionState::Default;
-            self.input_list.drain(..);
-            self.input_item.drain(..);
-            self.footer_meaage = String::from("Press 1 to enter input. \nPress 2 to navigate.\n Press q to exit app.");
+            self.input_list.take();
+            self.input_item.take();
+            self.clear_search();
+            self.stats = None;
+            self.account.clear();
+            self.share.clear();
         }
 
         /*
@@ -330,7 +588,6 @@ pub mod app_state{
             self.selected_list = SelectedList::Default;
             self.input_box = InputBox::AddList; 
             self.action_state =  ActionState::CaptureInput;
-            self.footer_meaage = String::from("Press right or left arrow key to choose input box. \nPress enter keys and press enter to add list or item. \n Press esc return to default.");
         }
 
         /*
@@ -340,205 +597,1262 @@ pub mod app_state{
             self.selected_list = SelectedList::List;
             self.input_box = InputBox::Default; 
             self.action_state =  ActionState::Navigate;
-            self.footer_meaage = String::from("Press arrow keys to navigate list \nPress enter to cross off an item. \nPress backspace/delete to remove an item or list. \nPress esc return to default.");
         }
 
         /*
-            This method handels the arrow key input from user
+            This method sets the search state for the app
         */
-        pub fn left_right_key(&mut self){
-            match self.action_state{
-                ActionState::Navigate=>{
-                    match self.selected_list{
-                        // toggle between Items and List
-                        SelectedList::List =>{self.selected_list = SelectedList::Items},
-                        SelectedList::Items => {self.selected_list = SelectedList::List},
-                        _ =>{},
-                    }
-                },
-                ActionState::CaptureInput=>{
-                    match self.input_box{
-                        // toggle between AddItem and AddList
-                        InputBox::AddList =>{self.input_box = InputBox::AddItem},
-                        InputBox::AddItem=> {self.input_box = InputBox::AddList},
-                        _ =>{},
-                    }
-                },
-                _ =>{},
-            }
+        pub fn search_state(&mut self){
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Search;
+            self.clear_search();
         }
 
         /*
-            This method handels adding input from the user
+            This method sets the dashboard state for the app and queries the statistics
+        */
+        pub fn dashboard_state(&mut self){
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Dashboard;
+            self.stats_offset = 0;
+            self.stats = Stats::load(&self.database, self.user.get_user_id(), Local::now().date_naive()).ok();
+        }
+
+        /*
+            This method scrolls the lists shown on the dashboard
 
             Prams
-                c: char that user enters
+                down: bool, true to scroll down, false to scroll up
         */
-        pub fn add_input(&mut self, c: char){
-            // if InputBox::AddList push to input_list
-            // if InputBox::AddItem push to input_item
-            match self.input_box{
-                InputBox::AddList =>{self.input_list.push(c);},
-                InputBox::AddItem =>{self.input_item.push(c);},
-                _ =>{},
+        pub fn scroll_dashboard(&mut self, down: bool){
+            let n: usize = self.stats.as_ref().map_or(0, |stats| stats.lists.len());
+            if down && self.stats_offset + 1 < n{
+                self.stats_offset += 1;
+            }
+            else if !down && self.stats_offset > 0{
+                self.stats_offset -= 1;
             }
         }
 
         /*
-            This method handesl delete/backspace when input box is selected
+            This method sets the board state for the app, showing the selected list as columns
         */
-        pub fn remove_input(&mut self){
-            // if InputBox::AddList then pop from input_list
-            // if InputBox::AddItem then pop from input_item
-            match self.input_box{
-                InputBox::AddList =>{self.input_list.pop();},
-                InputBox::AddItem =>{self.input_item.pop();},
-                _ =>{},
-            } 
+        pub fn board_state(&mut self){
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Board;
+            self.board_column = 0;
+            self.board_index = 0;
         }
 
         /*
-            This method is used to check if the input box is InputBox::AddList
+            This method finds the cards of the selected board column
 
-            Returns: true if InputBox::AddList is selected, false otherwise
+            Returns: Vec<usize>, indexes into the selected list, empty if there is no list
         */
-        pub fn input_box_list(&self) -> bool{
-            if self.action_state == ActionState::CaptureInput && self.input_box == InputBox::AddList{
-                return true;
+        pub fn board_cards(&self) -> Vec<usize>{
+            match self.current_list(){
+                Some(todo_list) =>{todo_list.column_indexes(self.board_column as u32)},
+                None =>{Vec::new()},
             }
-            return false;
         }
 
         /*
-            This method is used to check if the input box is InputBox::AddItem
+            This method selects the next or previous card in the board column
 
-            Returns: true if InputBox::AddItem is selected, false otherwise
+            Prams
+                down: bool, true for the next card, false for the previous
         */
-        pub fn input_box_item(&self) -> bool{
-
-            if self.action_state == ActionState::CaptureInput && self.input_box == InputBox::AddItem{
-                return true;
+        pub fn move_board_card(&mut self, down: bool){
+            let n: usize = self.board_cards().len();
+            if down && self.board_index + 1 < n{
+                self.board_index += 1;
+            }
+            else if !down && self.board_index > 0{
+                self.board_index -= 1;
             }
-            return false;
         }
 
         /*
-            This method is used to check if the SelectedList is SelectedList::List
+            This method selects the board column on the left or right
 
-            Returns: true if SelectedList::List is selected, false otherwise
+            Prams
+                right: bool, true for the column on the right, false for the left
         */
-        pub fn list_selected(&self) -> bool{
-            if self.action_state == ActionState::Navigate && self.selected_list == SelectedList::List{
-                return true;
+        pub fn change_board_column(&mut self, right: bool){
+            let n: usize = self.current_list().map_or(0, |todo_list| todo_list.get_columns().len());
+            if right && self.board_column + 1 < n{
+                self.board_column += 1;
             }
-            return false;
+            else if !right && self.board_column > 0{
+                self.board_column -= 1;
+            }
+            self.board_index = self.board_index.min(self.board_cards().len().saturating_sub(1));
         }
 
         /*
-            This method is used to check if the SelectedList is SelectedList::Items
+            This method moves the selected card to the column on the left or right,
+            the selection follows the card
 
-            Returns: true if SelectedList::Items is selected, false otherwise
+            Prams
+                right: bool, true to move right, false to move left
         */
-        pub fn item_selected(&self) -> bool{
-            if self.action_state == ActionState::Navigate && self.selected_list == SelectedList::Items{
-                return true;
+        pub fn move_card(&mut self, right: bool){
+
+            let index: usize = match self.board_cards().get(self.board_index){
+                Some(index) =>{*index},
+                None =>{return;},
+            };
+            let status: u32 = self.board_column as u32;
+            let status: u32 = if right {status + 1} else if status > 0 {status - 1} else {return;};
+            let list_index: usize = match self.list_index{
+                Some(list_index) =>{list_index},
+                None =>{return;},
+            };
+            if !self.can_write(list_index){
+                return;
             }
-            return false;
+
+            let todo_list: &mut TodoList = &mut self.todo_lists[list_index];
+            if !todo_list.move_item(index, status){
+                return;
+            }
+
+            let item_id: u32 = todo_list.get_item_id(index);
+            let list_id: u32 = todo_list.get_list_id();
+            let complete: u32 = todo_list.get_item_complete_status(index) as u32;
+            match self.database.update_item_status(item_id, list_id, status, complete){
+                Ok(()) =>{},
+                Err(err) =>{println!("{}", err)},
+            };
+
+            // follow the card
+            self.board_column = status as usize;
+            self.board_index = self.board_cards().iter().position(|i| *i == index).unwrap_or(0);
+            self.fix_selection();
         }
 
         /*
-            This method sets the item_index or list_index to the one below the current
+            This method sets the agenda state for the app and lists the items with a due date
         */
-        pub fn next_list_item(&mut self){
-            match self.selected_list{
-                SelectedList::List =>{
-                    if self.list_index < self.todo_lists.len()-1{
-                        self.list_index +=1;
-                    }
-                    else{
-                        self.list_index = 0;
-                    }
-                },
-                SelectedList::Items =>{
-                    if self.item_index < self.todo_lists[self.list_index].get_list_len() - 1{
-                        self.item_index += 1;
-                    }
-                    else{
-                        self.item_index = 0;
-                    }
-                },
-                _ => {},
+        pub fn agenda_state(&mut self){
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Agenda;
+            let today: NaiveDate = Local::now().date_naive();
+            self.agenda = agenda::build_agenda(&self.todo_lists, today);
+            self.agenda_index = 0;
+            self.calendar_selected = false;
+            self.calendar_day = self.agenda.first().map_or(today, |entry| entry.due);
+        }
+
+        /*
+            This method moves the agenda selection, or the calendar a week when it is selected
 
+            Prams
+                down: bool, true for the next item or week, false for the previous
+        */
+        pub fn move_agenda(&mut self, down: bool){
+            if self.calendar_selected{
+                self.move_calendar_day(if down {7} else {-7});
+                return;
+            }
+            if down && self.agenda_index + 1 < self.agenda.len(){
+                self.agenda_index += 1;
+            }
+            else if !down && self.agenda_index > 0{
+                self.agenda_index -= 1;
+            }
+            // the calendar shows the day of the item
+            if let Some(entry) = self.agenda.get(self.agenda_index){
+                self.calendar_day = entry.due;
             }
         }
 
         /*
-            This method sets the item_index or list_index to the one above the current
+            This method moves the selected calendar day, the agenda selects
+            the first item due on or after it
+
+            Prams
+                days: i64, days to move, negative moves back
         */
-        pub fn previous_list_item(&mut self){
-            match self.selected_list{
-                SelectedList::List =>{
-                    if self.list_index > 0{
-                        self.list_index -= 1;
-                    }
-                    else{
-                        self.list_index = self.todo_lists.len() - 1;
-                    }
-                },
-                SelectedList::Items =>{
-                    if self.item_index > 0{
-                        self.item_index -= 1;
-                    }
-                    else{
-                        self.item_index = self.todo_lists[self.list_index].get_list_len() - 1;
-                    }
-                    
-                },
-                _ => {},
+        pub fn move_calendar_day(&mut self, days: i64){
+            self.calendar_day += chrono::Duration::days(days);
+            self.select_agenda_day();
+        }
+
+        /*
+            This method moves the selected calendar day by months
+
+            Prams
+                months: i32, months to move, negative moves back
+        */
+        pub fn move_calendar_month(&mut self, months: i32){
+            self.calendar_day = agenda::add_months(self.calendar_day, months);
+            self.select_agenda_day();
+        }
+
+        /*
+            This method selects the first agenda item due on or after the calendar day
+        */
+        fn select_agenda_day(&mut self){
+            self.agenda_index = self.agenda
+                .iter()
+                .position(|entry| entry.due >= self.calendar_day)
+                .unwrap_or(self.agenda.len().saturating_sub(1));
+        }
+
+        /*
+            This method switches up and down between the agenda items and the calendar
+        */
+        pub fn switch_agenda_focus(&mut self){
+            self.calendar_selected = !self.calendar_selected;
+        }
+
+        /*
+            This method jumps to the selected agenda item in its list
+        */
+        pub fn select_agenda_entry(&mut self){
+            if let Some(entry) = self.agenda.get(self.agenda_index){
+                let (list_index, item_index) = (entry.list_index, entry.item_index);
+                self.jump_to_item(list_index, item_index);
             }
         }
 
         /*
-            This method get the username of the current user
+            This method sets the my assigned items state for the app and lists the items assigned to the user
+        */
+        pub fn assigned_state(&mut self){
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Assigned;
+            self.assigned = assignment::build_assigned(&self.todo_lists, self.user.get_user_id());
+            self.assigned_index = 0;
+        }
 
-            Returns: String, username
+        /*
+            This method selects the next or previous assigned item
+
+            Prams
+                down: bool, true for the next item, false for the previous
         */
-        pub fn get_username(&self)->String{
-            return self.user.get_username();
+        pub fn move_assigned(&mut self, down: bool){
+            if down && self.assigned_index + 1 < self.assigned.len(){
+                self.assigned_index += 1;
+            }
+            else if !down && self.assigned_index > 0{
+                self.assigned_index -= 1;
+            }
         }
 
         /*
-            This method get the current action_state
+            This method jumps to the selected assigned item in its list
+        */
+        pub fn select_assigned_entry(&mut self){
+            if let Some(entry) = self.assigned.get(self.assigned_index){
+                let (list_index, item_index) = (entry.list_index, entry.item_index);
+                self.jump_to_item(list_index, item_index);
+            }
+        }
 
-            Returns: String, actions_state
+        /*
+            This method assigns the selected item to the next user with access to the list,
+            after the last user the item is unassigned
         */
-        pub fn get_mode(&self)->String{
-            match self.action_state{
-                ActionState::CaptureInput=>{
-                    return String::from("Input");
+        pub fn cycle_assignee(&mut self){
+            if self.selected_list != SelectedList::Items{
+                return;
+            }
+            let (list_index, index) = match self.selected_indexes(){
+                Some(indexes) =>{indexes},
+                None =>{return;},
+            };
+            if !self.can_write(list_index){
+                return;
+            }
+
+            let todo_list: &mut TodoList = &mut self.todo_lists[list_index];
+            let next = assignment::next_assignee(todo_list.get_members(), todo_list.list[index].get_assignee()).cloned();
+            let assignee: Option<u32> = next.as_ref().map(|member| member.user_id);
+            todo_list.list[index].set_assignee(assignee);
+
+            let item_id: u32 = todo_list.get_item_id(index);
+            let list_id: u32 = todo_list.get_list_id();
+            let item_name: String = todo_list.list[index].get_item_name();
+            self.message = match self.database.update_item_assignee(item_id, list_id, assignee){
+                Ok(()) =>{
+                    match next{
+                        Some(member) =>{format!("Assigned {} to {}", item_name, member.username)},
+                        None =>{format!("Unassigned {}", item_name)},
+                    }
                 },
-                ActionState::Navigate =>{
-                    return String::from("Navigation");
+                Err(err) =>{err.to_string()},
+            };
+        }
+
+        /*
+            This method sets the share state for the app, the selected list is shared.
+            Only the owner of a list can share it.
+        */
+        pub fn share_state(&mut self){
+            let todo_list: &TodoList = match self.current_list(){
+                Some(todo_list) =>{todo_list},
+                None =>{
+                    self.message = String::from("Select a list to share");
+                    return;
                 },
-                ActionState::Default=>{
-                    return String::from("Default");
+            };
+            if todo_list.get_access() != ListAccess::Owner{
+                self.message = sharing::ShareError::NotOwner(todo_list.get_name()).to_string();
+                return;
+            }
+
+            let list_id: u32 = todo_list.get_list_id();
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Share;
+            self.share.clear();
+            self.load_shares(list_id);
+        }
+
+        /*
+            This method shares the selected list with the typed username, or stops sharing it
+        */
+        pub fn share_select(&mut self){
+            let command: sharing::ShareCommand = match self.share.take_command(){
+                Some(command) =>{command},
+                None =>{
+                    self.message = String::from("Type the username to share with");
+                    return;
                 },
+            };
+            self.share_list(&command);
+            if let Some(list_id) = self.current_list().map(|todo_list| todo_list.get_list_id()){
+                self.load_shares(list_id);
             }
         }
 
         /*
-           This method returns the name of the current list
+            This method loads the users a list is shared with for the share screen
 
-           Returns: String, current list name
+            Prams
+                list_id: u32, the list shown on the share screen
         */
-        pub fn get_list_name(&self) -> String{
-            if self.todo_lists.len() > 0{
-                return self.todo_lists[self.list_index].get_name();
-            }
+        fn load_shares(&mut self, list_id: u32){
+            self.share.shares = self.database
+                .get_list_shares(list_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|share| (share.username, ListAccess::from_code(share.can_write)))
+                .collect();
+        }
+
+        /*
+            This method sets the account state for the app
+        */
+        pub fn account_state(&mut self){
+            self.selected_list = SelectedList::Default;
+            self.input_box = InputBox::Default;
+            self.action_state = ActionState::Account;
+            self.account.clear();
+            self.account.sessions = self.database.count_sessions(self.user.get_user_id(), Local::now().naive_local()).unwrap_or(0);
+            if let Err(err) = account::load_two_factor(&self.database, &self.user, &mut self.account){
+                self.account.message = err.to_string();
+            }
+        }
+
+        /*
+            This method handles enter on the account screen.
+            The username box changes the username, the password boxes change the password,
+            the forget button forgets remembered logins, the two-factor box turns two-factor authentication on or off,
+            and the delete box deletes the account.
+
+            Returns: bool, true if the account was deleted and the user is logged out
+        */
+        pub fn account_select(&mut self) -> bool{
+            let field: AccountField = self.account.selected_field();
+            let result = match field{
+                AccountField::Username =>{
+                    let username: String = self.account.username_input.take();
+                    let result = account::change_username(&self.database, &mut self.user, &username)
+                        .map(|()| format!("Username changed to {}", username));
+                    // the initials of assigned items use the new username
+                    self.refresh_members();
+                    result
+                },
+                AccountField::ForgetSessions =>{
+                    let sessions: u32 = self.account.sessions;
+                    account::forget_sessions(&self.database, &self.user)
+                        .map(|()| format!("Forgot {} remembered logins", sessions))
+                },
+                AccountField::TwoFactor =>{
+                    let code: String = self.account.totp_input.take();
+                    account::update_two_factor(&self.database, &self.user, &mut self.account, &code, Local::now().timestamp() as u64)
+                },
+                AccountField::DeleteConfirm =>{
+                    let confirm: String = self.account.delete_input.take();
+                    account::delete_account(&self.database, &self.user, &confirm)
+                        .map(|()| String::from("Account deleted"))
+                },
+                _ =>{
+                    let current: String = self.account.current_input.take();
+                    let password: String = self.account.password_input.take();
+                    let confirm: String = self.account.confirm_input.take();
+                    self.account.show_password = false;
+                    account::change_password(&self.database, &mut self.user, &current, &password, &confirm)
+                        .map(|()| String::from("Password changed"))
+                },
+            };
+
+            // changing the password also forgets remembered logins
+            self.account.sessions = self.database.count_sessions(self.user.get_user_id(), Local::now().naive_local()).unwrap_or(0);
+            match result{
+                Ok(message) =>{self.account.message = message;},
+                Err(err) =>{
+                    self.account.message = err.to_string();
+                    return false;
+                },
+            }
+
+            if field == AccountField::DeleteConfirm{
+                // the lists are gone with the account, nothing is saved
+                self.end_session();
+                return true;
+            }
+            false
+        }
+
+        /*
+            This method logs the user out so another user can log in.
+            The selection is saved for the next login, then the lists are cleared from memory.
+        */
+        pub fn logout(&mut self){
+            self.save_selection();
+            self.end_session();
+        }
+
+        /*
+            Returns: true if the user logged out or deleted their account
+        */
+        pub fn logged_out(&self) -> bool{
+            self.logged_out
+        }
+
+        /*
+            This method clears everything loaded for the user and stops saving their selection
+        */
+        fn end_session(&mut self){
+            self.defalut_state();
+            self.todo_lists.clear();
+            self.item_cursors.clear();
+            self.search_results.clear();
+            self.agenda.clear();
+            self.assigned.clear();
+            self.last_click = None;
+            self.fix_selection();
+            self.logged_out = true;
+        }
+
+        /*
+            This method shows or hides the help overlay
+        */
+        pub fn toggle_help(&mut self){
+            self.show_help = !self.show_help;
+        }
+
+        /*
+            This method adds a char to the search and updates the results
+
+            Prams
+                c: char that user enters
+        */
+        pub fn add_search_input(&mut self, c: char){
+            self.search_input.insert(c);
+            self.update_search();
+        }
+
+        /*
+            This method removes the char before the cursor from the search and updates the results
+        */
+        pub fn remove_search_input(&mut self){
+            self.search_input.backspace();
+            self.update_search();
+        }
+
+        /*
+            This method finds all items matching search_input.
+            Large databases find the candidates in SQLite, both ways rank them with
+            search::match_score so the same query finds the same items.
+        */
+        pub fn update_search(&mut self){
+            self.search_index = 0;
+
+            let item_count: usize = self.todo_lists.iter().map(|todo_list| todo_list.get_list_len()).sum();
+
+            if item_count < DATABASE_SEARCH_THRESHOLD{
+                self.search_results = search::search_lists(&self.todo_lists, self.search_input.get_text());
+                return;
+            }
+
+            let pattern: String = search::like_pattern(self.search_input.get_text());
+            if pattern.is_empty(){
+                self.search_results = Vec::new();
+                return;
+            }
+
+            // fall back to in memory search if the query fails
+            let rows: Vec<QuerySearch> = match self.database.match_items(self.user.get_user_id(), &pattern){
+                Ok(res) =>{res},
+                Err(_err) =>{
+                    self.search_results = search::search_lists(&self.todo_lists, self.search_input.get_text());
+                    return;
+                },
+            };
+
+            // map database ids to list and item indexes
+            let query: &str = self.search_input.get_text();
+            let mut results: Vec<SearchResult> = rows
+                .into_iter()
+                .filter_map(|row|{
+                    let score: i64 = search::match_score(query, &row.item_name)?;
+                    let list_index = self.todo_lists.iter().position(|todo_list| todo_list.get_list_id() == row.list_id)?;
+                    let item_index = self.todo_lists[list_index].list.iter().position(|item| item.get_item_id() == row.item_id)?;
+                    Some(SearchResult{
+                        list_index,
+                        item_index,
+                        list_name: row.list_name,
+                        item_name: row.item_name,
+                        score,
+                    })
+                })
+                .collect();
+
+            // the same order as search_lists, best match first then list order
+            results.sort_by_key(|result| (Reverse(result.score), result.list_index, result.item_index));
+            self.search_results = results;
+        }
+
+        /*
+            This method selects the search result below the current
+        */
+        pub fn next_search_result(&mut self){
+            if self.search_results.is_empty(){
+                return;
+            }
+            self.search_index = (self.search_index + 1) % self.search_results.len();
+        }
+
+        /*
+            This method selects the search result above the current
+        */
+        pub fn previous_search_result(&mut self){
+            if self.search_results.is_empty(){
+                return;
+            }
+            if self.search_index > 0{
+                self.search_index -= 1;
+            }
             else{
-                return String::from("No Active Lists");
+                self.search_index = self.search_results.len() - 1;
+            }
+        }
+
+        /*
+            This method jumps to the selected search result and enters navigate state
+        */
+        pub fn select_search_result(&mut self){
+            let (list_index, item_index) = match self.search_results.get(self.search_index){
+                Some(result) =>{(result.list_index, result.item_index)},
+                None =>{return;},
+            };
+            self.jump_to_item(list_index, item_index);
+        }
+
+        /*
+            This method selects an item in navigate state, showing every item if the filter hides it
+
+            Prams
+                list_index: usize, index of the list in todo_lists
+                item_index: usize, index of the item in the list
+        */
+        fn jump_to_item(&mut self, list_index: usize, item_index: usize){
+            if list_index >= self.todo_lists.len(){
+                return;
+            }
+
+            self.navigate_state();
+            self.select_list(list_index);
+            self.selected_list = SelectedList::Items;
+
+            // show every item if the filter hides the item
+            if !self.todo_lists[list_index].visible_indexes().contains(&item_index){
+                self.todo_lists[list_index].set_filter(ItemFilter::All);
+                self.save_list_view();
+            }
+            self.item_index = self.todo_lists[list_index]
+                .visible_indexes()
+                .iter()
+                .position(|index| *index == item_index);
+            self.fix_selection();
+        }
+
+        /*
+            This method clears the search input and results
+        */
+        fn clear_search(&mut self){
+            self.search_input.take();
+            self.search_results.clear();
+            self.search_index = 0;
+        }
+
+        /*
+            This method handels the arrow key input from user
+        */
+        pub fn left_right_key(&mut self){
+            match self.action_state{
+                ActionState::Navigate=>{
+                    match self.selected_list{
+                        // toggle between Items and List
+                        SelectedList::List =>{self.selected_list = SelectedList::Items},
+                        SelectedList::Items => {self.selected_list = SelectedList::List},
+                        _ =>{},
+                    }
+                },
+                ActionState::CaptureInput=>{
+                    match self.input_box{
+                        // toggle between AddItem and AddList
+                        InputBox::AddList =>{self.input_box = InputBox::AddItem},
+                        InputBox::AddItem=> {self.input_box = InputBox::AddList},
+                        _ =>{},
+                    }
+                },
+                _ =>{},
+            }
+        }
+
+        /*
+            This method handels adding input from the user
+
+            Prams
+                c: char that user enters
+        */
+        pub fn add_input(&mut self, c: char){
+            // if InputBox::AddList type into input_list
+            // if InputBox::AddItem type into input_item
+            if let Some(editor) = self.selected_input(){
+                editor.insert(c);
+            }
+        }
+
+        /*
+            This method handesl delete/backspace when input box is selected
+        */
+        pub fn remove_input(&mut self){
+            // if InputBox::AddList then remove from input_list
+            // if InputBox::AddItem then remove from input_item
+            if let Some(editor) = self.selected_input(){
+                editor.backspace();
+            }
+        }
+
+        /*
+            This method moves the cursor or edits the text of the selected input box or search
+
+            Prams
+                action: Action, an editing action such as CursorLeft or DeleteWord
+        */
+        pub fn edit_input(&mut self, action: Action){
+            if self.action_state == ActionState::Search{
+                let before: String = String::from(self.search_input.get_text());
+                self.search_input.edit(action);
+                if self.search_input.get_text() != before{
+                    self.update_search();
+                }
+            }
+            else if let Some(editor) = self.selected_input(){
+                editor.edit(action);
+            }
+        }
+
+        /*
+            This method types pasted text into the selected input box or search
+
+            Prams
+                text: &str, the pasted text
+        */
+        pub fn paste(&mut self, text: &str){
+            if self.action_state == ActionState::Search{
+                self.search_input.paste(text);
+                self.update_search();
+            }
+            else if let Some(editor) = self.selected_input(){
+                editor.paste(text);
+            }
+        }
+
+        /*
+            Returns: Option<&mut LineEditor>, the selected input box, None if no input box is selected
+        */
+        fn selected_input(&mut self) -> Option<&mut LineEditor>{
+            if self.action_state == ActionState::Account{
+                return self.account.selected_input();
+            }
+            if self.action_state == ActionState::Share{
+                return Some(&mut self.share.username_input);
+            }
+            match self.input_box{
+                InputBox::AddList =>{Some(&mut self.input_list)},
+                InputBox::AddItem =>{Some(&mut self.input_item)},
+                _ =>{None},
+            }
+        }
+
+        /*
+            This method is used to check if the input box is InputBox::AddList
+
+            Returns: true if InputBox::AddList is selected, false otherwise
+        */
+        pub fn input_box_list(&self) -> bool{
+            if self.action_state == ActionState::CaptureInput && self.input_box == InputBox::AddList{
+                return true;
+            }
+            return false;
+        }
+
+        /*
+            This method is used to check if the input box is InputBox::AddItem
+
+            Returns: true if InputBox::AddItem is selected, false otherwise
+        */
+        pub fn input_box_item(&self) -> bool{
+
+            if self.action_state == ActionState::CaptureInput && self.input_box == InputBox::AddItem{
+                return true;
+            }
+            return false;
+        }
+
+        /*
+            This method is used to check if the SelectedList is SelectedList::List
+
+            Returns: true if SelectedList::List is selected, false otherwise
+        */
+        pub fn list_selected(&self) -> bool{
+            if self.action_state == ActionState::Navigate && self.selected_list == SelectedList::List{
+                return true;
+            }
+            return false;
+        }
+
+        /*
+            This method is used to check if the SelectedList is SelectedList::Items
+
+            Returns: true if SelectedList::Items is selected, false otherwise
+        */
+        pub fn item_selected(&self) -> bool{
+            if self.action_state == ActionState::Navigate && self.selected_list == SelectedList::Items{
+                return true;
+            }
+            return false;
+        }
+
+        /*
+            This method sets the item_index or list_index to the one below the current
+        */
+        pub fn next_list_item(&mut self){
+            match self.selected_list{
+                SelectedList::List =>{
+                    let n: usize = self.todo_lists.len();
+                    if let (Some(index), true) = (self.list_index, n > 0){
+                        self.select_list((index + 1) % n);
+                    }
+                },
+                SelectedList::Items =>{
+                    let n: usize = self.current_list().map_or(0, |todo_list| todo_list.get_visible_len());
+                    if let (Some(index), true) = (self.item_index, n > 0){
+                        self.item_index = Some((index + 1) % n);
+                    }
+                },
+                _ => {},
+
+            }
+            self.fix_selection();
+        }
+
+        /*
+            This method sets the item_index or list_index to the one above the current
+        */
+        pub fn previous_list_item(&mut self){
+            match self.selected_list{
+                SelectedList::List =>{
+                    let n: usize = self.todo_lists.len();
+                    if let (Some(index), true) = (self.list_index, n > 0){
+                        self.select_list(if index > 0 {index - 1} else {n - 1});
+                    }
+                },
+                SelectedList::Items =>{
+                    let n: usize = self.current_list().map_or(0, |todo_list| todo_list.get_visible_len());
+                    if let (Some(index), true) = (self.item_index, n > 0){
+                        self.item_index = Some(if index > 0 {index - 1} else {n - 1});
+                    }
+                },
+                _ => {},
+            }
+            self.fix_selection();
+        }
+
+        /*
+            This method moves the item_index or list_index by a number of rows,
+            stopping at the first and last row
+
+            Prams
+                rows: isize, rows to move, negative moves up
+        */
+        fn move_list_item(&mut self, rows: isize){
+            let (index, n) = match self.selected_list{
+                SelectedList::List =>{(self.list_index, self.todo_lists.len())},
+                SelectedList::Items =>{(self.item_index, self.current_list().map_or(0, |todo_list| todo_list.get_visible_len()))},
+                _ =>{return;},
+            };
+
+            // nothing to move through
+            let index: usize = match index{
+                Some(index) if n > 0 =>{index},
+                _ =>{return;},
+            };
+
+            let new_index: usize = (index as isize + rows).clamp(0, n as isize - 1) as usize;
+            match self.selected_list{
+                SelectedList::List =>{self.select_list(new_index);},
+                _ =>{self.item_index = Some(new_index);},
+            }
+            self.fix_selection();
+        }
+
+        /*
+            This method moves the selection down one page
+        */
+        pub fn page_down(&mut self){
+            self.move_list_item(self.page_size.max(1) as isize);
+        }
+
+        /*
+            This method moves the selection up one page
+        */
+        pub fn page_up(&mut self){
+            self.move_list_item(-(self.page_size.max(1) as isize));
+        }
+
+        /*
+            This method selects the first list or item
+        */
+        pub fn first_list_item(&mut self){
+            self.move_list_item(isize::MIN / 2);
+        }
+
+        /*
+            This method selects the last list or item
+        */
+        pub fn last_list_item(&mut self){
+            self.move_list_item(isize::MAX / 2);
+        }
+
+        /*
+            This method handles a left click, selecting the list, item,
+            search result or input box under the mouse.
+            A double click on an item crosses it off, on a search result jumps to it.
+
+            Prams
+                column: u16, the mouse column
+                row: u16, the mouse row
+        */
+        pub fn click(&mut self, column: u16, row: u16){
+
+            let target: MouseTarget = self.layout.target(column, row);
+
+            // a second click on the same target soon after is a double click
+            let now: Instant = Instant::now();
+            let double_click: bool = match self.last_click{
+                Some((last_target, time)) =>{last_target == target && now.duration_since(time) < DOUBLE_CLICK_TIME},
+                None =>{false},
+            };
+            self.last_click = if double_click {None} else {Some((target, now))};
+
+            match target{
+                MouseTarget::List(index) =>{
+                    if self.action_state != ActionState::Navigate{
+                        self.navigate_state();
+                    }
+                    self.selected_list = SelectedList::List;
+                    if index < self.todo_lists.len(){
+                        self.select_list(index);
+                    }
+                },
+                MouseTarget::Item(index) =>{
+                    if self.action_state != ActionState::Navigate{
+                        self.navigate_state();
+                    }
+                    self.selected_list = SelectedList::Items;
+                    if index >= self.current_list().map_or(0, |todo_list| todo_list.get_visible_len()){
+                        return;
+                    }
+                    self.item_index = Some(index);
+                    if double_click{
+                        self.check_off();
+                    }
+                },
+                MouseTarget::SearchResult(index) =>{
+                    self.search_index = index;
+                    if double_click{
+                        self.select_search_result();
+                    }
+                },
+                MouseTarget::ListInput =>{
+                    self.capture_input_state();
+                },
+                MouseTarget::ItemInput =>{
+                    self.capture_input_state();
+                    self.input_box = InputBox::AddItem;
+                },
+                MouseTarget::Nothing =>{},
+            }
+        }
+
+        /*
+            This method handles the scroll wheel, moving the selection of the pane under the mouse
+
+            Prams
+                column: u16, the mouse column
+                row: u16, the mouse row
+                down: bool, true if scrolled down, false if scrolled up
+        */
+        pub fn scroll(&mut self, column: u16, row: u16, down: bool){
+
+            let rows: isize = if down {1} else {-1};
+
+            match self.layout.pane(column, row){
+                MouseTarget::List(_) =>{
+                    if self.action_state != ActionState::Navigate{
+                        self.navigate_state();
+                    }
+                    self.selected_list = SelectedList::List;
+                    self.move_list_item(rows);
+                },
+                MouseTarget::Item(_) =>{
+                    if self.action_state != ActionState::Navigate{
+                        self.navigate_state();
+                    }
+                    self.selected_list = SelectedList::Items;
+                    self.move_list_item(rows);
+                },
+                MouseTarget::SearchResult(_) =>{
+                    if down && self.search_index + 1 < self.search_results.len(){
+                        self.search_index += 1;
+                    }
+                    else if !down && self.search_index > 0{
+                        self.search_index -= 1;
+                    }
+                },
+                _ =>{},
+            }
+        }
+
+        /*
+            Returns: Option<usize>, index of the selected list in todo_lists, None if there are no lists
+        */
+        pub fn get_list_index(&self) -> Option<usize>{
+            self.list_index
+        }
+
+        /*
+            Returns: Option<usize>, position of the selected item in the shown items, None if no item is shown
+        */
+        pub fn get_item_index(&self) -> Option<usize>{
+            self.item_index
+        }
+
+        /*
+            Returns: Option<&TodoList>, the selected list, None if there are no lists
+        */
+        pub fn current_list(&self) -> Option<&TodoList>{
+            self.list_index.and_then(|index| self.todo_lists.get(index))
+        }
+
+        /*
+            This method maps item_index to the index of the item in the current list
+
+            Returns: Option<usize>
+                Some(index): index into the current list of the selected item
+                None: there is no list or no shown item
+        */
+        pub fn selected_item(&self) -> Option<usize>{
+            self.selected_indexes().map(|(_list_index, index)| index)
+        }
+
+        /*
+            Returns: Option<(usize, usize)>, the selected list index and the index of the selected item in it
+        */
+        fn selected_indexes(&self) -> Option<(usize, usize)>{
+            let list_index: usize = self.list_index?;
+            let index: usize = *self.todo_lists.get(list_index)?.visible_indexes().get(self.item_index?)?;
+            Some((list_index, index))
+        }
+
+        /*
+            This method selects a list, remembering the item selected in the old list
+            and selecting the item last selected in the new one
+
+            Prams
+                index: usize, index of the list in todo_lists
+        */
+        fn select_list(&mut self, index: usize){
+            if let (Some(todo_list), Some(item_index)) = (self.current_list(), self.item_index){
+                let list_id: u32 = todo_list.get_list_id();
+                self.item_cursors.insert(list_id, item_index);
+            }
+            self.list_index = Some(index);
+            self.restore_cursor();
+        }
+
+        /*
+            This method selects the item last selected in the current list, the first item if there is none
+        */
+        fn restore_cursor(&mut self){
+            self.item_index = None;
+            self.fix_selection();
+            let cursor: Option<usize> = self.current_list().and_then(|todo_list| self.item_cursors.get(&todo_list.get_list_id()).copied());
+            if cursor.is_some(){
+                self.item_index = cursor;
+                self.fix_selection();
+            }
+        }
+
+        /*
+            This method selects the list and item the user had selected when they last left the app
+        */
+        fn load_selection(&mut self){
+            self.fix_selection();
+            let preferences = match self.database.get_preferences(self.user.get_user_id()){
+                Ok(Some(preferences)) =>{preferences},
+                Ok(None) =>{return;},
+                Err(err) =>{println!("{}", err); return;},
+            };
+
+            let list_index: Option<usize> = self.todo_lists.iter().position(|todo_list| Some(todo_list.get_list_id()) == preferences.list_id);
+            if let Some(list_index) = list_index{
+                self.list_index = Some(list_index);
+                let todo_list: &TodoList = &self.todo_lists[list_index];
+                self.item_index = todo_list
+                    .visible_indexes()
+                    .iter()
+                    .position(|index| Some(todo_list.get_item_id(*index)) == preferences.item_id);
+                self.fix_selection();
+            }
+        }
+
+        /*
+            This method stores the selected list and item so they are selected on the next login
+        */
+        pub fn save_selection(&self){
+            // nothing is loaded once logged out, a deleted account keeps no preferences
+            if self.logged_out{
+                return;
+            }
+            let list_id: Option<u32> = self.current_list().map(|todo_list| todo_list.get_list_id());
+            let item_id: Option<u32> = self.selected_indexes().map(|(list_index, index)| self.todo_lists[list_index].get_item_id(index));
+            match self.database.update_preferences(self.user.get_user_id(), list_id, item_id){
+                Ok(()) =>{},
+                Err(err) =>{println!("{}", err)},
+            };
+        }
+
+        /*
+            This method keeps the selection valid after the lists or items change.
+            list_index is None only when there are no lists and is otherwise in todo_lists,
+            item_index is None only when the current list shows no items and is otherwise a shown item.
+        */
+        fn fix_selection(&mut self){
+            let n: usize = self.todo_lists.len();
+            self.list_index = match n{
+                0 =>{None},
+                _ =>{Some(self.list_index.unwrap_or(0).min(n - 1))},
+            };
+
+            let n: usize = self.current_list().map_or(0, |todo_list| todo_list.get_visible_len());
+            self.item_index = match n{
+                0 =>{None},
+                _ =>{Some(self.item_index.unwrap_or(0).min(n - 1))},
+            };
+        }
+
+        /*
+            This method shows the next filter for the current list
+        */
+        pub fn cycle_filter(&mut self){
+            if let Some(todo_list) = self.list_index.and_then(|index| self.todo_lists.get_mut(index)){
+                let filter = todo_list.get_filter().next();
+                todo_list.set_filter(filter);
+                self.item_index = None;
+                self.fix_selection();
+                self.save_list_view();
+            }
+        }
+
+        /*
+            This method shows the next sort for the current list
+        */
+        pub fn cycle_sort(&mut self){
+            if let Some(todo_list) = self.list_index.and_then(|index| self.todo_lists.get_mut(index)){
+                let sort = todo_list.get_sort().next();
+                todo_list.set_sort(sort);
+                self.item_index = None;
+                self.fix_selection();
+                self.save_list_view();
+            }
+        }
+
+        /*
+            This method stores the filter and sort of the current list in the database
+        */
+        fn save_list_view(&self){
+            if let Some(todo_list) = self.current_list(){
+                match self.database.update_list_view(todo_list.get_list_id(), self.user.get_user_id(), todo_list.get_filter(), todo_list.get_sort()){
+                    Ok(()) =>{},
+                    Err(err) =>{println!("{}", err)},
+                };
+            }
+        }
+
+        /*
+            This method raises or lowers the priority of the selected item
+
+            Prams
+                raise: true to raise the priority, false to lower it
+        */
+        pub fn change_priority(&mut self, raise: bool){
+            if self.selected_list != SelectedList::Items{
+                return;
+            }
+            let (list_index, index) = match self.selected_indexes(){
+                Some(indexes) =>{indexes},
+                None =>{return;},
+            };
+            if !self.can_write(list_index){
+                return;
+            }
+
+            let todo_list = &mut self.todo_lists[list_index];
+            let priority: u32 = todo_list.list[index].get_priority();
+            let new_priority: u32 = if raise {(priority + 1).min(MAX_PRIORITY)} else {priority.saturating_sub(1)};
+            todo_list.list[index].set_priority(new_priority);
+
+            let item_id = todo_list.get_item_id(index);
+            let list_id = todo_list.get_list_id();
+            let due = todo_list.list[index].get_due();
+            match self.database.update_item_details(item_id, list_id, due, new_priority){
+                Ok(()) =>{},
+                Err(err) =>{println!("{}", err)},
+            };
+
+            // the item may have moved when sorted by priority
+            if let Some(position) = self.todo_lists[list_index].visible_indexes().iter().position(|i| *i == index){
+                self.item_index = Some(position);
+            }
+        }
+
+        /*
+            This method gets the filter and sort of the current list
+
+            Returns: String, filter and sort names
+        */
+        pub fn get_view_name(&self) -> String{
+            match self.current_list(){
+                Some(todo_list) =>{format!("{} / {}", todo_list.get_filter().get_name(), todo_list.get_sort().get_name())},
+                None =>{String::from("None")},
+            }
+        }
+
+        /*
+            This method get the username of the current user
+
+            Returns: String, username
+        */
+        pub fn get_username(&self)->String{
+            return self.user.get_username();
+        }
+
+        /*
+            This method gets the key bindings that are active in the current action_state
+
+            Returns: KeyContext
+        */
+        pub fn get_key_context(&self) -> KeyContext{
+            match self.action_state{
+                ActionState::Default => KeyContext::Default,
+                ActionState::CaptureInput => KeyContext::Input,
+                ActionState::Navigate => KeyContext::Navigate,
+                ActionState::Search => KeyContext::Search,
+                ActionState::Dashboard => KeyContext::Dashboard,
+                ActionState::Board => KeyContext::Board,
+                ActionState::Agenda => KeyContext::Agenda,
+                ActionState::Account => KeyContext::Account,
+                ActionState::Assigned => KeyContext::Assigned,
+                ActionState::Share => KeyContext::Share,
+            }
+        }
+
+        /*
+            This method get the current action_state
+
+            Returns: String, actions_state
+        */
+        pub fn get_mode(&self)->String{
+            match self.action_state{
+                ActionState::CaptureInput=>{
+                    return String::from("Input");
+                },
+                ActionState::Navigate =>{
+                    return String::from("Navigation");
+                },
+                ActionState::Search =>{
+                    return String::from("Search");
+                },
+                ActionState::Dashboard =>{
+                    return String::from("Dashboard");
+                },
+                ActionState::Board =>{
+                    return String::from("Board");
+                },
+                ActionState::Agenda =>{
+                    return String::from("Agenda");
+                },
+                ActionState::Account =>{
+                    return String::from("Account");
+                },
+                ActionState::Assigned =>{
+                    return String::from("Assigned");
+                },
+                ActionState::Share =>{
+                    return String::from("Share");
+                },
+                ActionState::Default=>{
+                    return String::from("Default");
+                },
+            }
+        }
+
+        /*
+           This method returns the name of the current list
+
+           Returns: String, current list name
+        */
+        pub fn get_list_name(&self) -> String{
+            match self.current_list(){
+                Some(todo_list) =>{return todo_list.get_title();},
+                None =>{return String::from("No Active Lists");},
             }
-            
         }
 
         /*
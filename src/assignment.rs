@@ -0,0 +1,98 @@
+pub mod assignment{
+
+    use chrono::NaiveDate;
+    use crate::sharing::sharing::ListMember;
+    use crate::todo::todo::TodoList;
+
+    /*
+        This structure holds one item of the my assigned items view
+
+        Members:
+            list_index: usize, index of the list in todo_lists
+            item_index: usize, index of the item in the list
+            list_name: String, name of the list the item belongs to
+            item_name: String, name of the item
+            due: Option<NaiveDate>, when the item is due
+            complete: bool, the item is crossed off
+    */
+    pub struct AssignedEntry{
+        pub list_index: usize,
+        pub item_index: usize,
+        pub list_name: String,
+        pub item_name: String,
+        pub due: Option<NaiveDate>,
+        pub complete: bool,
+    }
+
+    /*
+        This function lists the items of every list assigned to a user,
+        open items first, then in list order
+
+        Prams:
+            todo_lists: the lists to look through
+            user_id: u32, the user the items are assigned to
+
+        Returns: Vec<AssignedEntry>
+    */
+    pub fn build_assigned(todo_lists: &[TodoList], user_id: u32) -> Vec<AssignedEntry>{
+
+        let mut entries: Vec<AssignedEntry> = Vec::new();
+        for (list_index, todo_list) in todo_lists.iter().enumerate(){
+            for (item_index, item) in todo_list.list.iter().enumerate(){
+                if item.get_assignee() == Some(user_id){
+                    entries.push(AssignedEntry{
+                        list_index,
+                        item_index,
+                        list_name: todo_list.get_name(),
+                        item_name: item.get_item_name(),
+                        due: item.get_due(),
+                        complete: item.get_complete(),
+                    });
+                }
+            }
+        }
+
+        // stable so items keep list order
+        entries.sort_by_key(|entry| entry.complete);
+        entries
+    }
+
+    /*
+        This function finds the user an item is assigned to after the current one,
+        going through the members in order and then back to unassigned
+
+        Prams:
+            members: &[ListMember], the users with access to the list
+            assignee: Option<u32>, the user the item is assigned to now
+
+        Returns: Option<&ListMember>, None to unassign the item
+    */
+    pub fn next_assignee(members: &[ListMember], assignee: Option<u32>) -> Option<&ListMember>{
+        match assignee.and_then(|user_id| members.iter().position(|member| member.user_id == user_id)){
+            Some(position) =>{members.get(position + 1)},
+            None if assignee.is_none() =>{members.first()},
+            // assigned to a user without access, unassign
+            None =>{None},
+        }
+    }
+
+    /*
+        This function shortens a username to the initials shown next to an item.
+        Each word gives its first letter, a one word name gives its first two letters.
+
+        Pram: username: &str, the username
+        Returns: String, up to two upper case letters e.g. "mary_jane" is "MJ", "bob" is "BO"
+    */
+    pub fn initials(username: &str) -> String{
+        let words: Vec<&str> = username
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        let letters: String = match words.as_slice(){
+            [] =>{String::from("?")},
+            [word] =>{word.chars().take(2).collect()},
+            [first, second, ..] =>{first.chars().take(1).chain(second.chars().take(1)).collect()},
+        };
+        letters.to_uppercase()
+    }
+}
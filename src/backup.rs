@@ -0,0 +1,282 @@
+pub mod backup{
+
+    use crate::database::database::{QueryRestore, TodoDatabase};
+    use chrono::{Duration as TimeDuration, Local, NaiveDate, NaiveDateTime};
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::thread;
+    use std::time::Duration;
+    use thiserror::Error;
+
+    // backup files are named data-<time>.db, newest last when sorted by name
+    const BACKUP_PREFIX: &str = "data-";
+    const BACKUP_EXTENSION: &str = "db";
+    const BACKUP_TIME_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+    // the newest backup of each of this many days is kept as well as the newest backups
+    pub const DAILY_BACKUPS: usize = 30;
+
+    /*
+        This enum is every reason a backup cannot be made or restored
+
+        Members:
+            NotFound: no backup has the number or name given
+            UnknownUser: the user is not in the backup, or another account has the username now
+            Conflict: rows in the backup have ids other users have now, e.g. "list 20"
+            File: the backup folder could not be read or written
+            Database: the database or backup could not be read or written
+    */
+    #[derive(Error, Debug)]
+    pub enum BackupError{
+        #[error("No backup {0}, run restore to list the backups")]
+        NotFound(String),
+        #[error("User: {0} cannot be restored from the backup")]
+        UnknownUser(String),
+        #[error("User: {0} cannot be restored, other users now have the ids of: {1}")]
+        Conflict(String, String),
+        #[error("Backup file error: {0}")]
+        File(#[from] io::Error),
+        #[error("Database error: {0}")]
+        Database(#[from] rusqlite::Error),
+    }
+
+    /*
+        This function names the backup made at a time
+
+        Pram: now: NaiveDateTime, the time of the backup
+        Returns: String, e.g. "data-20261019-120000.db"
+    */
+    pub fn backup_name(now: NaiveDateTime) -> String{
+        format!("{}{}.{}", BACKUP_PREFIX, now.format(BACKUP_TIME_FORMAT), BACKUP_EXTENSION)
+    }
+
+    /*
+        This function finds the time a backup was made from its name
+
+        Pram: path: &Path, the backup file
+        Returns: Option<NaiveDateTime>, None if the file is not a backup
+    */
+    pub fn backup_time(path: &Path) -> Option<NaiveDateTime>{
+        if path.extension()? != BACKUP_EXTENSION{
+            return None;
+        }
+        let time: &str = path.file_stem()?.to_str()?.strip_prefix(BACKUP_PREFIX)?;
+        NaiveDateTime::parse_from_str(time, BACKUP_TIME_FORMAT).ok()
+    }
+
+    /*
+        This function lists the backups in a folder
+
+        Pram: dir: &Path, the backup folder
+        Returns: Result<Vec<PathBuf>, BackupError>, newest first, empty if the folder does not exist
+    */
+    pub fn list_backups(dir: &Path) -> Result<Vec<PathBuf>, BackupError>{
+        let entries = match fs::read_dir(dir){
+            Ok(entries) =>{entries},
+            Err(err) if err.kind() == io::ErrorKind::NotFound =>{return Ok(Vec::new());},
+            Err(err) =>{return Err(BackupError::File(err));},
+        };
+
+        let mut backups: Vec<PathBuf> = Vec::new();
+        for entry in entries{
+            let path: PathBuf = entry?.path();
+            if backup_time(&path).is_some(){
+                backups.push(path);
+            }
+        }
+        // the time in the name sorts oldest to newest
+        backups.sort();
+        backups.reverse();
+        Ok(backups)
+    }
+
+    /*
+        This function snapshots the database into the backup folder, then removes the oldest backups
+
+        Prams:
+            database: &TodoDatabase, the database to copy
+            dir: &Path, the backup folder, created if needed
+            keep: usize, the most recent backups kept
+            now: NaiveDateTime, the time of the backup
+
+        Returns: Result<PathBuf, BackupError>
+            Ok(PathBuf): the new backup file
+            Err(BackupError): the folder or database failed
+    */
+    pub fn create_backup(database: &TodoDatabase, dir: &Path, keep: usize, now: NaiveDateTime) -> Result<PathBuf, BackupError>{
+        let path: PathBuf = snapshot(database, dir, now)?;
+        rotate(dir, keep)?;
+        Ok(path)
+    }
+
+    /*
+        This function snapshots the database unless a backup was made within the interval,
+        so starting the app many times does not replace the history with copies of the same data
+
+        Prams:
+            database: &TodoDatabase, the database to copy
+            dir: &Path, the backup folder, created if needed
+            keep: usize, the most recent backups kept
+            interval: TimeDuration, the time a backup is recent for
+            now: NaiveDateTime, the time of the backup
+
+        Returns: Result<Option<PathBuf>, BackupError>
+            Ok(Some(PathBuf)): the new backup file
+            Ok(None): the newest backup is recent, no backup was made
+            Err(BackupError): the folder or database failed
+    */
+    pub fn create_backup_if_due(database: &TodoDatabase, dir: &Path, keep: usize, interval: TimeDuration, now: NaiveDateTime) -> Result<Option<PathBuf>, BackupError>{
+        let newest: Option<NaiveDateTime> = list_backups(dir)?.first().and_then(|path| backup_time(path));
+        if newest.is_some_and(|newest| newest > now - interval){
+            return Ok(None);
+        }
+        Ok(Some(create_backup(database, dir, keep, now)?))
+    }
+
+    /*
+        This function snapshots the database into the backup folder.
+        A backup never replaces another, one made in the same second is named a second later.
+
+        Prams:
+            database: &TodoDatabase, the database to copy
+            dir: &Path, the backup folder, created if needed
+            now: NaiveDateTime, the time of the backup
+
+        Returns: Result<PathBuf, BackupError>, the new backup file
+    */
+    fn snapshot(database: &TodoDatabase, dir: &Path, now: NaiveDateTime) -> Result<PathBuf, BackupError>{
+        fs::create_dir_all(dir)?;
+        let mut time: NaiveDateTime = now;
+        while dir.join(backup_name(time)).exists(){
+            time += TimeDuration::seconds(1);
+        }
+        let path: PathBuf = dir.join(backup_name(time));
+        database.backup_to(&path)?;
+        Ok(path)
+    }
+
+    /*
+        This function removes the oldest backups.
+        The newest backup of each of the last DAILY_BACKUPS days is kept so older data can still be restored.
+
+        Prams:
+            dir: &Path, the backup folder
+            keep: usize, the most recent backups kept
+
+        Returns: Result<Vec<PathBuf>, BackupError>, the backups removed
+    */
+    pub fn rotate(dir: &Path, keep: usize) -> Result<Vec<PathBuf>, BackupError>{
+        let mut days: Vec<NaiveDate> = Vec::new();
+        let mut old: Vec<PathBuf> = Vec::new();
+        for (index, path) in list_backups(dir)?.into_iter().enumerate(){
+            // newest first, so the first backup of a day is the one kept
+            let day: Option<NaiveDate> = backup_time(&path).map(|time| time.date());
+            let daily: bool = days.len() < DAILY_BACKUPS && day.is_some_and(|day| !days.contains(&day));
+            days.extend(day.filter(|_day| daily));
+            if index >= keep && !daily{
+                fs::remove_file(&path)?;
+                old.push(path);
+            }
+        }
+        Ok(old)
+    }
+
+    /*
+        This function finds a backup by its number in list_backups, 1 is the newest, or by its file name
+
+        Prams:
+            dir: &Path, the backup folder
+            choice: &str, the number or file name typed
+
+        Returns: Result<PathBuf, BackupError>, NotFound if no backup matches
+    */
+    pub fn find_backup(dir: &Path, choice: &str) -> Result<PathBuf, BackupError>{
+        let backups: Vec<PathBuf> = list_backups(dir)?;
+        let found: Option<PathBuf> = match choice.parse::<usize>(){
+            Ok(number) =>{number.checked_sub(1).and_then(|index| backups.get(index).cloned())},
+            Err(_err) =>{backups.into_iter().find(|path| path.file_name().is_some_and(|name| name == choice))},
+        };
+        found.ok_or(BackupError::NotFound(String::from(choice)))
+    }
+
+    /*
+        This function restores every user from a backup.
+        The database is backed up first so the restore can be undone.
+
+        Prams:
+            database: &mut TodoDatabase, the database to replace
+            dir: &Path, the backup folder
+            choice: &str, the number or file name of the backup
+            keep: usize, the most recent backups kept
+            now: NaiveDateTime, the current time
+
+        Returns: Result<PathBuf, BackupError>
+            Ok(PathBuf): the backup of the database before the restore
+            Err(BackupError): the backup is not found or cannot be read
+    */
+    pub fn restore(database: &mut TodoDatabase, dir: &Path, choice: &str, keep: usize, now: NaiveDateTime) -> Result<PathBuf, BackupError>{
+        let path: PathBuf = find_backup(dir, choice)?;
+        // old backups are only removed once the chosen one is restored
+        let undo: PathBuf = snapshot(database, dir, now)?;
+        database.restore_from(&path)?;
+        rotate(dir, keep)?;
+        Ok(undo)
+    }
+
+    /*
+        This function restores the lists of one user from a backup, other users are not changed.
+        The database is backed up first so the restore can be undone, the backup is removed if nothing is restored.
+
+        Prams:
+            database: &TodoDatabase, the database to change
+            dir: &Path, the backup folder
+            choice: &str, the number or file name of the backup
+            username: &str, the user to restore
+            keep: usize, the most recent backups kept
+            now: NaiveDateTime, the current time
+
+        Returns: Result<usize, BackupError>
+            Ok(usize): the number of lists restored
+            Err(BackupError): the backup or user is not found, other users have ids in the backup, or the backup cannot be read
+    */
+    pub fn restore_user(database: &TodoDatabase, dir: &Path, choice: &str, username: &str, keep: usize, now: NaiveDateTime) -> Result<usize, BackupError>{
+        let path: PathBuf = find_backup(dir, choice)?;
+        let undo: PathBuf = snapshot(database, dir, now)?;
+        let restored: Result<QueryRestore, rusqlite::Error> = database.restore_user(&path, username);
+        if let Ok(QueryRestore::Restored(lists)) = restored{
+            rotate(dir, keep)?;
+            return Ok(lists);
+        }
+
+        // nothing was changed, so nothing is undone and no older backup is removed
+        fs::remove_file(&undo)?;
+        match restored?{
+            QueryRestore::Conflict(ids) =>{Err(BackupError::Conflict(String::from(username), ids.join(", ")))},
+            _ =>{Err(BackupError::UnknownUser(String::from(username)))},
+        }
+    }
+
+    /*
+        This function backs up the database in a thread, every interval, until the app exits.
+        The thread has its own connection so the app is not blocked.
+
+        Prams:
+            path: PathBuf, the database file
+            key: Option<String>, the passphrase of an encrypted database
+            dir: PathBuf, the backup folder
+            keep: usize, the most recent backups kept
+            interval: Duration, the time between backups
+    */
+    pub fn spawn_periodic(path: PathBuf, key: Option<String>, dir: PathBuf, keep: usize, interval: Duration){
+        thread::spawn(move ||{
+            loop{
+                thread::sleep(interval);
+                // the terminal belongs to the app, a failed backup is tried again next interval
+                if let Ok(database) = TodoDatabase::open(&path.display().to_string(), key.as_deref()){
+                    let _ = create_backup(&database, &dir, keep, Local::now().naive_local());
+                }
+            }
+        });
+    }
+}
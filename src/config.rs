@@ -0,0 +1,111 @@
+pub mod config{
+
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+    use std::{fs, io, path::Path};
+    use thiserror::Error;
+
+    /*
+        This enum is every error loading the config file
+
+        Members:
+            Read: the file exists but could not be read
+            Parse: the file is not valid TOML or has the wrong shape
+            UnknownPreset: [keys] preset is not a built in preset
+            UnknownContext: a [keys.<context>] table names no context
+            UnknownAction: a key binding names no action
+            UnknownKey: a key binding could not be parsed
+            UnknownTheme: [theme] name is not a built in theme
+            UnknownColorName: [theme.colors] sets a color no draw function uses
+            UnknownColor: a color could not be parsed
+    */
+    #[derive(Error, Debug)]
+    pub enum ConfigError{
+        #[error("could not read config file: {0}")]
+        Read(#[from] io::Error),
+        #[error("could not parse config file: {0}")]
+        Parse(#[from] toml::de::Error),
+        #[error("unknown key preset: {0}")]
+        UnknownPreset(String),
+        #[error("unknown key context: {0}")]
+        UnknownContext(String),
+        #[error("unknown action: {0}")]
+        UnknownAction(String),
+        #[error("unknown key: {0}")]
+        UnknownKey(String),
+        #[error("unknown theme: {0}")]
+        UnknownTheme(String),
+        #[error("unknown theme color name: {0}")]
+        UnknownColorName(String),
+        #[error("unknown color: {0}")]
+        UnknownColor(String),
+    }
+
+    /*
+        This structure is the [keys] table of the config file
+
+        Members:
+            preset: Option<String>, built in bindings to start from, "default" or "vim"
+            contexts: BTreeMap, [keys.<context>] tables mapping action names to key sequences
+    */
+    #[derive(Deserialize, Default, Debug)]
+    pub struct KeyConfig{
+        pub preset: Option<String>,
+        #[serde(flatten)]
+        pub contexts: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+    }
+
+    /*
+        This structure is the [theme] table of the config file
+
+        Members:
+            name: Option<String>, built in theme to start from, "light", "dark" or "high_contrast"
+            colors: BTreeMap, [theme.colors] table replacing colors of the theme
+    */
+    #[derive(Deserialize, Default, Debug)]
+    #[serde(deny_unknown_fields)]
+    pub struct ThemeConfig{
+        pub name: Option<String>,
+        #[serde(default)]
+        pub colors: BTreeMap<String, String>,
+    }
+
+    /*
+        This structure is the config file
+
+        Members:
+            keys: KeyConfig, the key bindings
+            theme: ThemeConfig, the colors
+    */
+    #[derive(Deserialize, Default, Debug)]
+    #[serde(deny_unknown_fields)]
+    pub struct Config{
+        #[serde(default)]
+        pub keys: KeyConfig,
+        #[serde(default)]
+        pub theme: ThemeConfig,
+    }
+
+    /*
+        This function parses the text of a config file
+
+        Pram: the TOML text
+        Returns: Result<Config, ConfigError>
+    */
+    pub fn parse_config(text: &str) -> Result<Config, ConfigError>{
+        Ok(toml::from_str(text)?)
+    }
+
+    /*
+        This function loads the config file, a missing file is the default config
+
+        Pram: path to the config file
+        Returns: Result<Config, ConfigError>
+    */
+    pub fn load_config(file_path: &str) -> Result<Config, ConfigError>{
+        if !Path::new(file_path).exists(){
+            return Ok(Config::default());
+        }
+        parse_config(&fs::read_to_string(file_path)?)
+    }
+}
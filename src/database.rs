@@ -1,8 +1,14 @@
 pub mod database{
 
-    use rusqlite::{params, Connection, Result};
-    use crate::todo::todo::TodoList;
-    use crate::todo_item::todo_item::TodoItem;
+    use rusqlite::{params, Connection, OptionalExtension, Result};
+    use rusqlite::backup::{Backup, StepResult};
+    use std::path::Path;
+    use std::time::Duration;
+    use crate::todo::todo::{TodoList, DEFAULT_COLUMNS};
+    use crate::todo_item::todo_item::{TodoItem, DATE_FORMAT, DATE_TIME_FORMAT};
+    use crate::item_view::item_view::{ItemFilter, ItemSort};
+    use crate::sharing::sharing::{ListAccess, ListMember};
+    use chrono::{NaiveDate, NaiveDateTime};
 
 
     pub enum TodoDatabaseErrorCode{
@@ -37,11 +43,17 @@ pub mod database{
             user_id: u32 FORIGEN KEY the user that owns this list
             list_id: u32 PRIMARY KEY unique identifer for a list
             list_name: String the name of the list
+            item_filter: u32 ItemFilter code for the list
+            item_sort: u32 ItemSort code for the list
+            board_columns: Option<String> board column names joined by ",", None for DEFAULT_COLUMNS
     */
     pub struct QueryLists{
         pub user_id: u32,
         pub list_id: u32,
         pub list_name: String,
+        pub item_filter: u32,
+        pub item_sort: u32,
+        pub board_columns: Option<String>,
     }
     /*
         This structure stores data from a query in the items table
@@ -51,12 +63,175 @@ pub mod database{
             list_id: u32 FORIGEN KEY the list that owns this item
             tem_name: String the item name
             complete: u32 the status of complete 1 = true, 0 = false
+            created: Option<String> when the item was created, DATE_TIME_FORMAT
+            due: Option<String> when the item is due, DATE_FORMAT
+            priority: u32 the item priority, 0 = none
+            status: u32 the board column of the item
     */
     pub struct QueryItems{
         pub item_id: u32,
         pub list_id: u32,
         pub item_name: String,
         pub complete: u32,
+        pub created: Option<String>,
+        pub due: Option<String>,
+        pub priority: u32,
+        pub status: u32,
+        pub assignee: Option<u32>,
+    }
+    /*
+        This structure stores data from a full text search of the items table
+
+        Members:
+            list_id: u32 the list that owns the item
+            item_id: u32 the item that matched
+            list_name: String the name of the list
+            item_name: String the item name
+    */
+    pub struct QuerySearch{
+        pub list_id: u32,
+        pub item_id: u32,
+        pub list_name: String,
+        pub item_name: String,
+    }
+    /*
+        This structure stores the item counts of one list
+
+        Members:
+            list_id: u32 the list counted
+            list_name: String the name of the list
+            total: u64 the number of items
+            done: u64 the number of completed items
+            overdue: u64 the number of open items due before today
+    */
+    pub struct QueryListStats{
+        pub list_id: u32,
+        pub list_name: String,
+        pub total: u64,
+        pub done: u64,
+        pub overdue: u64,
+    }
+    /*
+        This structure stores the number of items completed on one day
+
+        Members:
+            day: String the day, DATE_FORMAT
+            count: u64 the number of items completed that day
+    */
+    pub struct QueryDayCount{
+        pub day: String,
+        pub count: u64,
+    }
+    /*
+        This structure stores data from a query in the preferences table
+
+        Members:
+            user_id: u32 PRIMARY KEY the user the preferences belong to
+            list_id: Option<u32> the list selected when the user last left the app
+            item_id: Option<u32> the item selected when the user last left the app
+    */
+    pub struct QueryPreferences{
+        pub user_id: u32,
+        pub list_id: Option<u32>,
+        pub item_id: Option<u32>,
+    }
+    
+    /*
+        This structure stores the failed logins of a username
+
+        Members:
+            username: String PRIMARY KEY the username tried
+            failures: u32 failed logins since the last successful login
+            locked_until: Option<String> logins are refused until this time, DATE_TIME_FORMAT
+    */
+    pub struct QueryLoginAttempts{
+        pub username: String,
+        pub failures: u32,
+        pub locked_until: Option<String>,
+    }
+    /*
+        This structure stores one row of the auth audit table
+
+        Members:
+            audit_id: u32 PRIMARY KEY the order the attempts were made
+            username: String the username tried
+            success: u32 1 = logged in, 0 = failed
+            reason: String why the login failed, empty if it succeeded
+            time: String when the login was tried, DATE_TIME_FORMAT
+    */
+    pub struct QueryAuthAudit{
+        pub audit_id: u32,
+        pub username: String,
+        pub success: u32,
+        pub reason: String,
+        pub time: String,
+    }
+    /*
+        This structure stores a list shared with a user
+
+        Members:
+            list: QueryLists the shared list
+            owner: String username of the owner of the list
+            can_write: u32 1 = read-write, 0 = read only
+    */
+    pub struct QuerySharedList{
+        pub list: QueryLists,
+        pub owner: String,
+        pub can_write: u32,
+    }
+    /*
+        This structure stores one row of the list_shares table with the username
+
+        Members:
+            list_id: u32 the shared list
+            user_id: u32 the user the list is shared with
+            username: String the username of the user the list is shared with
+            can_write: u32 1 = read-write, 0 = read only
+    */
+    pub struct QueryListShare{
+        pub list_id: u32,
+        pub user_id: u32,
+        pub username: String,
+        pub can_write: u32,
+    }
+    /*
+        This structure stores the two-factor authentication of a user
+
+        Members:
+            secret: String the TOTP secret as base32
+            last_step: Option<u64> the time step of the last code used, a code is only accepted once
+    */
+    pub struct QueryTwoFactor{
+        pub secret: String,
+        pub last_step: Option<u64>,
+    }
+    /*
+        This enum is the outcome of restoring the lists of one user from a backup
+
+        Members:
+            Restored(usize): the number of lists restored
+            UnknownUser: the user is not in the backup, or another account has the username now
+            Conflict(Vec<String>): rows in the backup have ids other users have now, e.g. "list 20", nothing is changed
+    */
+    pub enum QueryRestore{
+        Restored(usize),
+        UnknownUser,
+        Conflict(Vec<String>),
+    }
+    /*
+        This structure stores one remembered login of the sessions table
+
+        Members:
+            token_hash: String PRIMARY KEY the SHA-256 of the token, the token itself is never stored
+            user_id: u32 FORIGEN KEY the user logged in by the token
+            expires: String the token is refused after this time, DATE_TIME_FORMAT
+            two_factor: bool the user had two-factor authentication on when the token was issued
+    */
+    pub struct QuerySession{
+        pub token_hash: String,
+        pub user_id: u32,
+        pub expires: String,
+        pub two_factor: bool,
     }
     
     /*
@@ -66,11 +241,18 @@ pub mod database{
         Members:
             file_path: String path to the database file
             connection: Connection to the database
+            key: Option<String> the passphrase of an encrypted database, backups are encrypted with it too
     */
     pub struct TodoDatabase{
         connection: Connection,
+        key: Option<String>,
     }
 
+    // pages copied per step of a backup, other connections may write between steps
+    const BACKUP_PAGES: i32 = 64;
+    // wait between steps of a backup
+    const BACKUP_PAUSE_MS: u64 = 5;
+
     impl TodoDatabase{
         /*
             This Method creates a new TodoDatabase struct
@@ -89,6 +271,7 @@ pub mod database{
             // create TodoDatabase
             let new_db = TodoDatabase{
                 connection,
+                key: None,
             };
 
             // create the tabels
@@ -99,11 +282,190 @@ pub mod database{
 
             return new_db;
         }
+        /*
+            This Method opens a TodoDatabase, encrypted with SQLCipher when a key is given
+
+            Prams:
+                file_path: &str, the path to the database file
+                key: Option<&str>, the passphrase of an encrypted database, None for a plain database
+
+            Returns: Result<TodoDatabase, rusqlite::Error>
+                Ok(TodoDatabase): the tables are built and readable
+                Err: the file cannot be opened, or the key is wrong (SQLITE_NOTADB)
+        */
+        pub fn open(file_path: &str, key: Option<&str>) -> Result<TodoDatabase, rusqlite::Error>{
+            let connection = Connection::open(file_path)?;
+            // the key must be set before anything is read
+            if let Some(key) = key{
+                connection.pragma_update(None, "key", key)?;
+            }
+
+            let new_db = TodoDatabase{
+                connection,
+                key: key.map(String::from),
+            };
+            new_db.build_db()?;
+            Ok(new_db)
+        }
         pub fn close_connection(self)->Result<(), rusqlite::Error>{
             self.connection.close();
             Ok(())
         }
 
+        /*
+            This Method opens another database file with the key of this database
+
+            Pram: path: &Path, the file, a backup or the file a backup is written to
+            Returns: Result<Connection, rusqlite::Error>
+        */
+        fn open_with_key(&self, path: &Path) -> Result<Connection, rusqlite::Error>{
+            let connection = Connection::open(path)?;
+            if let Some(key) = &self.key{
+                connection.pragma_update(None, "key", key)?;
+            }
+            Ok(connection)
+        }
+
+        /*
+            This Method copies the database to a file with SQLite's online backup,
+            the app can keep writing while the copy is made
+
+            Pram: path: &Path, the backup file, replaced if it exists
+            Returns: Result<(), rusqlite::Error>
+        */
+        pub fn backup_to(&self, path: &Path) -> Result<(), rusqlite::Error>{
+            let mut copy = self.open_with_key(path)?;
+            let backup = Backup::new(&self.connection, &mut copy)?;
+            backup.run_to_completion(BACKUP_PAGES, Duration::from_millis(BACKUP_PAUSE_MS), None)
+        }
+
+        /*
+            This Method replaces every table with the tables of a backup
+
+            Pram: path: &Path, the backup file, made with the same key
+            Returns: Result<(), rusqlite::Error>, Err if the backup cannot be read
+        */
+        pub fn restore_from(&mut self, path: &Path) -> Result<(), rusqlite::Error>{
+            let source = self.open_with_key(path)?;
+            // reading the schema checks the key before anything is replaced
+            source.query_row("SELECT count(*) FROM sqlite_master;", (), |row| row.get::<_, u32>(0))?;
+            {
+                let backup = Backup::new(&source, &mut self.connection)?;
+                loop{
+                    match backup.step(BACKUP_PAGES)?{
+                        StepResult::Done =>{break;},
+                        _ =>{std::thread::sleep(Duration::from_millis(BACKUP_PAUSE_MS));},
+                    }
+                }
+            }
+            // an older backup gets the columns added since
+            self.build_db()
+        }
+
+        /*
+            This Method restores the lists, items and preferences of one user from a backup.
+            The user's lists now are replaced, other users are not changed.
+            A deleted account is restored with the password in the backup.
+
+            Prams:
+                path: &Path, the backup file, made with the same key
+                username: &str, the user to restore
+
+            Returns: Result<QueryRestore, rusqlite::Error>
+                Ok(QueryRestore): the number of lists restored, or why the user cannot be restored
+                Err: the backup cannot be read, nothing is changed
+        */
+        pub fn restore_user(&self, path: &Path, username: &str) -> Result<QueryRestore, rusqlite::Error>{
+            self.connection.execute(
+                "ATTACH DATABASE ? AS snapshot KEY ?;",
+                params![path.display().to_string(), self.key.as_deref().unwrap_or("")]
+            )?;
+            let restored = self.restore_user_rows(username);
+            self.connection.execute("DETACH DATABASE snapshot;", ())?;
+            restored
+        }
+
+        /*
+            This Method copies the rows of one user from the attached snapshot database in one transaction
+
+            Pram: username: &str, the user to restore
+            Returns: Result<QueryRestore, rusqlite::Error>, the number of lists restored, or why the user cannot be restored
+        */
+        fn restore_user_rows(&self, username: &str) -> Result<QueryRestore, rusqlite::Error>{
+            let user_id: u32 = match self.connection.query_row(
+                "SELECT user_id FROM snapshot.users WHERE username = ?;",
+                params![username],
+                |row| row.get(0)
+            ).optional()?{
+                Some(user_id) =>{user_id},
+                None =>{return Ok(QueryRestore::UnknownUser);},
+            };
+            // the username belongs to a new account now
+            let account: Option<QueryUser> = self.get_user_by_name(username)?;
+            if account.as_ref().is_some_and(|user| user.user_id != user_id){
+                return Ok(QueryRestore::UnknownUser);
+            }
+
+            // ids are random, another user may have one of the ids in the backup now
+            let conflicts: [(&str, &str); 3] = [
+                ("user", "SELECT user_id FROM main.users WHERE user_id = ?1 AND user_id IS NOT ?2"),
+                ("list", "SELECT list_id FROM snapshot.lists WHERE user_id = ?1
+                    AND list_id IN (SELECT list_id FROM main.lists WHERE user_id IS NOT ?2)"),
+                ("item", "SELECT item_id FROM snapshot.items WHERE list_id IN (SELECT list_id FROM snapshot.lists WHERE user_id = ?1)
+                    AND item_id IN (SELECT item_id FROM main.items WHERE list_id NOT IN (SELECT list_id FROM main.lists WHERE user_id IS ?2))"),
+            ];
+            // the rows being replaced are the account's, none if it was deleted
+            let owner: Option<u32> = account.as_ref().map(|user| user.user_id);
+            let mut conflict: Vec<String> = Vec::new();
+            for (name, query) in conflicts{
+                let ids: Vec<u32> = self.connection
+                    .prepare(query)?
+                    .query_map(params![user_id, owner], |row| row.get(0))?
+                    .collect::<Result<Vec<u32>, rusqlite::Error>>()?;
+                conflict.extend(ids.iter().map(|id| format!("{} {}", name, id)));
+            }
+            if !conflict.is_empty(){
+                return Ok(QueryRestore::Conflict(conflict));
+            }
+
+            let transaction = self.connection.unchecked_transaction()?;
+
+            // the lists now are replaced
+            transaction.execute("DELETE FROM items WHERE list_id IN (SELECT list_id FROM lists WHERE user_id = ?1);", params![user_id])?;
+            transaction.execute("DELETE FROM list_shares WHERE list_id IN (SELECT list_id FROM lists WHERE user_id = ?1);", params![user_id])?;
+            transaction.execute("DELETE FROM lists WHERE user_id = ?1;", params![user_id])?;
+            transaction.execute("DELETE FROM preferences WHERE user_id = ?1;", params![user_id])?;
+
+            // only columns in both databases are copied, an older backup has fewer
+            let copies: [(&str, &str); 5] = [
+                ("users", "user_id = ?1"),
+                ("lists", "user_id = ?1"),
+                ("items", "list_id IN (SELECT list_id FROM snapshot.lists WHERE user_id = ?1)"),
+                ("list_shares", "list_id IN (SELECT list_id FROM snapshot.lists WHERE user_id = ?1)"),
+                ("preferences", "user_id = ?1"),
+            ];
+            let mut lists: usize = 0;
+            for (table, condition) in copies{
+                let columns: Vec<String> = transaction
+                    .prepare("SELECT name FROM pragma_table_info(?1, 'main') WHERE name IN (SELECT name FROM pragma_table_info(?1, 'snapshot'));")?
+                    .query_map(params![table], |row| row.get(0))?
+                    .collect::<Result<Vec<String>, rusqlite::Error>>()?;
+                let columns: String = columns.join(", ");
+                // an account that was not deleted is kept, any other row already there is an error and nothing is changed
+                let insert: &str = if table == "users" {"INSERT OR IGNORE"} else {"INSERT"};
+                let copied: usize = transaction.execute(
+                    &format!("{insert} INTO main.{table} ({columns}) SELECT {columns} FROM snapshot.{table} WHERE {condition};"),
+                    params![user_id]
+                )?;
+                if table == "lists"{
+                    lists = copied;
+                }
+            }
+
+            transaction.commit()?;
+            Ok(QueryRestore::Restored(lists))
+        }
+
         /*
             This Method creates all the tables for a TodoDatabase
             Prams: self
@@ -114,6 +476,9 @@ pub mod database{
         */
         pub fn build_db(&self)-> Result<(), rusqlite::Error>{
 
+            // the bundled SQLCipher turns foreign keys on, rows are removed in any order like with the system SQLite
+            self.connection.pragma_update(None, "foreign_keys", false)?;
+
             // User table
             self.connection.execute(
                 "CREATE TABLE IF NOT EXISTS users (
@@ -148,9 +513,151 @@ pub mod database{
                 ()
             )?;
 
+            // Preferences table, one row per user
+            self.connection.execute(
+                "CREATE TABLE IF NOT EXISTS preferences (
+                    user_id INTEGER PRIMARY KEY,
+                    list_id INTEGER,
+                    item_id INTEGER,
+                    FOREIGN KEY(user_id) REFERENCES users(user_id)
+                 );",
+                ()
+            )?;
+
+            // Failed logins, one row per username
+            self.connection.execute(
+                "CREATE TABLE IF NOT EXISTS login_attempts (
+                    username TEXT PRIMARY KEY,
+                    failures INTEGER NOT NULL DEFAULT 0,
+                    locked_until TEXT
+                 );",
+                ()
+            )?;
+
+            // Every login tried
+            self.connection.execute(
+                "CREATE TABLE IF NOT EXISTS auth_audit (
+                    audit_id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    username TEXT,
+                    success INTEGER,
+                    reason TEXT,
+                    time TEXT
+                 );",
+                ()
+            )?;
+
+            // Lists shared with users that do not own them
+            self.connection.execute(
+                "CREATE TABLE IF NOT EXISTS list_shares (
+                    list_id INTEGER NOT NULL,
+                    user_id INTEGER NOT NULL,
+                    can_write INTEGER NOT NULL DEFAULT 0,
+                    PRIMARY KEY(list_id, user_id),
+                    FOREIGN KEY(list_id) REFERENCES lists(list_id),
+                    FOREIGN KEY(user_id) REFERENCES users(user_id)
+                 );",
+                ()
+            )?;
+
+            // Remembered logins
+            self.connection.execute(
+                "CREATE TABLE IF NOT EXISTS sessions (
+                    token_hash TEXT PRIMARY KEY,
+                    user_id INTEGER NOT NULL,
+                    expires TEXT NOT NULL,
+                    FOREIGN KEY(user_id) REFERENCES users(user_id)
+                 );",
+                ()
+            )?;
+
+            // One time recovery codes for two-factor authentication, stored hashed
+            self.connection.execute(
+                "CREATE TABLE IF NOT EXISTS recovery_codes (
+                    code_hash TEXT PRIMARY KEY,
+                    user_id INTEGER NOT NULL,
+                    FOREIGN KEY(user_id) REFERENCES users(user_id)
+                 );",
+                ()
+            )?;
+
+            // columns added after the tables were first created
+            self.add_column_if_missing("lists", "item_filter", "INTEGER NOT NULL DEFAULT 0")?;
+            self.add_column_if_missing("lists", "item_sort", "INTEGER NOT NULL DEFAULT 0")?;
+            self.add_column_if_missing("items", "created", "TEXT")?;
+            self.add_column_if_missing("items", "due", "TEXT")?;
+            self.add_column_if_missing("items", "priority", "INTEGER NOT NULL DEFAULT 0")?;
+            self.add_column_if_missing("items", "completed_at", "TEXT")?;
+            self.add_column_if_missing("lists", "board_columns", "TEXT")?;
+            self.add_column_if_missing("items", "status", "INTEGER NOT NULL DEFAULT 0")?;
+            self.add_column_if_missing("items", "assignee", "INTEGER")?;
+            self.add_column_if_missing("users", "totp_secret", "TEXT")?;
+            self.add_column_if_missing("users", "totp_step", "INTEGER")?;
+            // each user a list is shared with has their own filter and sort
+            self.add_column_if_missing("list_shares", "item_filter", "INTEGER NOT NULL DEFAULT 0")?;
+            self.add_column_if_missing("list_shares", "item_sort", "INTEGER NOT NULL DEFAULT 0")?;
+            // ids are random, the order rows were added in, older rows have none
+            self.add_column_if_missing("lists", "position", "INTEGER")?;
+            self.add_column_if_missing("items", "position", "INTEGER")?;
+            // positions count up within each user's lists and each list's items
+            self.connection.execute("CREATE INDEX IF NOT EXISTS lists_position ON lists(user_id, position);", ())?;
+            self.connection.execute("CREATE INDEX IF NOT EXISTS items_position ON items(list_id, position);", ())?;
+            // tokens issued before two-factor authentication was turned on are refused
+            self.add_column_if_missing("sessions", "two_factor", "INTEGER NOT NULL DEFAULT 0")?;
+
+            // search scans the items table, see match_items
+            self.remove_search_index()?;
+
             return Ok(());
         }
 
+        /*
+            This Method adds a column to a table created by an older version
+
+            Prams:
+                table: the table to add the column to
+                column: the name of the column
+                definition: the column type and constraints
+
+            Returns: Result< Ok, Err>
+                Ok(): the column exists
+                Err: there was an error while running the SQL commands
+        */
+        fn add_column_if_missing(&self, table: &str, column: &str, definition: &str)-> Result<(), rusqlite::Error>{
+
+            // look for the column in the table info
+            let mut table_info = self.connection.prepare(&format!("PRAGMA table_info({});", table))?;
+            let columns: Vec<String> = table_info
+                .query_map((), |row| row.get("name"))?
+                .collect::<Result<Vec<String>, rusqlite::Error>>()?;
+
+            if !columns.iter().any(|name| name == column){
+                self.connection.execute(
+                    &format!("ALTER TABLE {} ADD COLUMN {} {};", table, column, definition),
+                    ()
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /*
+            This Method removes the FTS5 search index an older version kept of item names.
+            FTS5 matches words, not the fuzzy match search uses, so the index was never read
+            and its triggers only slowed every write to items.
+
+            Returns: Result< Ok, Err>
+                Ok(): the index and its triggers do not exist
+                Err: there was an error while running the SQL commands
+        */
+        fn remove_search_index(&self)-> Result<(), rusqlite::Error>{
+            for trigger in ["items_fts_insert", "items_fts_delete", "items_fts_update"]{
+                self.connection.execute(&format!("DROP TRIGGER IF EXISTS {};", trigger), ())?;
+            }
+            self.connection.execute("DROP TABLE IF EXISTS items_fts;", ())?;
+
+            Ok(())
+        }
+
         /*
             This method creates a user in the user table.
 
@@ -197,75 +704,765 @@ pub mod database{
                 "
             )?;
 
-            // Execute the query and map results to QueryUser
-            let rows = qury_user_id
-                .query_map(
-                    params![username, password], |row| Ok(QueryUser{
-                        user_id: row.get("user_id")?,
-                        username: row.get("username")?,
-                        password: row.get("password")?,
-                    }
-                )
+            // Execute the query and map results to QueryUser
+            let rows = qury_user_id
+                .query_map(
+                    params![username, password], |row| Ok(QueryUser{
+                        user_id: row.get("user_id")?,
+                        username: row.get("username")?,
+                        password: row.get("password")?,
+                    }
+                )
+            )?;
+
+            // Collect all results
+            let collected: Result<Vec<QueryUser>, rusqlite::Error> = rows.collect();
+
+            return collected;      
+        }
+
+        /*
+            This method queries the database for the user with a username
+
+            Prams:
+                username: account username
+
+            Returns: Result< Ok(Option<QueryUser>), Err>
+                Ok(Some(QueryUser)): the user with the username
+                Ok(None): no user has the username
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_user_by_name(&self, username: &str) -> Result<Option<QueryUser>, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT user_id, username, password FROM users WHERE username = ?;",
+                params![username],
+                |row| Ok(QueryUser{
+                    user_id: row.get("user_id")?,
+                    username: row.get("username")?,
+                    password: row.get("password")?,
+                })
+            ).optional()
+        }
+
+        /*
+            This method queries the database for the user with a user_id
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Result< Ok(Option<QueryUser>), Err>
+                Ok(Some(QueryUser)): the user with the user_id
+                Ok(None): no user has the user_id
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_user_by_id(&self, user_id: u32) -> Result<Option<QueryUser>, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT user_id, username, password FROM users WHERE user_id = ?;",
+                params![user_id],
+                |row| Ok(QueryUser{
+                    user_id: row.get("user_id")?,
+                    username: row.get("username")?,
+                    password: row.get("password")?,
+                })
+            ).optional()
+        }
+
+        /*
+            This method changes the username of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+                username: the new username, must not belong to another user
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands, a taken username is a UNIQUE constraint error
+        */
+        pub fn update_username(&self, user_id: u32, username: &str)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "UPDATE users SET username = ? WHERE user_id = ?;",
+                params![username, user_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method changes the password of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+                password: the new password
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_password(&self, user_id: u32, password: &str)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "UPDATE users SET password = ? WHERE user_id = ?;",
+                params![password, user_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method gets the failed logins of a username
+
+            Prams:
+                username: the username tried
+
+            Returns: Result< Ok, Err>
+                Ok(Some(QueryLoginAttempts)): the failed logins
+                Ok(None): the username has no failed logins
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_login_attempts(&self, username: &str) -> Result<Option<QueryLoginAttempts>, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT username, failures, locked_until FROM login_attempts WHERE username = ?;",
+                params![username],
+                |row| Ok(QueryLoginAttempts{
+                    username: row.get("username")?,
+                    failures: row.get("failures")?,
+                    locked_until: row.get("locked_until")?,
+                })
+            ).optional()
+        }
+
+        /*
+            This method stores the failed logins of a username
+
+            Prams:
+                username: the username tried
+                failures: failed logins since the last successful login
+                locked_until: logins are refused until this time, None if not locked
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_login_attempts(&self, username: &str, failures: u32, locked_until: Option<NaiveDateTime>)->  Result<(), rusqlite::Error>{
+
+            self.connection.execute(
+                "
+                INSERT INTO login_attempts (username, failures, locked_until) VALUES (?1, ?2, ?3)
+                ON CONFLICT(username) DO UPDATE SET failures = ?2, locked_until = ?3;
+                ",
+                params![username, failures, locked_until.map(|time| time.format(DATE_TIME_FORMAT).to_string())]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method forgets the failed logins of a username, after a successful login
+
+            Prams:
+                username: the username logged in
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn clear_login_attempts(&self, username: &str)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "DELETE FROM login_attempts WHERE username = ?;",
+                params![username]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method adds a login to the auth audit table
+
+            Prams:
+                username: the username tried
+                success: true if the user logged in
+                reason: why the login failed, empty if it succeeded
+                time: when the login was tried
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn insert_auth_audit(&self, username: &str, success: bool, reason: &str, time: NaiveDateTime)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "INSERT INTO auth_audit (username, success, reason, time) VALUES (?, ?, ?, ?);",
+                params![username, success as u32, reason, time.format(DATE_TIME_FORMAT).to_string()]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method gets the logins tried with a username
+
+            Prams:
+                username: the username tried
+
+            Returns: Result< Ok, Err>
+                Ok(Vec<QueryAuthAudit>): the logins, oldest first
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_auth_audit(&self, username: &str) -> Result<Vec<QueryAuthAudit>, rusqlite::Error>{
+
+            // prepare the query
+            let mut query_audit = self.connection.prepare(
+                "
+                SELECT audit_id, username, success, reason, time
+                FROM auth_audit
+                WHERE username = ?
+                ORDER BY audit_id;
+                "
+            )?;
+
+            // execute the query, map each row to QueryAuthAudit
+            let rows = query_audit
+                .query_map(
+                    params![username], |row| Ok(QueryAuthAudit{
+                        audit_id: row.get("audit_id")?,
+                        username: row.get("username")?,
+                        success: row.get("success")?,
+                        reason: row.get("reason")?,
+                        time: row.get("time")?,
+                    }
+                )
+            )?;
+
+            // collect results
+            rows.collect()
+        }
+
+        /*
+            This method stores a remembered login
+
+            Prams:
+                token_hash: the SHA-256 of the token
+                user_id: the user logged in by the token
+                expires: when the token is refused
+                two_factor: the user had two-factor authentication on when logging in
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn insert_session(&self, token_hash: &str, user_id: u32, expires: NaiveDateTime, two_factor: bool)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "INSERT INTO sessions (token_hash, user_id, expires, two_factor) VALUES (?, ?, ?, ?);",
+                params![token_hash, user_id, expires.format(DATE_TIME_FORMAT).to_string(), two_factor]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method finds a remembered login by the hash of its token
+
+            Prams:
+                token_hash: the SHA-256 of the token
+
+            Returns: Result< Ok, Err>
+                Ok(Some(QuerySession)): the remembered login
+                Ok(None): no login has the token
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_session(&self, token_hash: &str) -> Result<Option<QuerySession>, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT token_hash, user_id, expires, two_factor FROM sessions WHERE token_hash = ?;",
+                params![token_hash],
+                |row| Ok(QuerySession{
+                    token_hash: row.get("token_hash")?,
+                    user_id: row.get("user_id")?,
+                    expires: row.get("expires")?,
+                    two_factor: row.get("two_factor")?,
+                })
+            ).optional()
+        }
+
+        /*
+            This method counts the remembered logins of a user that have not expired
+
+            Prams:
+                user_id: the unique identifier for a user
+                now: the current time
+
+            Returns: Result< Ok, Err>
+                Ok(u32): the number of remembered logins
+                Err: there was an error while running the SQL commands
+        */
+        pub fn count_sessions(&self, user_id: u32, now: NaiveDateTime) -> Result<u32, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT count(*) FROM sessions WHERE user_id = ? AND expires > ?;",
+                params![user_id, now.format(DATE_TIME_FORMAT).to_string()],
+                |row| row.get(0)
+            )
+        }
+
+        /*
+            This method forgets one remembered login
+
+            Prams:
+                token_hash: the SHA-256 of the token
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn remove_session(&self, token_hash: &str)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "DELETE FROM sessions WHERE token_hash = ?;",
+                params![token_hash]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method forgets every remembered login of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn remove_user_sessions(&self, user_id: u32)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "DELETE FROM sessions WHERE user_id = ?;",
+                params![user_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method finds the two-factor authentication of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Result< Ok, Err>
+                Ok(Some(QueryTwoFactor)): the user turned on two-factor authentication
+                Ok(None): the user does not use two-factor authentication
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_two_factor(&self, user_id: u32) -> Result<Option<QueryTwoFactor>, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT totp_secret, totp_step FROM users WHERE user_id = ? AND totp_secret IS NOT NULL;",
+                params![user_id],
+                |row| Ok(QueryTwoFactor{
+                    secret: row.get("totp_secret")?,
+                    last_step: row.get("totp_step")?,
+                })
+            ).optional()
+        }
+
+        /*
+            This method turns two-factor authentication on or off for a user
+
+            Prams:
+                user_id: the unique identifier for a user
+                secret: the TOTP secret as base32, None turns it off
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_totp_secret(&self, user_id: u32, secret: Option<&str>)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "UPDATE users SET totp_secret = ?, totp_step = NULL WHERE user_id = ?;",
+                params![secret, user_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method stores the time step of the last code a user logged in with
+
+            Prams:
+                user_id: the unique identifier for a user
+                step: the time step of the code
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_totp_step(&self, user_id: u32, step: u64)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "UPDATE users SET totp_step = ? WHERE user_id = ?;",
+                params![step, user_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method replaces the recovery codes of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+                code_hashes: the hashes of the new codes, empty removes every code
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn replace_recovery_codes(&self, user_id: u32, code_hashes: &[String])->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "DELETE FROM recovery_codes WHERE user_id = ?;",
+                params![user_id]
+            )?;
+            for code_hash in code_hashes{
+                self.connection.execute(
+                    "INSERT INTO recovery_codes (code_hash, user_id) VALUES (?, ?);",
+                    params![code_hash, user_id]
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /*
+            This method uses up a recovery code of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+                code_hash: the hash of the code typed
+
+            Returns: Result< Ok, Err>
+                Ok(bool): true if the code was unused and is now removed
+                Err: there was an error while running the SQL commands
+        */
+        pub fn use_recovery_code(&self, user_id: u32, code_hash: &str)->  Result<bool, rusqlite::Error>{
+            let removed: usize = self.connection.execute(
+                "DELETE FROM recovery_codes WHERE code_hash = ? AND user_id = ?;",
+                params![code_hash, user_id]
+            )?;
+
+            Ok(removed > 0)
+        }
+
+        /*
+            This method counts the unused recovery codes of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Result< Ok, Err>
+                Ok(u32): the number of unused codes
+                Err: there was an error while running the SQL commands
+        */
+        pub fn count_recovery_codes(&self, user_id: u32) -> Result<u32, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT count(*) FROM recovery_codes WHERE user_id = ?;",
+                params![user_id],
+                |row| row.get(0)
+            )
+        }
+
+        /*
+            This method finds all lists and items that belong to a user_id
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Vector of TodoLists
+        */
+        pub fn load_user_data(&self, user_id: u32) -> Vec<TodoList>{
+
+            // get a vec of all list rows with user_id
+            // create empty vec if user was not found
+            let user_lists: Vec<QueryLists> = match self.get_user_lists(user_id){
+                Ok(res) =>{res},
+                Err(_err) =>{Vec::new()}, 
+            };
+
+            // create vec of TodoLists
+            let mut user_data: Vec<TodoList> = user_lists.iter().map(|list| self.load_list(list)).collect();
+
+            // lists other users shared follow the user's own lists
+            let shared_lists: Vec<QuerySharedList> = match self.get_shared_lists(user_id){
+                Ok(res) =>{res},
+                Err(_err) =>{Vec::new()},
+            };
+            shared_lists.into_iter().for_each(|shared|{
+                let mut current_list = self.load_list(&shared.list);
+                current_list.set_shared(shared.owner, ListAccess::from_code(shared.can_write));
+                user_data.push(current_list);
+            });
+
+            return user_data;
+        }
+
+        /*
+            This method creates a TodoList with all of its items from a lists row
+
+            Prams:
+                list: the lists row
+
+            Returns: TodoList, empty if the items could not be read
+        */
+        fn load_list(&self, list: &QueryLists) -> TodoList{
+            // create the TodoList
+            let mut current_list = TodoList::new(list.list_name.clone(), list.list_id);
+            current_list.set_filter(ItemFilter::from_code(list.item_filter));
+            current_list.set_sort(ItemSort::from_code(list.item_sort));
+
+            // query data base for all items rows with list_id
+            let list_items: Vec<QueryItems> = match self.get_list_items(list.list_id){
+                Ok(res) =>{res},
+                Err(_err) =>{Vec::new()},
+            };
+
+            // Create TodoItems for every item row 
+            list_items.iter().for_each(|item|{
+                let mut complete_status = false;
+                if item.complete > 0{
+                    complete_status = true;
+                }
+                let mut current_item = TodoItem::new_from_load(
+                    item.item_name.clone(), 
+                    item.item_id, 
+                    complete_status
+                );
+                current_item.set_created(item.created.as_ref().and_then(|created| NaiveDateTime::parse_from_str(created, DATE_TIME_FORMAT).ok()));
+                current_item.set_due(item.due.as_ref().and_then(|due| NaiveDate::parse_from_str(due, DATE_FORMAT).ok()));
+                current_item.set_priority(item.priority);
+                current_item.set_status(item.status, complete_status);
+                current_item.set_assignee(item.assignee);
+                // push to current list 
+                current_list.list.push(current_item);
+            });
+            // setting the columns also moves items into a valid column
+            let columns: Vec<String> = match &list.board_columns{
+                Some(columns) =>{columns.split(',').map(String::from).collect()},
+                None =>{DEFAULT_COLUMNS.iter().map(|column| String::from(*column)).collect()},
+            };
+            current_list.set_columns(columns);
+            current_list.set_members(self.get_list_members(list.list_id).unwrap_or_default());
+            current_list
+        }
+
+        /*
+            This method finds the lists other users shared with a user
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Result< Ok, Err>
+                Ok(Vec<QuerySharedList>): the shared lists with their owner
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_shared_lists(&self, user_id: u32) -> Result<Vec<QuerySharedList>, rusqlite::Error>{
+
+            // prepare the query
+            let mut query_shared = self.connection.prepare(
+                "
+                SELECT lists.list_id, lists.user_id, lists.list_name, lists.board_columns,
+                    list_shares.item_filter, list_shares.item_sort, users.username AS owner, list_shares.can_write
+                FROM list_shares
+                JOIN lists ON lists.list_id = list_shares.list_id
+                JOIN users ON users.user_id = lists.user_id
+                WHERE list_shares.user_id = ?
+                ORDER BY lists.position, lists.list_id;
+                "
+            )?;
+
+            // execute the query, map each row to QuerySharedList
+            let rows = query_shared
+                .query_map(
+                    params![user_id], |row| Ok(QuerySharedList{
+                        list: QueryLists{
+                            user_id: row.get("user_id")?,
+                            list_id: row.get("list_id")?,
+                            list_name: row.get("list_name")?,
+                            item_filter: row.get("item_filter")?,
+                            item_sort: row.get("item_sort")?,
+                            board_columns: row.get("board_columns")?,
+                        },
+                        owner: row.get("owner")?,
+                        can_write: row.get("can_write")?,
+                    }
+                )
+            )?;
+
+            // collect results
+            rows.collect()
+        }
+
+        /*
+            This method finds the users a list is shared with
+
+            Prams:
+                list_id: the unique identifier for a list
+
+            Returns: Result< Ok, Err>
+                Ok(Vec<QueryListShare>): every user the list is shared with
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_list_shares(&self, list_id: u32) -> Result<Vec<QueryListShare>, rusqlite::Error>{
+
+            // prepare the query
+            let mut query_shares = self.connection.prepare(
+                "
+                SELECT list_shares.list_id, list_shares.user_id, users.username, list_shares.can_write
+                FROM list_shares
+                JOIN users ON users.user_id = list_shares.user_id
+                WHERE list_shares.list_id = ?
+                ORDER BY users.username;
+                "
+            )?;
+
+            // execute the query, map each row to QueryListShare
+            let rows = query_shares
+                .query_map(
+                    params![list_id], |row| Ok(QueryListShare{
+                        list_id: row.get("list_id")?,
+                        user_id: row.get("user_id")?,
+                        username: row.get("username")?,
+                        can_write: row.get("can_write")?,
+                    }
+                )
+            )?;
+
+            // collect results
+            rows.collect()
+        }
+
+        /*
+            This method finds the users with access to a list
+
+            Prams:
+                list_id: the unique identifier for a list
+
+            Returns: Result< Ok, Err>
+                Ok(Vec<ListMember>): the owner first, then the users the list is shared with by username
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_list_members(&self, list_id: u32) -> Result<Vec<ListMember>, rusqlite::Error>{
+
+            // prepare the query
+            let mut query_members = self.connection.prepare(
+                "
+                SELECT users.user_id, users.username, 0 AS shared
+                FROM lists
+                JOIN users ON users.user_id = lists.user_id
+                WHERE lists.list_id = ?1
+                UNION ALL
+                SELECT users.user_id, users.username, 1 AS shared
+                FROM list_shares
+                JOIN users ON users.user_id = list_shares.user_id
+                WHERE list_shares.list_id = ?1
+                ORDER BY shared, username;
+                "
+            )?;
+
+            // execute the query, map each row to ListMember
+            let rows = query_members
+                .query_map(
+                    params![list_id], |row| Ok(ListMember{
+                        user_id: row.get("user_id")?,
+                        username: row.get("username")?,
+                    }
+                )
+            )?;
+
+            // collect results
+            rows.collect()
+        }
+
+        /*
+            This method shares a list with a user, or changes the access of a user it is shared with
+
+            Prams:
+                list_id: the unique identifier for a list
+                user_id: the user to share with
+                can_write: true for read-write, false for read only
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn share_list(&self, list_id: u32, user_id: u32, can_write: bool)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "
+                INSERT INTO list_shares (list_id, user_id, can_write) VALUES (?1, ?2, ?3)
+                ON CONFLICT(list_id, user_id) DO UPDATE SET can_write = ?3;
+                ",
+                params![list_id, user_id, can_write as u32]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method stops sharing a list with a user
+
+            Prams:
+                list_id: the unique identifier for a list
+                user_id: the user the list was shared with
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn unshare_list(&self, list_id: u32, user_id: u32)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "DELETE FROM list_shares WHERE list_id = ? AND user_id = ?;",
+                params![list_id, user_id]
             )?;
 
-            // Collect all results
-            let collected: Result<Vec<QueryUser>, rusqlite::Error> = rows.collect();
+            // the user is no longer responsible for items of the list
+            self.connection.execute(
+                "UPDATE items SET assignee = NULL WHERE list_id = ? AND assignee = ?;",
+                params![list_id, user_id]
+            )?;
 
-            return collected;      
+            Ok(())
         }
 
         /*
-            This method finds all lists and items that belong to a user_id
+            This method finds the items of all lists a user can see whose name matches a LIKE pattern.
+            SQLite only ignores the case of ASCII letters.
 
             Prams:
                 user_id: the unique identifier for a user
+                pattern: LIKE pattern escaped with '\', see search::like_pattern
 
-            Returns: Vector of TodoLists
+            Returns: Result< Ok, Err>
+                Ok(Vec<QuerySearch>): all matching items, in no order
+                Err: there was an error while running the SQL commands
         */
-        pub fn load_user_data(&self, user_id: u32) -> Vec<TodoList>{
+        pub fn match_items(&self, user_id: u32, pattern: &str) -> Result<Vec<QuerySearch>, rusqlite::Error>{
 
-            // get a vec of all list rows with user_id
-            // create empty vec if user was not found
-            let user_lists: Vec<QueryLists> = match self.get_user_lists(user_id){
-                Ok(res) =>{res},
-                Err(_err) =>{Vec::new()}, 
-            };
+            let mut query_match = self.connection.prepare(
+                "
+                SELECT items.item_id, items.list_id, items.item_name, lists.list_name
+                FROM items
+                JOIN lists ON lists.list_id = items.list_id
+                WHERE items.item_name LIKE ?1 ESCAPE '\\'
+                AND (lists.user_id = ?2 OR lists.list_id IN (SELECT list_id FROM list_shares WHERE user_id = ?2));
+                "
+            )?;
 
-            // create vec of TodoLists
-            let mut user_data: Vec<TodoList> = Vec::new();
-
-            // iterate through the list rows 
-            // look for items with matching list_id
-            user_lists.iter().for_each(|list|{
-                // create the TodoList
-                let mut current_list = TodoList::new(list.list_name.clone(), list.list_id);
-
-                // query data base for all items rows with list_id
-                let list_items: Vec<QueryItems> = match self.get_list_items(list.list_id){
-                    Ok(res) =>{res},
-                    Err(_err) =>{Vec::new()},
-                };
-
-                // Create TodoItems for every item row 
-                list_items.iter().for_each(|item|{
-                    let mut complete_status = false;
-                    if item.complete > 0{
-                        complete_status = true;
+            let rows = query_match
+                .query_map(
+                    params![pattern, user_id], |row| Ok(QuerySearch{
+                        list_id: row.get("list_id")?,
+                        item_id: row.get("item_id")?,
+                        list_name: row.get("list_name")?,
+                        item_name: row.get("item_name")?,
                     }
-                    let current_item = TodoItem::new_from_load(
-                        item.item_name.clone(), 
-                        item.item_id, 
-                        complete_status
-                    );
-                    // push to current list 
-                    current_list.list.push(current_item);
-                });
-                // for each time create and add
-                user_data.push(current_list);
-            });
+                )
+            )?;
 
-            return user_data;
+            rows.collect()
         }
+
         /*
             This method queries the list table to find all lists with user_id
 
@@ -273,7 +1470,7 @@ pub mod database{
                 user_id: the unique identifier for a user
             
             Returns: Result< Ok, Err>
-                Ok(Vec<QueryLists>): vector of all list rows found
+                Ok(Vec<QueryLists>): vector of all list rows found, in the order they were added
                 Err: there was an error while running the SQL commands
         */
         fn get_user_lists(&self, user_id: u32) -> Result<Vec<QueryLists>, rusqlite::Error>{
@@ -283,7 +1480,8 @@ pub mod database{
                 "
                 SELECT *
                 FROM lists
-                WHERE user_id = ?;
+                WHERE user_id = ?
+                ORDER BY position, list_id;
                 "
             )?;
 
@@ -294,6 +1492,9 @@ pub mod database{
                         user_id: row.get("user_id")?,
                         list_id: row.get("list_id")?,
                         list_name: row.get("list_name")?,
+                        item_filter: row.get("item_filter")?,
+                        item_sort: row.get("item_sort")?,
+                        board_columns: row.get("board_columns")?,
                     }
                 )
             )?;
@@ -312,7 +1513,7 @@ pub mod database{
                 list_id: the unique identifier for a list
 
             Returns: Result< Ok, Err>
-                Ok(Vec<QueryItems>):  vector of all item rows found
+                Ok(Vec<QueryItems>):  vector of all item rows found, in the order they were added
                 Err: there was an error while running the SQL commands
         */
         fn get_list_items(&self, list_id: u32) -> Result<Vec<QueryItems>, rusqlite::Error>{
@@ -322,7 +1523,8 @@ pub mod database{
                 "
                 SELECT *
                 FROM items
-                WHERE list_id = ?;
+                WHERE list_id = ?
+                ORDER BY position, created, item_id;
                 "
             )?;
 
@@ -334,7 +1536,11 @@ pub mod database{
                         list_id: row.get("list_id")?,
                         item_name: row.get("item_name")?,
                         complete: row.get("complete")?,
-
+                        created: row.get("created")?,
+                        due: row.get("due")?,
+                        priority: row.get("priority")?,
+                        status: row.get("status")?,
+                        assignee: row.get("assignee")?,
                     }
                 )
             )?;
@@ -359,7 +1565,8 @@ pub mod database{
         */
         pub fn insert_into_list(&self, list_name: String, list_id: u32, user_id: u32) -> Result<(), rusqlite::Error>{
             self.connection.execute(
-                "INSERT INTO lists (list_id, user_id, list_name) values(?, ?, ?)",
+                "INSERT INTO lists (list_id, user_id, list_name, position)
+                    values(?1, ?2, ?3, (SELECT IFNULL(MAX(position), 0) + 1 FROM lists WHERE user_id = ?2))",
                 params![list_id, user_id, &list_name,] 
             )?;
             // ERROR could not find user
@@ -388,8 +1595,13 @@ pub mod database{
                     item_id,
                     list_id,
                     item_name,  
-                    complete)
-                    values(?,?,?,?)",
+                    complete,
+                    created,
+                    completed_at,
+                    position)
+                    values(?1,?2,?3,?4,datetime('now', 'localtime'),
+                        CASE WHEN ?4 > 0 THEN datetime('now', 'localtime') END,
+                        (SELECT IFNULL(MAX(position), 0) + 1 FROM items WHERE list_id = ?2))",
                 params![item_id, list_id, &item_name, complete]
             )?;
             // Could not find list ID
@@ -409,12 +1621,42 @@ pub mod database{
         pub fn remove_user(&self, user_id: u32) ->  Result<(), rusqlite::Error>{
 
             // Get user lists
-            let lists: Vec<QueryLists> = self.get_user_lists(user_id).unwrap();
+            let lists: Vec<QueryLists> = self.get_user_lists(user_id)?;
             // remove all list and items
             for list in lists{
-                self.remove_list(list.list_id, user_id);
+                self.remove_list(list.list_id, user_id)?;
             }
 
+            // remove the user preferences
+            self.connection.execute(
+                "DELETE FROM preferences WHERE user_id = ?;",
+                params![user_id]
+            )?;
+
+            // forget the remembered logins
+            self.remove_user_sessions(user_id)?;
+
+            // remove the recovery codes
+            self.replace_recovery_codes(user_id, &[])?;
+
+            // remove the lists other users shared with the user
+            self.connection.execute(
+                "DELETE FROM list_shares WHERE user_id = ?;",
+                params![user_id]
+            )?;
+
+            // unassign the items the user was responsible for
+            self.connection.execute(
+                "UPDATE items SET assignee = NULL WHERE assignee = ?;",
+                params![user_id]
+            )?;
+
+            // remove the failed logins so a new account can reuse the username
+            self.connection.execute(
+                "DELETE FROM login_attempts WHERE username = (SELECT username FROM users WHERE user_id = ?);",
+                params![user_id]
+            )?;
+
             // remove the list from list table
             self.connection.execute(
                 "DELETE FROM users WHERE user_id = ?;",
@@ -438,9 +1680,16 @@ pub mod database{
         pub fn remove_list(&self, list_id: u32, user_id: u32) ->  Result<(), rusqlite::Error>{
 
             // remove items owned by list_id from the item table
+            // only when the user owns the list, a list shared with the user is not removed
+            self.connection.execute(
+                "DELETE FROM items WHERE list_id = (SELECT list_id FROM lists WHERE list_id = ?1 AND user_id = ?2);",
+                params![list_id, user_id]
+            )?;
+
+            // stop sharing the list
             self.connection.execute(
-                "DELETE FROM items WHERE list_id = ?;",
-                params![list_id]
+                "DELETE FROM list_shares WHERE list_id = (SELECT list_id FROM lists WHERE list_id = ?1 AND user_id = ?2);",
+                params![list_id, user_id]
             )?;
 
             // remove the list from list table
@@ -475,7 +1724,8 @@ pub mod database{
 
         }
         /*
-            This method update a item in the item table
+            This method update a item in the item table.
+            Completing an item records when it was completed.
 
             Prams:
                 item_id: the unique identifier for a item
@@ -491,8 +1741,9 @@ pub mod database{
             self.connection.execute(
                 "
                 UPDATE items 
-                SET complete = ?
-                WHERE item_id = ? AND list_id = ?;
+                SET complete = ?1,
+                    completed_at = CASE WHEN ?1 > 0 THEN datetime('now', 'localtime') END
+                WHERE item_id = ?2 AND list_id = ?3;
                 ",
                 params![complete, item_id, list_id]
             )?;
@@ -500,5 +1751,300 @@ pub mod database{
             // ERROR: Could not find Item ID
             Ok(())
         }
+
+        /*
+            This method moves an item to a board column.
+            Moving to the done column completes the item and records when.
+
+            Prams:
+                item_id: the unique identifier for a item
+                list_id: the unique identifier for a list
+                status: the board column
+                complete: complete status, 1 = true, 0 = false
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_item_status(&self, item_id: u32, list_id: u32, status: u32, complete: u32)->  Result<(), rusqlite::Error>{
+
+            // completed_at is kept if the item was already complete
+            self.connection.execute(
+                "
+                UPDATE items 
+                SET status = ?1,
+                    completed_at = CASE WHEN ?2 = 0 THEN NULL
+                        WHEN complete > 0 THEN completed_at
+                        ELSE datetime('now', 'localtime') END,
+                    complete = ?2
+                WHERE item_id = ?3 AND list_id = ?4;
+                ",
+                params![status, complete, item_id, list_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method stores the board columns of a list
+
+            Prams:
+                list_id: the unique identifier for a list
+                user_id: the unique identifier for a user
+                columns: the column names, the last is the done column
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands,
+                    QueryReturnedNoRows if the user does not own the list
+        */
+        pub fn update_list_columns(&self, list_id: u32, user_id: u32, columns: &[String])->  Result<(), rusqlite::Error>{
+
+            let updated: usize = self.connection.execute(
+                "
+                UPDATE lists 
+                SET board_columns = ?
+                WHERE list_id = ? AND user_id = ?;
+                ",
+                params![columns.join(","), list_id, user_id]
+            )?;
+
+            // columns are shared by everyone who sees the list, only the owner changes them
+            if updated == 0{
+                return Err(rusqlite::Error::QueryReturnedNoRows);
+            }
+            Ok(())
+        }
+
+        /*
+            This method updates the due date and priority of an item
+
+            Prams:
+                item_id: the unique identifier for a item
+                list_id: the unique identifier for a list
+                due: the due date, None clears it
+                priority: the item priority, 0 = none
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_item_details(&self, item_id: u32, list_id: u32, due: Option<NaiveDate>, priority: u32)->  Result<(), rusqlite::Error>{
+
+            let due: Option<String> = due.map(|date| date.format(DATE_FORMAT).to_string());
+
+            self.connection.execute(
+                "
+                UPDATE items 
+                SET due = ?, priority = ?
+                WHERE item_id = ? AND list_id = ?;
+                ",
+                params![due, priority, item_id, list_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method sets the user responsible for an item
+
+            Prams:
+                item_id: the unique identifier for a item
+                list_id: the unique identifier for a list
+                assignee: the user_id of the user, None unassigns the item
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_item_assignee(&self, item_id: u32, list_id: u32, assignee: Option<u32>)->  Result<(), rusqlite::Error>{
+            self.connection.execute(
+                "
+                UPDATE items 
+                SET assignee = ?
+                WHERE item_id = ? AND list_id = ?;
+                ",
+                params![assignee, item_id, list_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method stores how a list shows its items to a user.
+            The owner's view is stored with the list, a shared list's view with the share.
+
+            Prams:
+                list_id: the unique identifier for a list
+                user_id: the unique identifier for a user
+                filter: which items are shown
+                sort: the order items are shown in
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands,
+                    QueryReturnedNoRows if the user does not own the list and it is not shared with them
+        */
+        pub fn update_list_view(&self, list_id: u32, user_id: u32, filter: ItemFilter, sort: ItemSort)->  Result<(), rusqlite::Error>{
+
+            let mut updated: usize = self.connection.execute(
+                "
+                UPDATE lists 
+                SET item_filter = ?, item_sort = ?
+                WHERE list_id = ? AND user_id = ?;
+                ",
+                params![filter.to_code(), sort.to_code(), list_id, user_id]
+            )?;
+
+            if updated == 0{
+                updated = self.connection.execute(
+                    "
+                    UPDATE list_shares
+                    SET item_filter = ?, item_sort = ?
+                    WHERE list_id = ? AND user_id = ?;
+                    ",
+                    params![filter.to_code(), sort.to_code(), list_id, user_id]
+                )?;
+            }
+
+            if updated == 0{
+                return Err(rusqlite::Error::QueryReturnedNoRows);
+            }
+            Ok(())
+        }
+
+        /*
+            This method gets the preferences of a user
+
+            Prams:
+                user_id: the unique identifier for a user
+
+            Returns: Result< Ok, Err>
+                Ok(Some(QueryPreferences)): the stored preferences
+                Ok(None): the user has no preferences stored
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_preferences(&self, user_id: u32) -> Result<Option<QueryPreferences>, rusqlite::Error>{
+            self.connection.query_row(
+                "SELECT user_id, list_id, item_id FROM preferences WHERE user_id = ?;",
+                params![user_id],
+                |row| Ok(QueryPreferences{
+                    user_id: row.get("user_id")?,
+                    list_id: row.get("list_id")?,
+                    item_id: row.get("item_id")?,
+                })
+            ).optional()
+        }
+
+        /*
+            This method stores the list and item a user last selected
+
+            Prams:
+                user_id: the unique identifier for a user
+                list_id: the selected list, None if there are no lists
+                item_id: the selected item, None if no item is selected
+
+            Returns: Result< Ok, Err>
+                Ok(): the SQL commands ran without error
+                Err: there was an error while running the SQL commands
+        */
+        pub fn update_preferences(&self, user_id: u32, list_id: Option<u32>, item_id: Option<u32>)->  Result<(), rusqlite::Error>{
+
+            self.connection.execute(
+                "
+                INSERT INTO preferences (user_id, list_id, item_id) VALUES (?1, ?2, ?3)
+                ON CONFLICT(user_id) DO UPDATE SET list_id = ?2, item_id = ?3;
+                ",
+                params![user_id, list_id, item_id]
+            )?;
+
+            Ok(())
+        }
+
+        /*
+            This method counts the items of every list owned by a user
+
+            Prams:
+                user_id: the unique identifier for a user
+                today: items due before today and not complete are overdue
+
+            Returns: Result< Ok, Err>
+                Ok(Vec<QueryListStats>): the counts of each list, in list order
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_list_stats(&self, user_id: u32, today: NaiveDate) -> Result<Vec<QueryListStats>, rusqlite::Error>{
+
+            // prepare the query, lists without items count as 0
+            let mut query_stats = self.connection.prepare(
+                "
+                SELECT lists.list_id, lists.list_name,
+                    COUNT(items.item_id) AS total,
+                    COALESCE(SUM(items.complete > 0), 0) AS done,
+                    COALESCE(SUM(items.complete = 0 AND items.due < ?), 0) AS overdue
+                FROM lists
+                LEFT JOIN items ON items.list_id = lists.list_id
+                WHERE lists.user_id = ?
+                GROUP BY lists.list_id
+                ORDER BY lists.list_id;
+                "
+            )?;
+
+            // execute the query, map each row to QueryListStats
+            let rows = query_stats
+                .query_map(
+                    params![today.format(DATE_FORMAT).to_string(), user_id], |row| Ok(QueryListStats{
+                        list_id: row.get("list_id")?,
+                        list_name: row.get("list_name")?,
+                        total: row.get("total")?,
+                        done: row.get("done")?,
+                        overdue: row.get("overdue")?,
+                    }
+                )
+            )?;
+
+            // collect results
+            rows.collect()
+        }
+
+        /*
+            This method counts the items a user completed on each day since a date
+
+            Prams:
+                user_id: the unique identifier for a user
+                since: the first day counted
+
+            Returns: Result< Ok, Err>
+                Ok(Vec<QueryDayCount>): the count of each day with completed items, oldest first
+                Err: there was an error while running the SQL commands
+        */
+        pub fn get_completed_per_day(&self, user_id: u32, since: NaiveDate) -> Result<Vec<QueryDayCount>, rusqlite::Error>{
+
+            // prepare the query
+            let mut query_days = self.connection.prepare(
+                "
+                SELECT date(items.completed_at) AS day, COUNT(*) AS count
+                FROM items
+                JOIN lists ON lists.list_id = items.list_id
+                WHERE lists.user_id = ?
+                AND items.complete > 0
+                AND date(items.completed_at) >= ?
+                GROUP BY day
+                ORDER BY day;
+                "
+            )?;
+
+            // execute the query, map each row to QueryDayCount
+            let rows = query_days
+                .query_map(
+                    params![user_id, since.format(DATE_FORMAT).to_string()], |row| Ok(QueryDayCount{
+                        day: row.get("day")?,
+                        count: row.get("count")?,
+                    }
+                )
+            )?;
+
+            // collect results
+            rows.collect()
+        }
     }
-}
\ No newline at end of file
+}
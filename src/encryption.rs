@@ -0,0 +1,176 @@
+pub mod encryption{
+
+    use crate::database::database::TodoDatabase;
+    use rusqlite::Connection;
+    use std::fs;
+    use std::io::{self, Read};
+    use std::path::{Path, PathBuf};
+    use thiserror::Error;
+
+    // environment variable holding the passphrase of an encrypted database
+    pub const KEY_ENV: &str = "TODO_DB_KEY";
+
+    // every plain SQLite file starts with this, an encrypted file looks random
+    const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+    /*
+        This enum is every reason an encrypted database cannot be opened or migrated
+
+        Members:
+            NoKey: the database is encrypted and no key was given
+            WrongKey: the key does not open the database
+            NotEncrypted: a key was given for a plain database, it must be encrypted first
+            AlreadyEncrypted: the database to encrypt is already encrypted
+            File: the database file could not be read or replaced
+            Database: the database could not be read or written
+    */
+    #[derive(Error, Debug)]
+    pub enum EncryptionError{
+        #[error("{0} is encrypted, set {KEY_ENV} to its passphrase")]
+        NoKey(String),
+        #[error("Wrong passphrase for {0}")]
+        WrongKey(String),
+        #[error("{0} is not encrypted, run the encrypt command first or unset {KEY_ENV}")]
+        NotEncrypted(String),
+        #[error("{0} is already encrypted")]
+        AlreadyEncrypted(String),
+        #[error("Database file error: {0}")]
+        File(#[from] io::Error),
+        #[error("Database error: {0}")]
+        Database(#[from] rusqlite::Error),
+    }
+
+    /*
+        This function reads the passphrase from KEY_ENV
+
+        Returns: Option<String>, None if it is not set or empty
+    */
+    pub fn key_from_env() -> Option<String>{
+        std::env::var(KEY_ENV).ok().filter(|key| !key.is_empty())
+    }
+
+    /*
+        This function checks if a database file is encrypted
+
+        Pram: path: &Path, the database file
+        Returns: bool, false if the file is a plain SQLite file, empty or missing
+    */
+    pub fn is_encrypted(path: &Path) -> bool{
+        let mut header: [u8; 16] = [0; 16];
+        match fs::File::open(path).and_then(|mut file| file.read_exact(&mut header)){
+            Ok(()) =>{&header != SQLITE_HEADER},
+            Err(_err) =>{false},
+        }
+    }
+
+    /*
+        This function opens the database, a new database is encrypted when a key is given
+
+        Prams:
+            path: &Path, the database file
+            key: Option<&str>, the passphrase, None for a plain database
+
+        Returns: Result<TodoDatabase, EncryptionError>
+            Ok(TodoDatabase): the database is open
+            Err(EncryptionError): the key is missing or wrong, or the database failed
+    */
+    pub fn open_database(path: &Path, key: Option<&str>) -> Result<TodoDatabase, EncryptionError>{
+        let name: String = path.display().to_string();
+        let encrypted: bool = is_encrypted(path);
+        if encrypted && key.is_none(){
+            return Err(EncryptionError::NoKey(name));
+        }
+        // a file that exists and is plain
+        if !encrypted && key.is_some() && fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0){
+            return Err(EncryptionError::NotEncrypted(name));
+        }
+
+        match TodoDatabase::open(&name, key){
+            Err(rusqlite::Error::SqliteFailure(err, _)) if err.code == rusqlite::ErrorCode::NotADatabase =>{
+                Err(EncryptionError::WrongKey(name))
+            },
+            result =>{Ok(result?)},
+        }
+    }
+
+    /*
+        This function encrypts a plain database in place, the migration for an existing database
+
+        Prams:
+            path: &Path, the database file
+            key: &str, the new passphrase
+
+        Returns: Result<(), EncryptionError>
+            Ok(()): the file is replaced by the encrypted copy
+            Err(EncryptionError): the file is already encrypted, or the copy failed
+    */
+    pub fn encrypt_file(path: &Path, key: &str) -> Result<(), EncryptionError>{
+        if is_encrypted(path){
+            return Err(EncryptionError::AlreadyEncrypted(path.display().to_string()));
+        }
+        export(path, None, key)
+    }
+
+    /*
+        This function decrypts an encrypted database in place
+
+        Prams:
+            path: &Path, the database file
+            key: &str, the passphrase
+
+        Returns: Result<(), EncryptionError>
+            Ok(()): the file is replaced by the plain copy
+            Err(EncryptionError): the file is not encrypted, the key is wrong, or the copy failed
+    */
+    pub fn decrypt_file(path: &Path, key: &str) -> Result<(), EncryptionError>{
+        if !is_encrypted(path){
+            return Err(EncryptionError::NotEncrypted(path.display().to_string()));
+        }
+        export(path, Some(key), "")
+    }
+
+    /*
+        This function copies a database with SQLCipher's export to a new key,
+        then replaces the file with the copy
+
+        Prams:
+            path: &Path, the database file
+            key: Option<&str>, the passphrase of the file now, None if it is plain
+            new_key: &str, the passphrase of the copy, empty for a plain copy
+
+        Returns: Result<(), EncryptionError>
+    */
+    fn export(path: &Path, key: Option<&str>, new_key: &str) -> Result<(), EncryptionError>{
+        let name: String = path.display().to_string();
+        let copy: PathBuf = path.with_extension("export");
+        if copy.exists(){
+            fs::remove_file(&copy)?;
+        }
+
+        let connection = Connection::open(path)?;
+        if let Some(key) = key{
+            connection.pragma_update(None, "key", key)?;
+        }
+        // reading the schema checks the key
+        match connection.query_row("SELECT count(*) FROM sqlite_master;", (), |row| row.get::<_, u32>(0)){
+            Err(rusqlite::Error::SqliteFailure(err, _)) if err.code == rusqlite::ErrorCode::NotADatabase =>{
+                return Err(EncryptionError::WrongKey(name));
+            },
+            result =>{result?;},
+        }
+
+        let exported = connection
+            .execute("ATTACH DATABASE ?1 AS export KEY ?2;", (copy.display().to_string(), new_key))
+            .and_then(|_rows| connection.query_row("SELECT sqlcipher_export('export');", (), |_row| Ok(())))
+            .and_then(|()| connection.execute("DETACH DATABASE export;", ()));
+        if let Err(err) = exported{
+            let _ = fs::remove_file(&copy);
+            return Err(EncryptionError::Database(err));
+        }
+        connection.close().map_err(|(_connection, err)| err)?;
+
+        // the copy replaces the file in one step
+        fs::rename(&copy, path)?;
+        Ok(())
+    }
+}
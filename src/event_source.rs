@@ -0,0 +1,128 @@
+pub mod event_source{
+
+    use crossterm::event::{self, Event};
+    use std::collections::VecDeque;
+    use std::{fs, io};
+    use thiserror::Error;
+    use crate::keymap::keymap::{KeyPress, parse_sequence};
+
+    /*
+        This trait is where the app reads its input events from
+    */
+    pub trait EventSource{
+        /*
+            This method waits for the next input event
+
+            Returns: io::Result<Option<Event>>
+                Ok(Some(Event)): the next event
+                Ok(None): there are no more events, the app exits
+                Err: the input could not be read
+        */
+        fn next_event(&mut self) -> io::Result<Option<Event>>;
+    }
+
+    /*
+        This structure reads events from the terminal
+    */
+    pub struct TerminalEvents;
+
+    impl EventSource for TerminalEvents{
+        fn next_event(&mut self) -> io::Result<Option<Event>>{
+            Ok(Some(event::read()?))
+        }
+    }
+
+    /*
+        This enum is every error reading a replay script
+
+        Members:
+            Read: the script file could not be read
+            UnknownKey: a line has a key that could not be parsed
+    */
+    #[derive(Error, Debug)]
+    pub enum ScriptError{
+        #[error("could not read script file: {0}")]
+        Read(#[from] io::Error),
+        #[error("unknown key on line {line}: {keys}")]
+        UnknownKey{line: usize, keys: String},
+    }
+
+    /*
+        This structure replays a list of events, used to script the app in tests
+
+        Members:
+            events: VecDeque<Event>, the events not yet read
+    */
+    pub struct ReplayEvents{
+        events: VecDeque<Event>,
+    }
+
+    impl ReplayEvents{
+        /*
+            This method creates a replay source from events
+
+            Pram: Vec<Event>, the events in the order they are read
+            Returns: ReplayEvents
+        */
+        pub fn new(events: Vec<Event>) -> ReplayEvents{
+            ReplayEvents{
+                events: events.into(),
+            }
+        }
+
+        /*
+            This method creates a replay source from a script.
+            Each line is either "type <text>" to type the text one char at a time,
+            or keys written as in the config file, e.g. "Down Down Enter" or "d d".
+            Blank lines and lines starting with # are skipped.
+
+            Pram: the script text
+            Returns: Result<ReplayEvents, ScriptError>
+        */
+        pub fn from_script(script: &str) -> Result<ReplayEvents, ScriptError>{
+
+            let mut events: Vec<Event> = Vec::new();
+            for (line_index, line) in script.lines().enumerate(){
+                let line: &str = line.trim();
+                if line.is_empty() || line.starts_with('#'){
+                    continue;
+                }
+
+                // typed text, every char is a key press
+                if let Some(text) = line.strip_prefix("type "){
+                    events.extend(text.chars().map(|c| Event::Key(KeyPress::from_char(c).to_event())));
+                    continue;
+                }
+
+                let keys: Vec<KeyPress> = parse_sequence(line)
+                    .ok_or_else(|| ScriptError::UnknownKey{line: line_index + 1, keys: String::from(line)})?;
+                events.extend(keys.iter().map(|key| Event::Key(key.to_event())));
+            }
+
+            Ok(ReplayEvents::new(events))
+        }
+
+        /*
+            This method creates a replay source from a script file, see from_script
+
+            Pram: path to the script file
+            Returns: Result<ReplayEvents, ScriptError>
+        */
+        pub fn from_file(file_path: &str) -> Result<ReplayEvents, ScriptError>{
+            ReplayEvents::from_script(&fs::read_to_string(file_path)?)
+        }
+
+        /*
+            Returns: usize the number of events not yet read
+        */
+        pub fn remaining(&self) -> usize{
+            self.events.len()
+        }
+    }
+
+    impl EventSource for ReplayEvents{
+        fn next_event(&mut self) -> io::Result<Option<Event>>{
+            Ok(self.events.pop_front())
+        }
+    }
+}
@@ -0,0 +1,163 @@
+pub mod help{
+
+    use crate::keymap::keymap::{Action, KeyContext, Keymap};
+
+    /*
+        This structure is one line of the help overlay
+
+        Members:
+            keys: String, every key bound to the action, e.g. "Backspace / d d"
+            action: Action, the bound action
+            description: &str, what the action does in this context
+    */
+    pub struct HelpEntry{
+        pub keys: String,
+        pub action: Action,
+        pub description: &'static str,
+    }
+
+    /*
+        This function describes what an action does in a context
+
+        Prams:
+            context: KeyContext, the active context
+            action: Action, the action to describe
+
+        Returns: &str, the description
+    */
+    pub fn describe(context: KeyContext, action: Action) -> &'static str{
+        match (context, action){
+            (_, Action::Quit) => "exit the app",
+            (KeyContext::LoginInput, Action::Back) => "stop typing, or cancel a login waiting for a two-factor code",
+            (KeyContext::Share, Action::Back) => "return to the lists",
+            (_, Action::Back) => "return to default mode",
+            (KeyContext::Login, Action::Input) => "type a username and password",
+            (_, Action::Input) => "add a list or item",
+            (_, Action::Navigate) => "navigate lists and items",
+            (_, Action::Search) => "search every list",
+            (_, Action::Dashboard) => "show progress of every list",
+            (_, Action::Board) => "show the selected list as a board",
+            (_, Action::Agenda) => "show items by due date",
+            (_, Action::Account) => "change the username or password, or delete the account",
+            (_, Action::Logout) => "log out and switch user",
+            (_, Action::RememberMe) => "remember the login for the next launch",
+            (_, Action::Assign) => "assign the item to the next user with access, then unassign",
+            (_, Action::Assigned) => "show the items assigned to you in every list",
+            (_, Action::Share) => "share the selected list with another user",
+            (KeyContext::Assigned, Action::Next) => "select the next item",
+            (KeyContext::Assigned, Action::Prev) => "select the previous item",
+            (KeyContext::Assigned, Action::Select) => "jump to the selected item in its list",
+            (KeyContext::Search, Action::Next) => "select the next result",
+            (KeyContext::Search, Action::Prev) => "select the previous result",
+            (KeyContext::Dashboard, Action::Next) => "scroll down the lists",
+            (KeyContext::Dashboard, Action::Prev) => "scroll up the lists",
+            (KeyContext::Board, Action::Next) => "select the next card",
+            (KeyContext::Board, Action::Prev) => "select the previous card",
+            (KeyContext::Agenda, Action::Next) => "select the next item, or the next week in the calendar",
+            (KeyContext::Agenda, Action::Prev) => "select the previous item, or the previous week in the calendar",
+            (KeyContext::Agenda, Action::PrevColumn) => "select the previous day",
+            (KeyContext::Agenda, Action::NextColumn) => "select the next day",
+            (KeyContext::Agenda, Action::PageUp) => "select the previous month",
+            (KeyContext::Agenda, Action::PageDown) => "select the next month",
+            (KeyContext::Agenda, Action::Switch) => "switch between the items and the calendar",
+            (KeyContext::Agenda, Action::Select) => "jump to the selected item in its list",
+            (KeyContext::LoginInput | KeyContext::Account, Action::Next) => "select the next box",
+            (KeyContext::LoginInput | KeyContext::Account, Action::Prev) => "select the previous box",
+            (_, Action::Next) => "select the next list or item",
+            (_, Action::Prev) => "select the previous list or item",
+            (KeyContext::Input, Action::Switch) => "switch between list and item input",
+            (KeyContext::Share, Action::Switch) => "switch between read only, read-write and stop sharing",
+            (_, Action::Switch) => "switch between lists and items",
+            (KeyContext::Search, Action::Select) => "jump to the selected result",
+            (KeyContext::LoginInput, Action::Select) => "press the selected button",
+            (KeyContext::Account, Action::Select) => "save the new username or password, or delete the account",
+            (KeyContext::Share, Action::Select) => "share the list with the user, or stop sharing it",
+            (_, Action::Select) => "add to the selected list, or cross off the selected item",
+            (_, Action::Add) => "add the typed list or item",
+            (_, Action::Delete) => "delete the selected list or item",
+            (_, Action::Toggle) => "cross off the selected item",
+            (_, Action::Backspace) => "remove the typed char before the cursor",
+            (_, Action::PageUp) => "move up one page",
+            (_, Action::PageDown) => "move down one page",
+            (_, Action::First) => "select the first list or item",
+            (_, Action::Last) => "select the last list or item",
+            (_, Action::Filter) => "show all, open or done items",
+            (_, Action::Sort) => "sort items by added, name, created, due or priority",
+            (_, Action::PriorityUp) => "raise the item priority",
+            (_, Action::PriorityDown) => "lower the item priority",
+            (_, Action::Help) => "show this help",
+            (_, Action::PrevColumn) => "select the column on the left",
+            (_, Action::NextColumn) => "select the column on the right",
+            (_, Action::MoveLeft) => "move the card to the column on the left",
+            (_, Action::MoveRight) => "move the card to the column on the right",
+            (_, Action::CursorLeft) => "move the cursor left",
+            (_, Action::CursorRight) => "move the cursor right",
+            (_, Action::LineStart) => "move the cursor to the start",
+            (_, Action::LineEnd) => "move the cursor to the end",
+            (_, Action::DeleteWord) => "remove the typed word before the cursor",
+            (_, Action::DeleteChar) => "remove the typed char after the cursor",
+            (_, Action::RevealPassword) => "show or hide the password",
+        }
+    }
+
+    /*
+        This function lists every action bound in a context
+
+        Prams:
+            keymap: Keymap, the active key bindings
+            context: KeyContext, the active context
+
+        Returns: Vec<HelpEntry> in the order actions are declared
+    */
+    pub fn help_entries(keymap: &Keymap, context: KeyContext) -> Vec<HelpEntry>{
+        Action::all()
+            .into_iter()
+            .filter_map(|action|{
+                let keys: Vec<String> = keymap.keys_for(context, action);
+                if keys.is_empty(){
+                    return None;
+                }
+                Some(HelpEntry{
+                    keys: keys.join(" / "),
+                    action,
+                    description: describe(context, action),
+                })
+            })
+            .collect()
+    }
+
+    /*
+        This function creates the short key summary shown in the footer
+
+        Prams:
+            keymap: Keymap, the active key bindings
+            context: KeyContext, the active context
+
+        Returns: String, e.g. "q: quit | 1: input | ?: help"
+    */
+    pub fn footer_text(keymap: &Keymap, context: KeyContext) -> String{
+        help_entries(keymap, context)
+            .iter()
+            .map(|entry| format!("{}: {}", entry.keys, entry.action.get_name().replace('_', " ")))
+            .collect::<Vec<String>>()
+            .join(" | ")
+    }
+
+    /*
+        This function gives tips for a context that are not key bindings
+
+        Pram: KeyContext, the active context
+        Returns: Option<&str>, None if the context has no tips
+    */
+    pub fn context_note(context: KeyContext) -> Option<&'static str>{
+        match context{
+            KeyContext::Input => Some("Type to enter a name. Add due:YYYY-MM-DD or p:1-3 to an item. Add cols:Todo,Doing,Done to a list, or enter only cols: to change the selected list."),
+            KeyContext::Board => Some("Moving a card to the last column completes it."),
+            KeyContext::Search => Some("Type to search item names in every list."),
+            KeyContext::LoginInput => Some("Type to enter a username or password. With two-factor authentication, type the code from your authenticator app or a recovery code next."),
+            KeyContext::Account => Some("Enter in the username box changes the username. Enter in a password box changes the password once the current password is typed. Press Enter in the two-factor box to show a new secret, then type a code from your authenticator app to turn it on, or type a code to turn it off. Type your username in the last box and press Enter to delete the account and all of its lists."),
+            KeyContext::Share => Some("Type the username to share the selected list with, then press Enter. Only the owner of a list can share it."),
+            _ => None,
+        }
+    }
+}
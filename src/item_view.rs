@@ -0,0 +1,150 @@
+pub mod item_view{
+
+    /*
+        This enum sets which items of a list are shown
+
+        Members:
+            All: show every item
+            HideCompleted: show items that are not complete
+            OnlyCompleted: show items that are complete
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum ItemFilter{
+        All,
+        HideCompleted,
+        OnlyCompleted,
+    }
+
+    /*
+        This enum sets the order items of a list are shown in
+
+        Members:
+            Insertion: the order items were added
+            Alphabetical: by item name
+            Created: oldest item first
+            Due: earliest due date first, items without a due date last
+            Priority: highest priority first
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum ItemSort{
+        Insertion,
+        Alphabetical,
+        Created,
+        Due,
+        Priority,
+    }
+
+    impl ItemFilter{
+        /*
+            Returns: the filter after this one, wraps to All
+        */
+        pub fn next(self) -> ItemFilter{
+            match self{
+                ItemFilter::All => ItemFilter::HideCompleted,
+                ItemFilter::HideCompleted => ItemFilter::OnlyCompleted,
+                ItemFilter::OnlyCompleted => ItemFilter::All,
+            }
+        }
+
+        /*
+            Pram: complete status of an item
+            Returns: true if an item with this status is shown
+        */
+        pub fn shows(self, complete: bool) -> bool{
+            match self{
+                ItemFilter::All => true,
+                ItemFilter::HideCompleted => !complete,
+                ItemFilter::OnlyCompleted => complete,
+            }
+        }
+
+        /*
+            Returns: u32 code stored in the lists table
+        */
+        pub fn to_code(self) -> u32{
+            match self{
+                ItemFilter::All => 0,
+                ItemFilter::HideCompleted => 1,
+                ItemFilter::OnlyCompleted => 2,
+            }
+        }
+
+        /*
+            Pram: u32 code stored in the lists table
+            Returns: the matching filter, All for unknown codes
+        */
+        pub fn from_code(code: u32) -> ItemFilter{
+            match code{
+                1 => ItemFilter::HideCompleted,
+                2 => ItemFilter::OnlyCompleted,
+                _ => ItemFilter::All,
+            }
+        }
+
+        /*
+            Returns: the name displayed to the user
+        */
+        pub fn get_name(self) -> String{
+            match self{
+                ItemFilter::All => String::from("All"),
+                ItemFilter::HideCompleted => String::from("Open"),
+                ItemFilter::OnlyCompleted => String::from("Done"),
+            }
+        }
+    }
+
+    impl ItemSort{
+        /*
+            Returns: the sort after this one, wraps to Insertion
+        */
+        pub fn next(self) -> ItemSort{
+            match self{
+                ItemSort::Insertion => ItemSort::Alphabetical,
+                ItemSort::Alphabetical => ItemSort::Created,
+                ItemSort::Created => ItemSort::Due,
+                ItemSort::Due => ItemSort::Priority,
+                ItemSort::Priority => ItemSort::Insertion,
+            }
+        }
+
+        /*
+            Returns: u32 code stored in the lists table
+        */
+        pub fn to_code(self) -> u32{
+            match self{
+                ItemSort::Insertion => 0,
+                ItemSort::Alphabetical => 1,
+                ItemSort::Created => 2,
+                ItemSort::Due => 3,
+                ItemSort::Priority => 4,
+            }
+        }
+
+        /*
+            Pram: u32 code stored in the lists table
+            Returns: the matching sort, Insertion for unknown codes
+        */
+        pub fn from_code(code: u32) -> ItemSort{
+            match code{
+                1 => ItemSort::Alphabetical,
+                2 => ItemSort::Created,
+                3 => ItemSort::Due,
+                4 => ItemSort::Priority,
+                _ => ItemSort::Insertion,
+            }
+        }
+
+        /*
+            Returns: the name displayed to the user
+        */
+        pub fn get_name(self) -> String{
+            match self{
+                ItemSort::Insertion => String::from("Added"),
+                ItemSort::Alphabetical => String::from("A-Z"),
+                ItemSort::Created => String::from("Created"),
+                ItemSort::Due => String::from("Due"),
+                ItemSort::Priority => String::from("Priority"),
+            }
+        }
+    }
+}
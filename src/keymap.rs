@@ -0,0 +1,780 @@
+pub mod keymap{
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use crate::config::config::{ConfigError, KeyConfig};
+
+    /*
+        This enum is every named action a key can be bound to
+
+        Members:
+            Quit: exit the app
+            Back: return to the default state
+            Input: enter input state to add a list or item
+            Navigate: enter navigate state
+            Search: enter search state
+            Next: select the next list, item or input
+            Prev: select the previous list, item or input
+            Switch: switch between the list and item panes or input boxes
+            Select: act on the selection, e.g. jump to a search result or press a button
+            Add: add the typed list or item
+            Delete: delete the selected list or item
+            Toggle: cross off the selected item
+            Backspace: remove the typed char before the cursor
+            PageUp: move the selection up one page
+            PageDown: move the selection down one page
+            First: select the first list or item
+            Last: select the last list or item
+            Filter: change which items are shown
+            Sort: change the order items are shown in
+            PriorityUp: raise the priority of the selected item
+            PriorityDown: lower the priority of the selected item
+            Help: show the keys of the current state
+            Dashboard: show the statistics dashboard
+            Board: show the items of the list as a board of columns
+            PrevColumn: select the board column on the left
+            NextColumn: select the board column on the right
+            MoveLeft: move the selected card to the column on the left
+            MoveRight: move the selected card to the column on the right
+            Agenda: show items by due date with a calendar
+            CursorLeft: move the input cursor one char left
+            CursorRight: move the input cursor one char right
+            LineStart: move the input cursor to the start
+            LineEnd: move the input cursor to the end
+            DeleteWord: remove the typed word before the cursor
+            DeleteChar: remove the typed char after the cursor
+            RevealPassword: show or hide the typed password on the login or account screen
+            Account: change the username or password, or delete the account
+            Logout: log out and return to the login screen
+            RememberMe: remember the next login so the login screen is skipped
+            Assign: assign the selected item to the next user with access to the list
+            Assigned: show the items of every list assigned to the user
+            Share: share the selected list with another user or stop sharing it
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum Action{
+        Quit,
+        Back,
+        Input,
+        Navigate,
+        Search,
+        Next,
+        Prev,
+        Switch,
+        Select,
+        Add,
+        Delete,
+        Toggle,
+        Backspace,
+        PageUp,
+        PageDown,
+        First,
+        Last,
+        Filter,
+        Sort,
+        PriorityUp,
+        PriorityDown,
+        Help,
+        Dashboard,
+        Board,
+        PrevColumn,
+        NextColumn,
+        MoveLeft,
+        MoveRight,
+        Agenda,
+        CursorLeft,
+        CursorRight,
+        LineStart,
+        LineEnd,
+        DeleteWord,
+        DeleteChar,
+        RevealPassword,
+        Account,
+        Logout,
+        RememberMe,
+        Assign,
+        Assigned,
+        Share,
+    }
+
+    // every action with the name used in the config file
+    const ACTION_NAMES: [(Action, &str); 42] = [
+        (Action::Quit, "quit"),
+        (Action::Back, "back"),
+        (Action::Input, "input"),
+        (Action::Navigate, "navigate"),
+        (Action::Search, "search"),
+        (Action::Next, "next"),
+        (Action::Prev, "prev"),
+        (Action::Switch, "switch"),
+        (Action::Select, "select"),
+        (Action::Add, "add"),
+        (Action::Delete, "delete"),
+        (Action::Toggle, "toggle"),
+        (Action::Backspace, "backspace"),
+        (Action::PageUp, "page_up"),
+        (Action::PageDown, "page_down"),
+        (Action::First, "first"),
+        (Action::Last, "last"),
+        (Action::Filter, "filter"),
+        (Action::Sort, "sort"),
+        (Action::PriorityUp, "priority_up"),
+        (Action::PriorityDown, "priority_down"),
+        (Action::Help, "help"),
+        (Action::Dashboard, "dashboard"),
+        (Action::Board, "board"),
+        (Action::PrevColumn, "prev_column"),
+        (Action::NextColumn, "next_column"),
+        (Action::MoveLeft, "move_left"),
+        (Action::MoveRight, "move_right"),
+        (Action::Agenda, "agenda"),
+        (Action::CursorLeft, "cursor_left"),
+        (Action::CursorRight, "cursor_right"),
+        (Action::LineStart, "line_start"),
+        (Action::LineEnd, "line_end"),
+        (Action::DeleteWord, "delete_word"),
+        (Action::DeleteChar, "delete_char"),
+        (Action::RevealPassword, "reveal_password"),
+        (Action::Account, "account"),
+        (Action::Logout, "logout"),
+        (Action::RememberMe, "remember_me"),
+        (Action::Assign, "assign"),
+        (Action::Assigned, "assigned"),
+        (Action::Share, "share"),
+    ];
+
+    impl Action{
+        /*
+            Pram: name of the action in the config file
+            Returns: Option<Action>, None if no action has the name
+        */
+        pub fn from_name(name: &str) -> Option<Action>{
+            ACTION_NAMES.iter().find(|(_action, action_name)| *action_name == name).map(|(action, _name)| *action)
+        }
+
+        /*
+            Returns: the name of the action used in the config file
+        */
+        pub fn get_name(self) -> &'static str{
+            ACTION_NAMES.iter().find(|(action, _name)| *action == self).map_or("", |(_action, name)| *name)
+        }
+
+        /*
+            Returns: every action in the order they are listed in help
+        */
+        pub fn all() -> Vec<Action>{
+            ACTION_NAMES.iter().map(|(action, _name)| *action).collect()
+        }
+    }
+
+    /*
+        This enum is the set of bindings that are active.
+        Each matches an ActionState or AuthenticationState.
+
+        Members:
+            Default: todo app default state
+            Input: todo app input state, unbound chars are typed
+            Navigate: todo app navigate state
+            Search: todo app search state, unbound chars are typed
+            Dashboard: todo app dashboard state
+            Board: todo app board state
+            Agenda: todo app agenda state
+            Account: todo app account settings state, unbound chars are typed
+            Assigned: todo app my assigned items state
+            Share: todo app share list state, unbound chars are typed
+            Login: login page default state
+            LoginInput: login page input state, unbound chars are typed
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum KeyContext{
+        Default,
+        Input,
+        Navigate,
+        Search,
+        Dashboard,
+        Board,
+        Agenda,
+        Account,
+        Assigned,
+        Share,
+        Login,
+        LoginInput,
+    }
+
+    // every context with the name used in the config file
+    const CONTEXT_NAMES: [(KeyContext, &str); 12] = [
+        (KeyContext::Default, "default"),
+        (KeyContext::Input, "input"),
+        (KeyContext::Navigate, "navigate"),
+        (KeyContext::Search, "search"),
+        (KeyContext::Dashboard, "dashboard"),
+        (KeyContext::Board, "board"),
+        (KeyContext::Agenda, "agenda"),
+        (KeyContext::Account, "account"),
+        (KeyContext::Assigned, "assigned"),
+        (KeyContext::Share, "share"),
+        (KeyContext::Login, "login"),
+        (KeyContext::LoginInput, "login_input"),
+    ];
+
+    impl KeyContext{
+        /*
+            Pram: name of the context in the config file
+            Returns: Option<KeyContext>, None if no context has the name
+        */
+        pub fn from_name(name: &str) -> Option<KeyContext>{
+            CONTEXT_NAMES.iter().find(|(_context, context_name)| *context_name == name).map(|(context, _name)| *context)
+        }
+
+        /*
+            Returns: true if unbound chars are typed into an input box
+        */
+        pub fn takes_text(self) -> bool{
+            matches!(self, KeyContext::Input | KeyContext::Search | KeyContext::Account | KeyContext::Share | KeyContext::LoginInput)
+        }
+    }
+
+    /*
+        This structure is one key press with its modifiers
+
+        Members:
+            code: KeyCode, the key
+            ctrl: bool, control was held
+            alt: bool, alt was held
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub struct KeyPress{
+        pub code: KeyCode,
+        pub ctrl: bool,
+        pub alt: bool,
+    }
+
+    impl KeyPress{
+        /*
+            This method creates a KeyPress from a terminal key event.
+            Shift is ignored, it is already part of the char.
+
+            Pram: KeyEvent from crossterm
+            Returns: KeyPress
+        */
+        pub fn from_event(event: KeyEvent) -> KeyPress{
+            KeyPress{
+                code: event.code,
+                ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+                alt: event.modifiers.contains(KeyModifiers::ALT),
+            }
+        }
+
+        /*
+            Pram: char, a typed char
+            Returns: KeyPress of the char without modifiers
+        */
+        pub fn from_char(c: char) -> KeyPress{
+            KeyPress{code: KeyCode::Char(c), ctrl: false, alt: false}
+        }
+
+        /*
+            This method creates a terminal key event, used to replay keys
+
+            Returns: KeyEvent for crossterm
+        */
+        pub fn to_event(&self) -> KeyEvent{
+            let mut modifiers: KeyModifiers = KeyModifiers::NONE;
+            if self.ctrl{
+                modifiers |= KeyModifiers::CONTROL;
+            }
+            if self.alt{
+                modifiers |= KeyModifiers::ALT;
+            }
+            KeyEvent::new(self.code, modifiers)
+        }
+
+        /*
+            This method parses one key such as "q", "Enter" or "Ctrl-w"
+
+            Pram: the key as written in the config file
+            Returns: Option<KeyPress>, None if the key is not valid
+        */
+        pub fn parse(key: &str) -> Option<KeyPress>{
+
+            let mut ctrl: bool = false;
+            let mut alt: bool = false;
+            let mut name: &str = key;
+
+            // strip modifiers, a lone "-" is the minus key
+            loop{
+                if let Some(rest) = name.strip_prefix("Ctrl-").filter(|rest| !rest.is_empty()){
+                    ctrl = true;
+                    name = rest;
+                }
+                else if let Some(rest) = name.strip_prefix("Alt-").filter(|rest| !rest.is_empty()){
+                    alt = true;
+                    name = rest;
+                }
+                else{
+                    break;
+                }
+            }
+
+            let code: KeyCode = match name{
+                "Esc" => KeyCode::Esc,
+                "Enter" => KeyCode::Enter,
+                "Backspace" => KeyCode::Backspace,
+                "Delete" => KeyCode::Delete,
+                "Tab" => KeyCode::Tab,
+                "BackTab" => KeyCode::BackTab,
+                "Up" => KeyCode::Up,
+                "Down" => KeyCode::Down,
+                "Left" => KeyCode::Left,
+                "Right" => KeyCode::Right,
+                "PageUp" => KeyCode::PageUp,
+                "PageDown" => KeyCode::PageDown,
+                "Home" => KeyCode::Home,
+                "End" => KeyCode::End,
+                "Space" => KeyCode::Char(' '),
+                _ =>{
+                    let mut chars = name.chars();
+                    match (chars.next(), chars.next()){
+                        (Some(c), None) => KeyCode::Char(c),
+                        _ =>{
+                            // function keys F1 to F12
+                            match name.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()){
+                                Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                                _ =>{return None;},
+                            }
+                        },
+                    }
+                },
+            };
+
+            Some(KeyPress{code, ctrl, alt})
+        }
+
+        /*
+            This method is the reverse of parse, used to show keys to the user
+
+            Returns: String, the key as it is written in the config file
+        */
+        pub fn get_name(&self) -> String{
+            let name: String = match self.code{
+                KeyCode::Esc => String::from("Esc"),
+                KeyCode::Enter => String::from("Enter"),
+                KeyCode::Backspace => String::from("Backspace"),
+                KeyCode::Delete => String::from("Delete"),
+                KeyCode::Tab => String::from("Tab"),
+                KeyCode::BackTab => String::from("BackTab"),
+                KeyCode::Up => String::from("Up"),
+                KeyCode::Down => String::from("Down"),
+                KeyCode::Left => String::from("Left"),
+                KeyCode::Right => String::from("Right"),
+                KeyCode::PageUp => String::from("PageUp"),
+                KeyCode::PageDown => String::from("PageDown"),
+                KeyCode::Home => String::from("Home"),
+                KeyCode::End => String::from("End"),
+                KeyCode::Char(' ') => String::from("Space"),
+                KeyCode::Char(c) => c.to_string(),
+                KeyCode::F(n) => format!("F{}", n),
+                _ => String::from("?"),
+            };
+
+            let mut modifiers = String::new();
+            if self.ctrl{
+                modifiers.push_str("Ctrl-");
+            }
+            if self.alt{
+                modifiers.push_str("Alt-");
+            }
+            modifiers + &name
+        }
+    }
+
+    /*
+        This function parses a key sequence, keys are separated by spaces e.g. "d d"
+
+        Pram: the sequence as written in the config file
+        Returns: Option<Vec<KeyPress>>, None if any key is not valid
+    */
+    pub fn parse_sequence(sequence: &str) -> Option<Vec<KeyPress>>{
+        let keys: Option<Vec<KeyPress>> = sequence.split_whitespace().map(KeyPress::parse).collect();
+        keys.filter(|keys| !keys.is_empty())
+    }
+
+    /*
+        This structure is a key sequence bound to an action
+
+        Members:
+            context: KeyContext, where the binding is active
+            keys: Vec<KeyPress>, the keys to press in order
+            action: Action, the action to run
+    */
+    pub struct Binding{
+        pub context: KeyContext,
+        pub keys: Vec<KeyPress>,
+        pub action: Action,
+    }
+
+    /*
+        This enum is the result of pressing a key
+
+        Members:
+            Action: the keys pressed so far are bound to an action
+            Pending: the keys pressed so far start a longer binding
+            Unbound: the key is not bound, holds the key
+    */
+    #[derive(PartialEq, Debug)]
+    pub enum KeyResult{
+        Action(Action),
+        Pending,
+        Unbound(KeyPress),
+    }
+
+    /*
+        This enum is a built in set of bindings
+
+        Members:
+            Default: arrow keys and number keys
+            Vim: the default bindings plus j/k/h/l, dd, x, gg, G, /, :
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum Preset{
+        Default,
+        Vim,
+    }
+
+    impl Preset{
+        /*
+            Pram: name of the preset in the config file
+            Returns: Option<Preset>, None if no preset has the name
+        */
+        pub fn from_name(name: &str) -> Option<Preset>{
+            match name{
+                "default" => Some(Preset::Default),
+                "vim" => Some(Preset::Vim),
+                _ => None,
+            }
+        }
+    }
+
+    // bindings of every preset
+    const DEFAULT_BINDINGS: &[(KeyContext, &str, &str)] = &[
+        (KeyContext::Default, "quit", "q"),
+        (KeyContext::Default, "input", "1"),
+        (KeyContext::Default, "navigate", "2"),
+        (KeyContext::Default, "search", "/"),
+        (KeyContext::Default, "dashboard", "3"),
+        (KeyContext::Default, "board", "4"),
+        (KeyContext::Default, "agenda", "5"),
+        (KeyContext::Default, "account", "6"),
+        (KeyContext::Default, "assigned", "7"),
+        (KeyContext::Default, "logout", "L"),
+        (KeyContext::Default, "back", "Esc"),
+        (KeyContext::Default, "help", "?"),
+        (KeyContext::Default, "help", "F1"),
+        (KeyContext::Input, "help", "F1"),
+        (KeyContext::Navigate, "help", "?"),
+        (KeyContext::Navigate, "help", "F1"),
+        (KeyContext::Search, "help", "F1"),
+        (KeyContext::Input, "back", "Esc"),
+        (KeyContext::Input, "add", "Enter"),
+        (KeyContext::Input, "backspace", "Backspace"),
+        (KeyContext::Input, "switch", "Tab"),
+        (KeyContext::Input, "switch", "BackTab"),
+        (KeyContext::Input, "cursor_left", "Left"),
+        (KeyContext::Input, "cursor_right", "Right"),
+        (KeyContext::Input, "line_start", "Home"),
+        (KeyContext::Input, "line_end", "End"),
+        (KeyContext::Input, "delete_word", "Ctrl-w"),
+        (KeyContext::Input, "delete_char", "Delete"),
+        (KeyContext::Navigate, "back", "Esc"),
+        (KeyContext::Navigate, "switch", "Left"),
+        (KeyContext::Navigate, "switch", "Right"),
+        (KeyContext::Navigate, "prev", "Up"),
+        (KeyContext::Navigate, "next", "Down"),
+        (KeyContext::Navigate, "page_up", "PageUp"),
+        (KeyContext::Navigate, "page_down", "PageDown"),
+        (KeyContext::Navigate, "first", "Home"),
+        (KeyContext::Navigate, "last", "End"),
+        (KeyContext::Navigate, "delete", "Backspace"),
+        (KeyContext::Navigate, "delete", "Delete"),
+        (KeyContext::Navigate, "select", "Enter"),
+        (KeyContext::Navigate, "toggle", "Space"),
+        (KeyContext::Navigate, "search", "/"),
+        (KeyContext::Navigate, "filter", "f"),
+        (KeyContext::Navigate, "sort", "s"),
+        (KeyContext::Navigate, "priority_up", "+"),
+        (KeyContext::Navigate, "priority_down", "-"),
+        (KeyContext::Navigate, "assign", "a"),
+        (KeyContext::Navigate, "share", "S"),
+        (KeyContext::Search, "back", "Esc"),
+        (KeyContext::Search, "backspace", "Backspace"),
+        (KeyContext::Search, "prev", "Up"),
+        (KeyContext::Search, "next", "Down"),
+        (KeyContext::Search, "select", "Enter"),
+        (KeyContext::Search, "cursor_left", "Left"),
+        (KeyContext::Search, "cursor_right", "Right"),
+        (KeyContext::Search, "line_start", "Home"),
+        (KeyContext::Search, "line_end", "End"),
+        (KeyContext::Search, "delete_word", "Ctrl-w"),
+        (KeyContext::Search, "delete_char", "Delete"),
+        (KeyContext::Dashboard, "quit", "q"),
+        (KeyContext::Dashboard, "back", "Esc"),
+        (KeyContext::Dashboard, "prev", "Up"),
+        (KeyContext::Dashboard, "next", "Down"),
+        (KeyContext::Dashboard, "help", "?"),
+        (KeyContext::Dashboard, "help", "F1"),
+        (KeyContext::Board, "quit", "q"),
+        (KeyContext::Board, "back", "Esc"),
+        (KeyContext::Board, "prev", "Up"),
+        (KeyContext::Board, "next", "Down"),
+        (KeyContext::Board, "prev_column", "Left"),
+        (KeyContext::Board, "next_column", "Right"),
+        (KeyContext::Board, "move_left", "<"),
+        (KeyContext::Board, "move_right", ">"),
+        (KeyContext::Board, "help", "?"),
+        (KeyContext::Board, "help", "F1"),
+        (KeyContext::Agenda, "quit", "q"),
+        (KeyContext::Agenda, "back", "Esc"),
+        (KeyContext::Agenda, "prev", "Up"),
+        (KeyContext::Agenda, "next", "Down"),
+        (KeyContext::Agenda, "prev_column", "Left"),
+        (KeyContext::Agenda, "next_column", "Right"),
+        (KeyContext::Agenda, "page_up", "PageUp"),
+        (KeyContext::Agenda, "page_down", "PageDown"),
+        (KeyContext::Agenda, "switch", "Tab"),
+        (KeyContext::Agenda, "select", "Enter"),
+        (KeyContext::Agenda, "help", "?"),
+        (KeyContext::Agenda, "help", "F1"),
+        (KeyContext::Account, "back", "Esc"),
+        (KeyContext::Account, "prev", "Up"),
+        (KeyContext::Account, "next", "Down"),
+        (KeyContext::Account, "next", "Tab"),
+        (KeyContext::Account, "prev", "BackTab"),
+        (KeyContext::Account, "select", "Enter"),
+        (KeyContext::Account, "backspace", "Backspace"),
+        (KeyContext::Account, "cursor_left", "Left"),
+        (KeyContext::Account, "cursor_right", "Right"),
+        (KeyContext::Account, "line_start", "Home"),
+        (KeyContext::Account, "line_end", "End"),
+        (KeyContext::Account, "delete_word", "Ctrl-w"),
+        (KeyContext::Account, "delete_char", "Delete"),
+        (KeyContext::Account, "reveal_password", "Ctrl-r"),
+        (KeyContext::Account, "help", "F1"),
+        (KeyContext::Assigned, "quit", "q"),
+        (KeyContext::Assigned, "back", "Esc"),
+        (KeyContext::Assigned, "prev", "Up"),
+        (KeyContext::Assigned, "next", "Down"),
+        (KeyContext::Assigned, "select", "Enter"),
+        (KeyContext::Assigned, "help", "?"),
+        (KeyContext::Assigned, "help", "F1"),
+        (KeyContext::Share, "back", "Esc"),
+        (KeyContext::Share, "select", "Enter"),
+        (KeyContext::Share, "switch", "Tab"),
+        (KeyContext::Share, "switch", "BackTab"),
+        (KeyContext::Share, "backspace", "Backspace"),
+        (KeyContext::Share, "cursor_left", "Left"),
+        (KeyContext::Share, "cursor_right", "Right"),
+        (KeyContext::Share, "line_start", "Home"),
+        (KeyContext::Share, "line_end", "End"),
+        (KeyContext::Share, "delete_word", "Ctrl-w"),
+        (KeyContext::Share, "delete_char", "Delete"),
+        (KeyContext::Share, "help", "F1"),
+        (KeyContext::Login, "input", "1"),
+        (KeyContext::Login, "quit", "q"),
+        (KeyContext::LoginInput, "back", "Esc"),
+        (KeyContext::LoginInput, "prev", "Up"),
+        (KeyContext::LoginInput, "next", "Down"),
+        (KeyContext::LoginInput, "select", "Enter"),
+        (KeyContext::LoginInput, "backspace", "Backspace"),
+        (KeyContext::LoginInput, "cursor_left", "Left"),
+        (KeyContext::LoginInput, "cursor_right", "Right"),
+        (KeyContext::LoginInput, "line_start", "Home"),
+        (KeyContext::LoginInput, "line_end", "End"),
+        (KeyContext::LoginInput, "delete_word", "Ctrl-w"),
+        (KeyContext::LoginInput, "delete_char", "Delete"),
+        (KeyContext::LoginInput, "reveal_password", "Ctrl-r"),
+        (KeyContext::LoginInput, "remember_me", "Ctrl-s"),
+    ];
+
+    const VIM_BINDINGS: &[(KeyContext, &str, &str)] = &[
+        (KeyContext::Default, "input", "i"),
+        (KeyContext::Default, "input", ":"),
+        (KeyContext::Default, "navigate", "n"),
+        (KeyContext::Navigate, "next", "j"),
+        (KeyContext::Navigate, "prev", "k"),
+        (KeyContext::Navigate, "switch", "h"),
+        (KeyContext::Navigate, "switch", "l"),
+        (KeyContext::Navigate, "delete", "d d"),
+        (KeyContext::Navigate, "toggle", "x"),
+        (KeyContext::Navigate, "first", "g g"),
+        (KeyContext::Navigate, "last", "G"),
+        (KeyContext::Navigate, "page_down", "Ctrl-d"),
+        (KeyContext::Navigate, "page_up", "Ctrl-u"),
+        (KeyContext::Navigate, "input", ":"),
+        (KeyContext::Search, "next", "Ctrl-n"),
+        (KeyContext::Search, "prev", "Ctrl-p"),
+        (KeyContext::Dashboard, "next", "j"),
+        (KeyContext::Dashboard, "prev", "k"),
+        (KeyContext::Board, "next", "j"),
+        (KeyContext::Board, "prev", "k"),
+        (KeyContext::Board, "prev_column", "h"),
+        (KeyContext::Board, "next_column", "l"),
+        (KeyContext::Board, "move_left", "H"),
+        (KeyContext::Board, "move_right", "L"),
+        (KeyContext::Agenda, "next", "j"),
+        (KeyContext::Agenda, "prev", "k"),
+        (KeyContext::Agenda, "prev_column", "h"),
+        (KeyContext::Agenda, "next_column", "l"),
+        (KeyContext::Assigned, "next", "j"),
+        (KeyContext::Assigned, "prev", "k"),
+        (KeyContext::Login, "input", "i"),
+        (KeyContext::LoginInput, "next", "Tab"),
+        (KeyContext::LoginInput, "prev", "BackTab"),
+    ];
+
+    /*
+        This structure maps key presses to actions
+
+        Members:
+            bindings: Vec<Binding>, every binding
+            pending: Vec<KeyPress>, keys pressed that start a longer binding
+    */
+    pub struct Keymap{
+        bindings: Vec<Binding>,
+        pending: Vec<KeyPress>,
+    }
+
+    impl Keymap{
+        /*
+            This method creates a keymap with a preset's bindings
+
+            Pram: Preset, the built in bindings to use
+            Returns: Keymap
+        */
+        pub fn new(preset: Preset) -> Keymap{
+            let mut keymap = Keymap{
+                bindings: Vec::new(),
+                pending: Vec::new(),
+            };
+
+            let mut presets: Vec<&[(KeyContext, &str, &str)]> = vec![DEFAULT_BINDINGS];
+            if preset == Preset::Vim{
+                presets.push(VIM_BINDINGS);
+            }
+
+            for (context, action, keys) in presets.into_iter().flatten(){
+                // built in bindings are always valid
+                if let (Some(action), Some(keys)) = (Action::from_name(action), parse_sequence(keys)){
+                    keymap.bindings.push(Binding{context: *context, keys, action});
+                }
+            }
+
+            keymap
+        }
+
+        /*
+            This method creates a keymap from the config file [keys] table.
+            The preset is used first, then each action listed in the config
+            has its bindings replaced for that context.
+
+            Pram: KeyConfig, the [keys] table of the config file
+            Returns: Result<Keymap, ConfigError>
+                Ok(Keymap): the keymap
+                Err(ConfigError): an unknown preset, context, action or key
+        */
+        pub fn from_config(config: &KeyConfig) -> Result<Keymap, ConfigError>{
+
+            let preset: Preset = match &config.preset{
+                Some(name) => Preset::from_name(name).ok_or_else(|| ConfigError::UnknownPreset(name.clone()))?,
+                None => Preset::Default,
+            };
+            let mut keymap = Keymap::new(preset);
+
+            for (context_name, actions) in &config.contexts{
+                let context: KeyContext = KeyContext::from_name(context_name)
+                    .ok_or_else(|| ConfigError::UnknownContext(context_name.clone()))?;
+
+                for (action_name, sequences) in actions{
+                    let action: Action = Action::from_name(action_name)
+                        .ok_or_else(|| ConfigError::UnknownAction(action_name.clone()))?;
+
+                    // replace the preset bindings for this action
+                    keymap.bindings.retain(|binding| binding.context != context || binding.action != action);
+
+                    for sequence in sequences{
+                        let keys: Vec<KeyPress> = parse_sequence(sequence)
+                            .ok_or_else(|| ConfigError::UnknownKey(sequence.clone()))?;
+                        keymap.bindings.push(Binding{context, keys, action});
+                    }
+                }
+            }
+
+            Ok(keymap)
+        }
+
+        /*
+            This method handles a key press in a context.
+            Keys that start a longer binding are held until the binding
+            is complete or a key breaks the sequence.
+
+            Prams:
+                context: KeyContext, the active context
+                key: KeyPress, the key pressed
+
+            Returns: KeyResult
+        */
+        pub fn resolve(&mut self, context: KeyContext, key: KeyPress) -> KeyResult{
+
+            self.pending.push(key);
+
+            // the keys so far are a complete binding
+            if let Some(binding) = self.bindings.iter().find(|binding| binding.context == context && binding.keys == self.pending){
+                self.pending.clear();
+                return KeyResult::Action(binding.action);
+            }
+
+            // the keys so far start a longer binding
+            if self.bindings.iter().any(|binding| binding.context == context && binding.keys.starts_with(&self.pending)){
+                return KeyResult::Pending;
+            }
+
+            // the sequence is broken, try the key on its own
+            let broken_sequence: bool = self.pending.len() > 1;
+            self.pending.clear();
+            if broken_sequence{
+                return self.resolve(context, key);
+            }
+
+            KeyResult::Unbound(key)
+        }
+
+        /*
+            This method forgets keys held for a longer binding, used when the context changes
+        */
+        pub fn clear_pending(&mut self){
+            self.pending.clear();
+        }
+
+        /*
+            This method lists the keys bound to an action, sequences are joined by spaces
+
+            Prams:
+                context: KeyContext, where the bindings are active
+                action: Action, the action to look up
+
+            Returns: Vec<String> e.g. ["Backspace", "d d"], empty if the action is unbound
+        */
+        pub fn keys_for(&self, context: KeyContext, action: Action) -> Vec<String>{
+            self.bindings
+                .iter()
+                .filter(|binding| binding.context == context && binding.action == action)
+                .map(|binding| binding.keys.iter().map(|key| key.get_name()).collect::<Vec<String>>().join(" "))
+                .collect()
+        }
+
+        /*
+            Returns: every binding
+        */
+        pub fn get_bindings(&self) -> &[Binding]{
+            &self.bindings
+        }
+    }
+}
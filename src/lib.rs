@@ -5,4 +5,23 @@ pub mod user;
 pub mod todo;
 pub mod todo_item;
 pub mod user_authentication;
-pub mod render_authenitcation;
\ No newline at end of file
+pub mod render_authenitcation;
+pub mod search;
+pub mod item_view;
+pub mod mouse;
+pub mod keymap;
+pub mod config;
+pub mod theme;
+pub mod help;
+pub mod stats;
+pub mod agenda;
+pub mod event_source;
+pub mod app;
+pub mod line_editor;
+pub mod account;
+pub mod session;
+pub mod sharing;
+pub mod assignment;
+pub mod two_factor;
+pub mod encryption;
+pub mod backup;
\ No newline at end of file
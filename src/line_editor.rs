@@ -0,0 +1,224 @@
+pub mod line_editor{
+
+    use unicode_width::UnicodeWidthStr;
+    use crate::keymap::keymap::Action;
+
+    /*
+        This structure is a one line text input with a cursor
+
+        Members:
+            text: String, the typed text
+            cursor: usize, byte index of the cursor in text, always on a char boundary
+    */
+    #[derive(Default, Clone, Debug)]
+    pub struct LineEditor{
+        text: String,
+        cursor: usize,
+    }
+
+    impl LineEditor{
+        /*
+            Returns: an empty LineEditor
+        */
+        pub fn new() -> LineEditor{
+            LineEditor::default()
+        }
+
+        /*
+            Returns: &str, the typed text
+        */
+        pub fn get_text(&self) -> &str{
+            &self.text
+        }
+
+        /*
+            Returns: usize, byte index of the cursor in the text
+        */
+        pub fn get_cursor(&self) -> usize{
+            self.cursor
+        }
+
+        /*
+            Returns: true if nothing is typed
+        */
+        pub fn is_empty(&self) -> bool{
+            self.text.is_empty()
+        }
+
+        /*
+            This method removes all text
+
+            Returns: String, the text that was typed
+        */
+        pub fn take(&mut self) -> String{
+            self.cursor = 0;
+            std::mem::take(&mut self.text)
+        }
+
+        /*
+            This method types a char at the cursor
+
+            Pram: c: char, the char typed
+        */
+        pub fn insert(&mut self, c: char){
+            self.text.insert(self.cursor, c);
+            self.cursor += c.len_utf8();
+        }
+
+        /*
+            This method types pasted text at the cursor,
+            line breaks become spaces and other control chars are dropped
+
+            Pram: text: &str, the pasted text
+        */
+        pub fn paste(&mut self, text: &str){
+            for c in text.chars(){
+                match c{
+                    '\r' =>{},
+                    '\n' | '\t' =>{self.insert(' ');},
+                    c if c.is_control() =>{},
+                    c =>{self.insert(c);},
+                }
+            }
+        }
+
+        /*
+            This method removes the char before the cursor
+        */
+        pub fn backspace(&mut self){
+            if let Some(start) = self.previous_boundary(){
+                self.text.drain(start..self.cursor);
+                self.cursor = start;
+            }
+        }
+
+        /*
+            This method removes the char after the cursor
+        */
+        pub fn delete(&mut self){
+            if let Some(end) = self.next_boundary(){
+                self.text.drain(self.cursor..end);
+            }
+        }
+
+        /*
+            This method removes the word before the cursor and the spaces after it
+        */
+        pub fn delete_word(&mut self){
+            let before: &str = &self.text[..self.cursor];
+            let trimmed: &str = before.trim_end();
+            let start: usize = trimmed
+                .char_indices()
+                .rev()
+                .find(|(_index, c)| c.is_whitespace())
+                .map_or(0, |(index, c)| index + c.len_utf8());
+            self.text.drain(start..self.cursor);
+            self.cursor = start;
+        }
+
+        /*
+            This method moves the cursor one char left
+        */
+        pub fn move_left(&mut self){
+            if let Some(start) = self.previous_boundary(){
+                self.cursor = start;
+            }
+        }
+
+        /*
+            This method moves the cursor one char right
+        */
+        pub fn move_right(&mut self){
+            if let Some(end) = self.next_boundary(){
+                self.cursor = end;
+            }
+        }
+
+        /*
+            This method moves the cursor before the first char
+        */
+        pub fn move_start(&mut self){
+            self.cursor = 0;
+        }
+
+        /*
+            This method moves the cursor after the last char
+        */
+        pub fn move_end(&mut self){
+            self.cursor = self.text.len();
+        }
+
+        /*
+            This method hides the text, used for passwords
+
+            Pram: mask: char, the char shown for every typed char
+            Returns: LineEditor, with every char replaced by mask and the cursor at the same char
+        */
+        pub fn masked(&self, mask: char) -> LineEditor{
+            let text: String = self.text.chars().map(|_c| mask).collect();
+            let cursor: usize = self.text[..self.cursor].chars().count() * mask.len_utf8();
+            LineEditor{text, cursor}
+        }
+
+        /*
+            This method runs an editing action
+
+            Pram: action: Action, the action bound to the keys pressed
+            Returns: bool, true if the action edits text, false if it is not an editing action
+        */
+        pub fn edit(&mut self, action: Action) -> bool{
+            match action{
+                Action::Backspace =>{self.backspace();},
+                Action::DeleteChar =>{self.delete();},
+                Action::DeleteWord =>{self.delete_word();},
+                Action::CursorLeft =>{self.move_left();},
+                Action::CursorRight =>{self.move_right();},
+                Action::LineStart =>{self.move_start();},
+                Action::LineEnd =>{self.move_end();},
+                _ =>{return false;},
+            }
+            true
+        }
+
+        /*
+            This method finds the text shown in a box, scrolled so the cursor is inside it
+
+            Pram: width: usize, columns inside the box
+            Returns: (&str, u16), the text from the first shown char and the column of the cursor
+        */
+        pub fn visible(&self, width: usize) -> (&str, u16){
+            let mut start: usize = 0;
+            // drop chars from the start until the cursor fits
+            while start < self.cursor && self.text[start..self.cursor].width() >= width.max(1){
+                start += self.text[start..].chars().next().map_or(1, |c| c.len_utf8());
+            }
+            (&self.text[start..], self.text[start..self.cursor].width() as u16)
+        }
+
+        /*
+            Returns: Option<usize>, byte index of the char before the cursor, None at the start
+        */
+        fn previous_boundary(&self) -> Option<usize>{
+            self.text[..self.cursor].char_indices().next_back().map(|(index, _c)| index)
+        }
+
+        /*
+            Returns: Option<usize>, byte index after the char at the cursor, None at the end
+        */
+        fn next_boundary(&self) -> Option<usize>{
+            self.text[self.cursor..].chars().next().map(|c| self.cursor + c.len_utf8())
+        }
+    }
+
+    impl From<&str> for LineEditor{
+        /*
+            This method creates a LineEditor with text, the cursor at the end
+        */
+        fn from(text: &str) -> LineEditor{
+            LineEditor{
+                text: String::from(text),
+                cursor: text.len(),
+            }
+        }
+    }
+}
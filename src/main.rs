@@ -6,65 +6,120 @@ pub mod todo;
 pub mod todo_item;
 pub mod user_authentication;
 pub mod render_authenitcation;
+pub mod search;
+pub mod item_view;
+pub mod mouse;
+pub mod keymap;
+pub mod config;
+pub mod theme;
+pub mod help;
+pub mod stats;
+pub mod agenda;
+pub mod event_source;
+pub mod app;
+pub mod line_editor;
+pub mod account;
+pub mod session;
+pub mod sharing;
+pub mod assignment;
+pub mod two_factor;
+pub mod encryption;
+pub mod backup;
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use std::{error::Error, io}; 
+use std::{error::Error, io, path::Path, time::Duration}; 
 
 use tui::{
-    backend::{Backend, CrosstermBackend},
+    backend::CrosstermBackend,
     Terminal,
 };
-use crate::render::render_ui;
-use render_authenitcation::render_user_authentication;
-use database::database::TodoDatabase;
 use rusqlite::{Result};
-use user_authentication::user_authentication::{Authentication, AuthenticationState};
-use crate::user::user::User;
 
-use crate::app_state::app_state::{State, ActionState};
+use crate::keymap::keymap::Keymap;
+use crate::config::config::load_config;
+use crate::theme::theme::Theme;
+use crate::app::app::run_sessions;
+use crate::event_source::event_source::TerminalEvents;
+use crate::encryption::encryption::{key_from_env, is_encrypted, open_database, encrypt_file, decrypt_file, KEY_ENV};
+use crate::backup::backup::{create_backup, create_backup_if_due, list_backups, restore, restore_user, spawn_periodic};
+use chrono::{Duration as TimeDuration, Local};
+
+// path to the database shared by every user
+const DATABASE_PATH: &str = "database/data.db";
+
+// folder of the database snapshots, the newest BACKUP_KEEP and one a day are kept
+const BACKUP_DIR: &str = "database/backups";
+const BACKUP_KEEP: usize = 10;
+// a snapshot is made on startup and every BACKUP_MINUTES while the app runs,
+// not on startup if the newest is younger than BACKUP_MINUTES
+const BACKUP_MINUTES: u64 = 30;
+
+// path to the optional config file
+const CONFIG_PATH: &str = "config/config.toml";
+
+// path to the remembered login, only readable by the user
+const SESSION_PATH: &str = "database/session";
 
 
 
 /*
-    This function is the main function and runs the app
+    This function is the main function and runs the app.
+    "encrypt" and "decrypt" migrate the database with the passphrase in TODO_DB_KEY instead.
+    "backup" snapshots the database, "restore" lists the snapshots,
+    "restore <number>" restores one and "restore <number> <username>" restores only that user's lists.
 
     Returns: Result<Ok, Box<err>
         Ok(): app runs successfully
         Box<err>: Error with terminal
 */
 fn main() -> Result<(), Box<dyn Error>> {   
+
+    // an encrypted database needs its passphrase
+    let key: Option<String> = key_from_env();
+    let database_path: &Path = Path::new(DATABASE_PATH);
+    // commands run without the terminal, errors are printed for the user
+    if let Some(command) = std::env::args().nth(1){
+        if let Err(err) = run_command(&command, database_path, key.as_deref()){
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // load key bindings and theme before the terminal is taken over so errors are readable
+    let config = load_config(CONFIG_PATH)?;
+    let mut keymap = Keymap::from_config(&config.keys)?;
+    // https://no-color.org, any non empty value turns color off
+    let no_color: bool = std::env::var("NO_COLOR").is_ok_and(|value| !value.is_empty());
+    let theme = Theme::from_config(&config.theme, no_color)?;
+
+    // create database for  Authentication, shared by each user that logs in
+    let db = open_database(database_path, key.as_deref())?;
+
+    // snapshot on startup, then every BACKUP_MINUTES in the background
+    // a failed backup is only a warning, the lists are still usable
+    if let Err(err) = create_backup_if_due(&db, Path::new(BACKUP_DIR), BACKUP_KEEP, TimeDuration::minutes(BACKUP_MINUTES as i64), Local::now().naive_local()){
+        eprintln!("Warning: could not back up {} to {}: {}", DATABASE_PATH, BACKUP_DIR, err);
+    }
+    spawn_periodic(database_path.to_path_buf(), key.clone(), Path::new(BACKUP_DIR).to_path_buf(), BACKUP_KEEP, Duration::from_secs(BACKUP_MINUTES * 60));
    
     // setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout(); // output to termain
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // bracketed paste sends pasted text as one event instead of key presses
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, EnableBracketedPaste)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?; //create termainl
     terminal.clear()?;
 
-    // create database for  Authentication
-    let db = TodoDatabase::new(String::from("database/data.db"));
-    let authen = Authentication::new(&db);
-    // runs the user authentication, returns Result<Option<User>, Err>
-    let res: Option<User> = match run_user_authentication(&mut terminal, authen){
-        Ok(option) => {option},
-        Err(err) => {panic!("No User Error: {}", err)},
-    };
-    
-    if !res.is_none(){
-        let user: User = res.unwrap();
-        // create app and run it
-        let state = State::new(user, db);
-        let res_app = run_app(&mut terminal, state);
-
-        match res_app{
-            Err(err) =>{ println!("{:?}", err);},
+    // runs the user authentication then the app, again after each logout
+    match run_sessions(&mut terminal, db, Some(Path::new(SESSION_PATH)), &mut TerminalEvents, &mut keymap, &theme){
+        Err(err) =>{ println!("{:?}", err);},
         _ =>{},
-        }
     }
 
     // restore terminal
@@ -72,7 +127,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
@@ -80,92 +136,86 @@ fn main() -> Result<(), Box<dyn Error>> {
 }
 
 /*
-    This function runs user authentication
+    This function runs a command instead of the app
 
     Prams:
-        terminal: the terminal to display app in
-        user_authenticate: Authentication state
+        command: &str, the first argument
+        database_path: &Path, the database file
+        key: Option<&str>, the passphrase in TODO_DB_KEY
 
-    Returns: Result<Ok(Option<User>), Err>
-        Ok(Option<User>): app ran successfully and option was returned
-        Err: app failed and there was an error 
+    Returns: Result<Ok, Box<err>
+        Ok(): the command ran
+        Box<err>: the command is unknown or failed
 */
-fn run_user_authentication<B: Backend>(terminal: &mut Terminal<B>, mut user_authenticate: Authentication)-> io::Result<Option<User>>{
-    // continually render app
-    loop {
-        // draw widgets
-        terminal.draw(|f| render_user_authentication(f, &mut user_authenticate))?;
-
-        // handel input keys, calling user_authenticate methods
-        if let Event::Key(key) = event::read()? {
-            match user_authenticate.authentication_state{
-                AuthenticationState::Default => match key.code {
-                    KeyCode::Char('1') => {user_authenticate.user_input();},
-                    KeyCode::Char('q') => {return Ok(user_authenticate.get_user())},
-                    _ =>{},
-                },
-                AuthenticationState::UserInput => match key.code {
-                    KeyCode::Up => {user_authenticate.previous_index();},
-                    KeyCode::Down => {user_authenticate.next_index();},
-                    KeyCode::Enter => {user_authenticate.process_enter();},
-                    KeyCode::Char(c) => {user_authenticate.add_input(c)},
-                    KeyCode::Backspace => {user_authenticate.remove_input()},
-                    KeyCode::Esc => {user_authenticate.default();},
-                    _ =>{},
-                },
-                AuthenticationState::LoggedIn => {return Ok(user_authenticate.get_user())},
+fn run_command(command: &str, database_path: &Path, key: Option<&str>) -> Result<(), Box<dyn Error>>{
+    let dir: &Path = Path::new(BACKUP_DIR);
+    match command{
+        "encrypt" =>{
+            let key: &str = key.ok_or(format!("Set {} to the new passphrase to encrypt {}", KEY_ENV, DATABASE_PATH))?;
+            encrypt_file(database_path, key)?;
+            // the backups would still show every list
+            for path in list_backups(dir)?.iter().filter(|path| !is_encrypted(path)){
+                encrypt_file(path, key)?;
             }
-        }
+            println!("Encrypted {} and its backups, set {} to open it", DATABASE_PATH, KEY_ENV);
+        },
+        "decrypt" =>{
+            let key: &str = key.ok_or(format!("Set {} to the passphrase to decrypt {}", KEY_ENV, DATABASE_PATH))?;
+            decrypt_file(database_path, key)?;
+            for path in list_backups(dir)?.iter().filter(|path| is_encrypted(path)){
+                decrypt_file(path, key)?;
+            }
+            println!("Decrypted {} and its backups, unset {} to open it", DATABASE_PATH, KEY_ENV);
+        },
+        "backup" =>{
+            let db = open_database(database_path, key)?;
+            let path = create_backup(&db, dir, BACKUP_KEEP, Local::now().naive_local())?;
+            println!("Backed up {} to {}", DATABASE_PATH, path.display());
+        },
+        "restore" =>{restore_command(database_path, key)?;},
+        _ =>{return Err(format!("Unknown command: {}, use encrypt, decrypt, backup or restore", command).into());},
     }
+    Ok(())
 }
 
 /*
-    This function runs todo app
+    This function runs the restore command, the app is not started
 
     Prams:
-        terminal: the terminal to display app in
-        state: State for the todo app
+        database_path: &Path, the database file
+        key: Option<&str>, the passphrase of an encrypted database
 
-    Returns: Result<Ok(Option<User>), Err>
-        Ok(): app ran successfully
-        Err: app failed and there was an error 
+    Returns: Result<Ok, Box<err>
+        Ok(): the backups were listed or one was restored
+        Box<err>: the backup is not found or the restore failed
 */
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut state: State) -> io::Result<()> {
-    // continually render app
-    loop {
-        // draw widgets
-        terminal.draw(|f| render_ui(f, &mut state))?;
-
-        // handel input keys, calling state methods
-        if let Event::Key(key) = event::read()? {
-            match state.action_state{
-                ActionState::Default => match key.code {
-                    KeyCode::Esc => {state.defalut_state();},
-                    KeyCode::Char('1') => {state.capture_input_state()},
-                    KeyCode::Char('2') => {state.navigate_state()},
-                    KeyCode::Char('q') => {return Ok(())},
-                    _ => {},
-                },
-                ActionState::CaptureInput => match key.code {
-                    KeyCode::Esc => {state.defalut_state();},
-                    KeyCode::Char(c) => {state.add_input(c);},
-                    KeyCode::Backspace => {state.remove_input();},
-                    KeyCode::Enter => {state.add();}
-                    KeyCode::Left => {state.left_right_key();},
-                    KeyCode::Right => {state.left_right_key();},
-                    _ => {},
-                },
-                ActionState::Navigate => match key.code {
-                    KeyCode::Esc => {state.defalut_state();},
-                    KeyCode::Left => {state.left_right_key();},
-                    KeyCode::Right => {state.left_right_key();},
-                    KeyCode::Up => {state.previous_list_item();},
-                    KeyCode::Down => {state.next_list_item();},
-                    KeyCode::Backspace => {state.delete();},
-                    KeyCode::Enter => {state.handel_enter();},
-                    _ => {},
-                },
+fn restore_command(database_path: &Path, key: Option<&str>) -> Result<(), Box<dyn Error>>{
+    let dir: &Path = Path::new(BACKUP_DIR);
+    let args: Vec<String> = std::env::args().skip(2).collect();
+    let now = Local::now().naive_local();
+
+    match args.as_slice(){
+        [] =>{
+            let backups = list_backups(dir)?;
+            if backups.is_empty(){
+                println!("No backups in {}", BACKUP_DIR);
             }
-        }
+            for (index, path) in backups.iter().enumerate(){
+                println!("{:>3}  {}", index + 1, path.display());
+            }
+            println!("Restore one with: restore <number>, or one user's lists with: restore <number> <username>");
+        },
+        [choice] =>{
+            let mut db = open_database(database_path, key)?;
+            let undo = restore(&mut db, dir, choice, BACKUP_KEEP, now)?;
+            println!("Restored {} from backup {}, the database before is in {}", DATABASE_PATH, choice, undo.display());
+        },
+        [choice, username] =>{
+            let db = open_database(database_path, key)?;
+            let lists: usize = restore_user(&db, dir, choice, username, BACKUP_KEEP, now)?;
+            println!("Restored {} lists of User: {} from backup {}", lists, username, choice);
+        },
+        _ =>{return Err(String::from("Use: restore [<number> [<username>]]").into());},
     }
-}
\ No newline at end of file
+    Ok(())
+}
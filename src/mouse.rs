@@ -0,0 +1,190 @@
+pub mod mouse{
+
+    use tui::layout::Rect;
+
+    /*
+        This enum is the part of the screen a mouse event happened on
+
+        Members:
+            List: row of the lists pane, holds the list index
+            Item: row of the items pane, holds the shown item position
+            SearchResult: row of the search results, holds the result index
+            ListInput: the list input box
+            ItemInput: the item input box
+            Nothing: no part of the app that handles the mouse
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum MouseTarget{
+        List(usize),
+        Item(usize),
+        SearchResult(usize),
+        ListInput,
+        ItemInput,
+        Nothing,
+    }
+
+    /*
+        This structure holds where each pane was last drawn so mouse
+        coordinates can be mapped back to lists and items
+
+        Members:
+            lists: Rect, the lists pane
+            items: Rect, the items pane
+            search_results: Rect, the search results pane
+            list_input: Rect, the list input box
+            item_input: Rect, the item input box
+            list_offset: usize, first list shown in the lists pane
+            item_offset: usize, first item shown in the items pane
+            search_offset: usize, first result shown in the search results
+            list_count: usize, number of rows in the lists pane
+            item_count: usize, number of rows in the items pane
+            search_count: usize, number of rows in the search results
+    */
+    #[derive(Default, Clone, Copy, Debug)]
+    pub struct ScreenLayout{
+        pub lists: Rect,
+        pub items: Rect,
+        pub search_results: Rect,
+        pub list_input: Rect,
+        pub item_input: Rect,
+        pub list_offset: usize,
+        pub item_offset: usize,
+        pub search_offset: usize,
+        pub list_count: usize,
+        pub item_count: usize,
+        pub search_count: usize,
+    }
+
+    impl ScreenLayout{
+        /*
+            This method forgets where panes were drawn, offsets are kept
+            so scrolling continues from the same place next frame
+        */
+        pub fn clear_areas(&mut self){
+            self.lists = Rect::default();
+            self.items = Rect::default();
+            self.search_results = Rect::default();
+            self.list_input = Rect::default();
+            self.item_input = Rect::default();
+        }
+
+        /*
+            This method finds what is drawn at a screen position
+
+            Prams:
+                column: u16, the mouse column
+                row: u16, the mouse row
+
+            Returns: MouseTarget at the position
+        */
+        pub fn target(&self, column: u16, row: u16) -> MouseTarget{
+
+            if let Some(index) = list_row(self.lists, self.list_offset, self.list_count, column, row){
+                return MouseTarget::List(index);
+            }
+            if let Some(index) = list_row(self.items, self.item_offset, self.item_count, column, row){
+                return MouseTarget::Item(index);
+            }
+            if let Some(index) = list_row(self.search_results, self.search_offset, self.search_count, column, row){
+                return MouseTarget::SearchResult(index);
+            }
+            if contains(self.list_input, column, row){
+                return MouseTarget::ListInput;
+            }
+            if contains(self.item_input, column, row){
+                return MouseTarget::ItemInput;
+            }
+
+            MouseTarget::Nothing
+        }
+
+        /*
+            This method finds which pane is at a screen position, used for the scroll wheel
+
+            Prams:
+                column: u16, the mouse column
+                row: u16, the mouse row
+
+            Returns: MouseTarget::List, Item or SearchResult holding 0, or Nothing
+        */
+        pub fn pane(&self, column: u16, row: u16) -> MouseTarget{
+            if contains(self.lists, column, row){
+                return MouseTarget::List(0);
+            }
+            if contains(self.items, column, row){
+                return MouseTarget::Item(0);
+            }
+            if contains(self.search_results, column, row){
+                return MouseTarget::SearchResult(0);
+            }
+            MouseTarget::Nothing
+        }
+    }
+
+    /*
+        This function checks if a position is inside a Rect
+
+        Returns: true if the position is inside area
+    */
+    fn contains(area: Rect, column: u16, row: u16) -> bool{
+        column >= area.x && column < area.x + area.width && row >= area.y && row < area.y + area.height
+    }
+
+    /*
+        This function maps a position to a row of a bordered list
+
+        Prams:
+            area: Rect, where the list was drawn including borders
+            offset: usize, first row shown
+            n: usize, number of rows in the list
+            column: u16, the mouse column
+            row: u16, the mouse row
+
+        Returns: Option<usize> the row index, None if the position is not on a row
+    */
+    fn list_row(area: Rect, offset: usize, n: usize, column: u16, row: u16) -> Option<usize>{
+
+        // inside the borders
+        if area.width < 2 || area.height < 2{
+            return None;
+        }
+        let inner = Rect::new(area.x + 1, area.y + 1, area.width - 2, area.height - 2);
+        if !contains(inner, column, row){
+            return None;
+        }
+
+        let index: usize = offset + (row - inner.y) as usize;
+        if index < n {Some(index)} else {None}
+    }
+
+    /*
+        This function finds the first row a list shows, the same way
+        tui's List keeps the selected row visible
+
+        Prams:
+            offset: usize, first row shown last frame
+            selected: Option<usize>, the selected row
+            n: usize, number of rows in the list
+            height: usize, number of rows that fit in the list
+
+        Returns: usize the first row shown
+    */
+    pub fn scroll_offset(offset: usize, selected: Option<usize>, n: usize, height: usize) -> usize{
+
+        if n == 0 || height == 0{
+            return 0;
+        }
+
+        let mut offset: usize = offset.min(n - 1);
+        let selected: usize = selected.unwrap_or(0).min(n - 1);
+
+        if selected >= offset + height{
+            offset = selected + 1 - height;
+        }
+        if selected < offset{
+            offset = selected;
+        }
+
+        offset
+    }
+}
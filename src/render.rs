@@ -1,13 +1,26 @@
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Span, Spans, Text},
-    widgets::{Block, Borders, Paragraph, List, ListItem},
+    widgets::{Block, BorderType, Borders, Clear, Gauge, Paragraph, List, ListItem, ListState, Row, Sparkline, Table, Wrap},
     Frame,
 };
-use unicode_width::UnicodeWidthStr;
-use crate::app_state::app_state::State;
+use crate::mouse::mouse::scroll_offset;
+use crate::theme::theme::Theme;
+use crate::keymap::keymap::{Keymap, KeyContext};
+use crate::help::help::{help_entries, footer_text, context_note};
+use crate::app_state::app_state::{State, ActionState};
+use crate::todo::todo::TodoList;
+use crate::todo_item::todo_item::{TodoItem, DATE_FORMAT};
+use crate::stats::stats::{Stats, STATS_DAYS, completion_ratio};
+use crate::agenda::agenda::{AgendaGroup, month_weeks};
+use crate::assignment::assignment;
+use crate::line_editor::line_editor::LineEditor;
+use crate::account::account::AccountField;
+use crate::render_authenitcation::PASSWORD_MASK;
+use chrono::{Local, NaiveDate};
+use std::collections::HashSet;
 
 /*
     This enum is used to draw the lists and list items
@@ -27,8 +40,10 @@ enum DrawList{
     Prams: 
         f: Fram the terminal fram to draw in
         state: State, the state of the app
+        keymap: Keymap, the key bindings shown in the footer and help
+        theme: Theme, the colors to draw with
 */
-pub fn render_ui<B: Backend>(f: &mut Frame<B>, state: &mut State) {
+pub fn render_ui<B: Backend>(f: &mut Frame<B>, state: &mut State, keymap: &Keymap, theme: &Theme) {
     let size = f.size();
 
     // split fram into 3 chunks
@@ -47,18 +62,36 @@ pub fn render_ui<B: Backend>(f: &mut Frame<B>, state: &mut State) {
 
 
     // set background
-    let block = Block::default().style(Style::default().bg(Color::White).fg(Color::Black));
+    let block = Block::default().style(theme.base());
     f.render_widget(block, size);
 
+    // panes record where they are drawn for the mouse
+    state.layout.clear_areas();
+
     // draw the header
-    draw_header(f, state, chunks[0]);
+    draw_header(f, state, theme, chunks[0]);
     
-    // draw list
-    draw_list_display(f, state, chunks[1]);
+    // draw list, search results while searching, the dashboard, board or agenda
+    match state.action_state{
+        ActionState::Search =>{draw_search(f, state, theme, chunks[1]);},
+        ActionState::Dashboard =>{draw_dashboard(f, state, theme, chunks[1]);},
+        ActionState::Board =>{draw_board(f, state, theme, chunks[1]);},
+        ActionState::Agenda =>{draw_agenda(f, state, theme, chunks[1]);},
+        ActionState::Account =>{draw_account(f, state, theme, chunks[1]);},
+        ActionState::Assigned =>{draw_assigned(f, state, theme, chunks[1]);},
+        ActionState::Share =>{draw_share(f, state, theme, chunks[1]);},
+        _ =>{draw_list_display(f, state, theme, chunks[1]);},
+    }
     
     // draw footer
-    let footer = draw_footer(state);
+    let context = state.get_key_context();
+    let footer = draw_footer(keymap, context, &state.message, theme);
     f.render_widget(footer, chunks[2]);
+
+    // draw help over everything else
+    if state.show_help{
+        draw_help(f, keymap, context, theme, size);
+    }
 }
 
 /*
@@ -67,30 +100,35 @@ pub fn render_ui<B: Backend>(f: &mut Frame<B>, state: &mut State) {
     Prams: 
         f: Fram the terminal fram to draw in
         state: State, the state of the app
+        theme: Theme, the colors to draw with
         size: Rect, space where header will be drawn
 */
-fn draw_header<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Rect){
+fn draw_header<B: Backend>(f: &mut Frame<B>, state: &mut State, theme: &Theme, size: Rect){
 
     // split size into chucks
     let header_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(
             [
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
-                Constraint::Percentage(33),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
             ]
             .as_ref())
         .split(size);
         // draw username
-        let username = draw_username(state);
+        let username = draw_username(state, theme);
         f.render_widget(username, header_chunks[0]);
         // draw mode
-        let mode = draw_mode(state);
+        let mode = draw_mode(state, theme);
         f.render_widget( mode, header_chunks[1]);
         // draw list name
-        let list_name = draw_list_name(state);
+        let list_name = draw_list_name(state, theme);
         f.render_widget( list_name, header_chunks[2]);
+        // draw item filter and sort
+        let view = draw_view(state, theme);
+        f.render_widget( view, header_chunks[3]);
 }
 
 /*
@@ -98,13 +136,14 @@ fn draw_header<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Rect){
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
     
     Returns: Paragraph, with user name
 */
-fn draw_username(state: &State) -> Paragraph{
+fn draw_username<'a>(state: &'a State, theme: &Theme) -> Paragraph<'a>{
 
     return Paragraph::new(state.get_username())
-        .style(Style::default())
+        .style(theme.base())
         .block(Block::default().borders(Borders::ALL).title("Current User"));
 }
 
@@ -113,12 +152,13 @@ fn draw_username(state: &State) -> Paragraph{
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
     
     Returns: Paragraph, with state mode
 */
-fn draw_mode(state: &State) -> Paragraph{
+fn draw_mode<'a>(state: &'a State, theme: &Theme) -> Paragraph<'a>{
     return Paragraph::new(state.get_mode())
-        .style(Style::default())
+        .style(theme.base())
         .block(Block::default().borders(Borders::ALL).title("Current Mode"));
 }
 
@@ -127,24 +167,41 @@ fn draw_mode(state: &State) -> Paragraph{
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
     
     Returns: Paragraph, with current list name
 */
-fn draw_list_name(state: &State) ->Paragraph{
+fn draw_list_name<'a>(state: &'a State, theme: &Theme) -> Paragraph<'a>{
     return Paragraph::new(state.get_list_name())
-        .style(Style::default())
+        .style(theme.base())
         .block(Block::default().borders(Borders::ALL).title("Selected List"));
 }
 
+/*
+    This function creates a Paragraph where the filter and sort of the current list is drawn
+
+    Prams: 
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+    
+    Returns: Paragraph, with current filter and sort
+*/
+fn draw_view<'a>(state: &'a State, theme: &Theme) -> Paragraph<'a>{
+    return Paragraph::new(state.get_view_name())
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).title("Item View"));
+}
+
 /*
     This function draws all todo lists and todo items
 
     Prams: 
         f: Fram the terminal fram to draw in
         state: State, the state of the app
+        theme: Theme, the colors to draw with
         size: Rect, space where lists will be drawn
 */
-fn draw_list_display<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Rect){
+fn draw_list_display<B: Backend>(f: &mut Frame<B>, state: &mut State, theme: &Theme, size: Rect){
 
     // split size into 2 chunks
     let list_input_chunks= Layout::default()
@@ -157,16 +214,18 @@ fn draw_list_display<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Rect
             .as_ref())
         .split(size);
         // displays the lists and input box
-        let all_list_chunk:Vec<Rect> = draw_list_input_box(f,  state, list_input_chunks[0], DrawList::AllLists);
+        let all_list_chunk:Vec<Rect> = draw_list_input_box(f, state, theme, list_input_chunks[0], DrawList::AllLists);
         // displays the lists and input box
-        let item_list_chunk: Vec<Rect> = draw_list_input_box(f,  state, list_input_chunks[1], DrawList::ListItems);
+        let item_list_chunk: Vec<Rect> = draw_list_input_box(f, state, theme, list_input_chunks[1], DrawList::ListItems);
 
         // set curser
         if state.input_box_list(){
-            f.set_cursor(all_list_chunk[1].x + state.input_list.width() as u16 + 1, all_list_chunk[1].y+1);
+            let (x, y) = input_cursor(&state.input_list, all_list_chunk[1]);
+            f.set_cursor(x, y);
         }
         else if state.input_box_item(){
-            f.set_cursor(item_list_chunk[1].x + state.input_item.width() as u16 + 1, item_list_chunk[1].y+1);
+            let (x, y) = input_cursor(&state.input_item, item_list_chunk[1]);
+            f.set_cursor(x, y);
         }
 }
 
@@ -176,12 +235,13 @@ fn draw_list_display<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Rect
     Prams: 
         f: Fram the terminal fram to draw in
         state: State, the state of the app
+        theme: Theme, the colors to draw with
         size: Rect, space where list
         draw_type: DrawList, the type of list to draw(AllLists, ListItems)
 
     Returns: Vec<Rect> containing the chunks for list and input box
 */
-fn draw_list_input_box<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Rect, draw_type: DrawList) -> Vec<Rect>{
+fn draw_list_input_box<B: Backend>(f: &mut Frame<B>, state: &mut State, theme: &Theme, size: Rect, draw_type: DrawList) -> Vec<Rect>{
 
     // split size into two chucks
     let list_input_chunk = Layout::default()
@@ -194,20 +254,42 @@ fn draw_list_input_box<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Re
             .as_ref())
         .split(size);
 
+    // rows shown in the list, minus the borders
+    state.page_size = list_input_chunk[0].height.saturating_sub(2) as usize;
+
     // draw either AllLists or ListItems
+    // the ListState scrolls the list to keep the selection visible
     match draw_type{
         DrawList::AllLists=>{
-            let list  = draw_list_todo_lists(state);
-            f.render_widget(list, list_input_chunk[0]);
+            let mut scroll = state.list_scroll.clone();
+            scroll.select(state.get_list_index());
+            let list  = draw_list_todo_lists(state, theme);
+            f.render_stateful_widget(list, list_input_chunk[0], &mut scroll);
+            state.list_scroll = scroll;
 
-            let input = draw_input_list_name(state);
+            // record the pane for the mouse
+            state.layout.lists = list_input_chunk[0];
+            state.layout.list_input = list_input_chunk[1];
+            state.layout.list_count = state.todo_lists.len();
+            state.layout.list_offset = scroll_offset(state.layout.list_offset, state.get_list_index(), state.layout.list_count, state.page_size);
+
+            let input = draw_input_list_name(state, theme, list_input_chunk[1]);
             f.render_widget(input, list_input_chunk[1]);
         },
         DrawList::ListItems=>{
-            let list  = draw_list_todo_items(state);
-            f.render_widget(list, list_input_chunk[0]);
+            let mut scroll = state.item_scroll.clone();
+            scroll.select(state.get_item_index());
+            let list  = draw_list_todo_items(state, theme);
+            f.render_stateful_widget(list, list_input_chunk[0], &mut scroll);
+            state.item_scroll = scroll;
+
+            // record the pane for the mouse
+            state.layout.items = list_input_chunk[0];
+            state.layout.item_input = list_input_chunk[1];
+            state.layout.item_count = state.current_list().map_or(0, |todo_list| todo_list.get_visible_len());
+            state.layout.item_offset = scroll_offset(state.layout.item_offset, state.get_item_index(), state.layout.item_count, state.page_size);
 
-            let input = draw_input_item_name(state);
+            let input = draw_input_item_name(state, theme, list_input_chunk[1]);
             f.render_widget(input, list_input_chunk[1]);
         },
     }
@@ -220,12 +302,14 @@ fn draw_list_input_box<B: Backend>(f: &mut Frame<B>, state: &mut State, size: Re
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the input box will be drawn
     
     Returns: Paragraph, with list input
 */
-fn draw_input_list_name(state: &State) -> Paragraph{
-    return Paragraph::new(state.input_list.as_ref())
-        .style(Style::default())
+fn draw_input_list_name<'a>(state: &'a State, theme: &Theme, size: Rect) -> Paragraph<'a>{
+    return Paragraph::new(input_text(&state.input_list, size))
+        .style(theme.base())
         .block(Block::default().borders(Borders::ALL).title("List Input"));
 }
 
@@ -234,12 +318,14 @@ fn draw_input_list_name(state: &State) -> Paragraph{
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the input box will be drawn
     
     Returns: Paragraph, with item ist input
 */
-fn draw_input_item_name(state: &State) -> Paragraph{
-    return Paragraph::new(state.input_item.as_ref())
-        .style(Style::default())
+fn draw_input_item_name<'a>(state: &'a State, theme: &Theme, size: Rect) -> Paragraph<'a>{
+    return Paragraph::new(input_text(&state.input_item, size))
+        .style(theme.base())
         .block(Block::default().borders(Borders::ALL).title("Item Input"));
 }
 
@@ -248,39 +334,36 @@ fn draw_input_item_name(state: &State) -> Paragraph{
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
     
     Returns: List of curret list todo items
 */
-fn draw_list_todo_items(state: &State) -> List{
+fn draw_list_todo_items<'a>(state: &'a State, theme: &Theme) -> List<'a>{
 
     let items: Vec<ListItem>;
 
-    // empty list
-    if state.todo_lists.len() == 0{
-        items = Vec::new();
-    }
-    else{
-        // creates ListItem for each item
-        items = state
-            .todo_lists[state.list_index]
-            .list
+    if let Some(todo_list) = state.current_list(){
+        // creates ListItem for each shown item
+        items = todo_list
+            .visible_indexes()
             .iter()
+            .map(|index| &todo_list.list[*index])
             .enumerate()    
             .map(|(i, m)| {
-                let content = vec![Spans::from(Span::raw(format!("{}: {}", i, m.get_item_name())))];
+                let content = vec![Spans::from(Span::raw(format!("{}: {}{}{}", i, m.get_item_name(), item_details(m), item_assignee(todo_list, m))))];
                 // item is selected
-                if state.item_selected() && i == state.item_index{
+                if state.item_selected() && Some(i) == state.get_item_index(){
                     if m.get_complete(){
                         // item is marked complete
-                        ListItem::new(content).style(Style::default().fg(Color::Red).bg(Color::Blue).add_modifier(Modifier::CROSSED_OUT))
+                        ListItem::new(content).style(theme.highlight().add_modifier(Modifier::CROSSED_OUT))
                     }
                     else{
-                        ListItem::new(content).style(Style::default().fg(Color::Red).bg(Color::Blue))
+                        ListItem::new(content).style(theme.highlight())
                     }
                 }
                 else if m.get_complete(){
                     // item is marked complete
-                    ListItem::new(content).style(Style::default().add_modifier(Modifier::CROSSED_OUT))
+                    ListItem::new(content).style(theme.completed())
                 }
                 else{
                     ListItem::new(content)
@@ -289,25 +372,70 @@ fn draw_list_todo_items(state: &State) -> List{
             })
             .collect();
     }
+    else{
+        // empty list
+        items = Vec::new();
+    }
 
     // Create the List 
+    let title = match state.current_list(){
+        Some(todo_list) =>{scroll_title("List Items", state.get_item_index().unwrap_or(0), todo_list.get_visible_len())},
+        None =>{String::from("List Items")},
+    };
     return List::new(items)
-        .block(Block::default().borders(Borders::ALL).title("List Items"))
-        .style(
-            Style::default()
-        );
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(theme.base());
 
 }
 
+/*
+    This function formats the due date and priority of an item
+
+    Prams: 
+        item: TodoItem, the item to describe
+    
+    Returns: String, empty if the item has no due date or priority
+*/
+fn item_details(item: &TodoItem) -> String{
+    let mut details = String::new();
+    if item.get_priority() > 0{
+        details.push_str(&format!(" [{}]", "!".repeat(item.get_priority() as usize)));
+    }
+    if let Some(due) = item.get_due(){
+        details.push_str(&format!(" (due {})", due.format(DATE_FORMAT)));
+    }
+    details
+}
+
+/*
+    This function formats the initials of the user an item is assigned to
+
+    Prams: 
+        todo_list: TodoList, the list of the item, holds the users with access
+        item: TodoItem, the item to describe
+    
+    Returns: String, e.g. " @BO", empty if the item is not assigned
+*/
+fn item_assignee(todo_list: &TodoList, item: &TodoItem) -> String{
+    match item.get_assignee(){
+        Some(user_id) =>{
+            let username: &str = todo_list.get_member_name(user_id).unwrap_or("?");
+            format!(" @{}", assignment::initials(username))
+        },
+        None =>{String::new()},
+    }
+}
+
 /*
     This function creates a list of all the todo lists
 
     Prams: 
         state: State, the state of the app
+        theme: Theme, the colors to draw with
     
     Returns: List of all the todo lists
 */
-fn draw_list_todo_lists(state: &State) -> List{
+fn draw_list_todo_lists<'a>(state: &'a State, theme: &Theme) -> List<'a>{
 
     // creates ListItem for each list
     let all_lists: Vec<ListItem>  = state
@@ -315,10 +443,10 @@ fn draw_list_todo_lists(state: &State) -> List{
         .iter()
         .enumerate()    
         .map(|(i, m)| {
-            let content = vec![Spans::from(Span::raw(format!("{}: {}", i, m.get_name())))];
+            let content = vec![Spans::from(Span::raw(format!("{}: {}", i, m.get_title())))];
             // item is selected
-            if state.list_selected() && i == state.list_index{
-                ListItem::new(content).style(Style::default().fg(Color::Red).bg(Color::Blue),)
+            if state.list_selected() && Some(i) == state.get_list_index(){
+                ListItem::new(content).style(theme.highlight())
             }
             else{
                 ListItem::new(content)
@@ -327,27 +455,677 @@ fn draw_list_todo_lists(state: &State) -> List{
     .collect();
 
     // Create the List
+    let title = scroll_title("All Lists", state.get_list_index().unwrap_or(0), state.todo_lists.len());
     return List::new(all_lists)
-    .block(Block::default().borders(Borders::ALL).title("All Lists"))
-    .style(
-        Style::default()
-    );
+    .block(Block::default().borders(Borders::ALL).title(title))
+    .style(theme.base());
+
+}
+
+/*
+    This function draws the search input and the matching items of all lists
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where search will be drawn
+*/
+fn draw_search<B: Backend>(f: &mut Frame<B>, state: &mut State, theme: &Theme, size: Rect){
+
+    // split size into input box and results
+    let search_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(1),
+            ]
+            .as_ref())
+        .split(size);
+
+    // search input box
+    let input = Paragraph::new(input_text(&state.search_input, search_chunks[0]))
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).title("Search"));
+    f.render_widget(input, search_chunks[0]);
+
+    // creates ListItem for each result with its list name
+    let results: Vec<ListItem> = state
+        .search_results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let content = vec![Spans::from(vec![
+                Span::styled(format!("{}: ", result.list_name), theme.accent()),
+                Span::raw(result.item_name.clone()),
+            ])];
+            // result is selected
+            if i == state.search_index{
+                ListItem::new(content).style(theme.highlight())
+            }
+            else{
+                ListItem::new(content)
+            }
+        })
+        .collect();
+
+    let title = scroll_title("Results", state.search_index, state.search_results.len());
+    let list = List::new(results)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(theme.base());
+    let mut scroll = state.search_scroll.clone();
+    scroll.select(if state.search_results.is_empty() {None} else {Some(state.search_index)});
+    f.render_stateful_widget(list, search_chunks[1], &mut scroll);
+    state.search_scroll = scroll;
+
+    // record the pane for the mouse
+    state.layout.search_results = search_chunks[1];
+    state.layout.search_count = state.search_results.len();
+    state.layout.search_offset = scroll_offset(state.layout.search_offset, Some(state.search_index), state.layout.search_count, search_chunks[1].height.saturating_sub(2) as usize);
 
+    // set curser
+    let (x, y) = input_cursor(&state.search_input, search_chunks[0]);
+    f.set_cursor(x, y);
 }
 
 /*
-    This function creates a Paragraph where messages to the user is drawn
+    This function draws the account screen, an input box for each setting and a message
 
     Prams: 
+        f: Fram the terminal fram to draw in
         state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the account screen will be drawn
+*/
+fn draw_account<B: Backend>(f: &mut Frame<B>, state: &mut State, theme: &Theme, size: Rect){
+
+    // the two-factor box is drawn on the right with the secret and recovery codes
+    let fields: Vec<AccountField> = AccountField::all()
+        .into_iter()
+        .filter(|field| field.is_input() && *field != AccountField::TwoFactor)
+        .collect();
+
+    // input boxes on the left, the forget button, two-factor and message on the right
+    let pane_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(60),
+                Constraint::Percentage(40),
+            ]
+            .as_ref())
+        .split(size);
+    let account_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(fields.iter().map(|_field| Constraint::Length(3)).collect::<Vec<Constraint>>())
+        .split(pane_chunks[0]);
+    let side_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(6),
+                Constraint::Length(5),
+            ]
+            .as_ref())
+        .split(pane_chunks[1]);
+
+    let form = &state.account;
+    let selected: AccountField = form.selected_field();
+    for (field, chunk) in fields.iter().zip(account_chunks.iter()){
+        let shown: bool = form.show_password && field.is_password();
+        let title: String = match field{
+            AccountField::Username =>{String::from("New Username")},
+            AccountField::CurrentPassword =>{String::from("Current Password")},
+            AccountField::NewPassword =>{String::from("New Password")},
+            AccountField::ConfirmPassword =>{String::from("Confirm New Password")},
+            AccountField::DeleteConfirm =>{format!("Type {} to Delete the Account", state.user.get_username())},
+            AccountField::ForgetSessions | AccountField::TwoFactor =>{String::new()},
+        };
+        let title: String = if shown {title + " (shown)"} else {title};
+
+        // password boxes are masked unless the user shows them
+        let typed: LineEditor = form.input(*field).cloned().unwrap_or_default();
+        let editor: LineEditor = if field.is_password() && !shown {typed.masked(PASSWORD_MASK)} else {typed};
+
+        // the selected box has a thick border
+        let mut block = Block::default().borders(Borders::ALL);
+        if *field == selected{
+            block = block.border_type(BorderType::Thick).title(Span::styled(title, theme.accent()));
+            let (x, y) = input_cursor(&editor, *chunk);
+            f.set_cursor(x, y);
+        }
+        else{
+            block = block.title(title);
+        }
+        let input = Paragraph::new(input_text(&editor, *chunk).to_string())
+            .style(theme.base())
+            .block(block);
+        f.render_widget(input, *chunk);
+    }
+
+    // forget remembered logins button, color blue if selected
+    let style = if selected == AccountField::ForgetSessions {theme.button()} else {theme.base()};
+    let forget = Paragraph::new(format!("Forget {} remembered logins", form.sessions))
+        .style(style)
+        .block(Block::default().borders(Borders::ALL).title("Remembered Logins"));
+    f.render_widget(forget, side_chunks[0]);
+
+    // two-factor code box, then the new secret while turning it on, or the recovery codes once
+    let two_factor_title: &str = if form.two_factor{
+        "Two-Factor Code to Turn Off"
+    }
+    else if form.totp_secret.is_some(){
+        "Two-Factor Code to Turn On"
+    }
+    else{
+        "Two-Factor, Enter to Start"
+    };
+    let mut two_factor_text: Vec<Spans> = vec![Spans::from(input_text(&form.totp_input, side_chunks[1]).to_string())];
+    match &form.totp_secret{
+        Some(secret) =>{
+            two_factor_text.push(Spans::from("Secret:"));
+            two_factor_text.push(Spans::from(secret.clone()));
+            two_factor_text.push(Spans::from(form.totp_uri.clone()));
+        },
+        None if form.two_factor =>{
+            two_factor_text.push(Spans::from(format!("On, {} recovery codes left", form.recovery_left)));
+            two_factor_text.extend(form.recovery_codes.iter().map(|code| Spans::from(code.clone())));
+        },
+        None =>{two_factor_text.push(Spans::from("Off"));},
+    }
+    let mut block = Block::default().borders(Borders::ALL);
+    if selected == AccountField::TwoFactor{
+        block = block.border_type(BorderType::Thick).title(Span::styled(two_factor_title, theme.accent()));
+        let (x, y) = input_cursor(&form.totp_input, side_chunks[1]);
+        f.set_cursor(x, y);
+    }
+    else{
+        block = block.title(two_factor_title);
+    }
+    let two_factor = Paragraph::new(two_factor_text)
+        .style(theme.base())
+        .wrap(Wrap{trim: false})
+        .block(block);
+    f.render_widget(two_factor, side_chunks[1]);
+
+    // message output box
+    let message = Paragraph::new(form.message.as_ref())
+        .style(theme.base())
+        .wrap(Wrap{trim: true})
+        .block(Block::default().borders(Borders::ALL).title("Message"));
+    f.render_widget(message, side_chunks[2]);
+}
+
+/*
+    This function draws the dashboard, the progress of every list and items completed per day
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the dashboard will be drawn
+*/
+fn draw_dashboard<B: Backend>(f: &mut Frame<B>, state: &State, theme: &Theme, size: Rect){
+
+    let stats: &Stats = match &state.stats{
+        Some(stats) =>{stats},
+        None =>{
+            let error = Paragraph::new("Could not load statistics")
+                .style(theme.base())
+                .block(Block::default().borders(Borders::ALL).title("Dashboard"));
+            f.render_widget(error, size);
+            return;
+        },
+    };
+
+    // split size into totals, list gauges and completed per day
+    let dashboard_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(6),
+            ]
+            .as_ref())
+        .split(size);
+
+    // totals of every list
+    let totals = Paragraph::new(format!("Open: {}    Done: {}    Overdue: {}", stats.open, stats.done, stats.overdue))
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).title("All Items"));
+    f.render_widget(totals, dashboard_chunks[0]);
+
+    // one gauge for each list that fits, starting at stats_offset
+    let lists_block = Block::default()
+        .borders(Borders::ALL)
+        .title(scroll_title("List Progress", state.stats_offset, stats.lists.len()))
+        .style(theme.base());
+    let lists_area = lists_block.inner(dashboard_chunks[1]);
+    f.render_widget(lists_block, dashboard_chunks[1]);
+    for (row, list) in stats.lists.iter().skip(state.stats_offset).take(lists_area.height as usize).enumerate(){
+        let mut label = format!("{}: {}/{} done", list.list_name, list.done, list.total);
+        if list.overdue > 0{
+            label.push_str(&format!(", {} overdue", list.overdue));
+        }
+        let gauge = Gauge::default()
+            .gauge_style(theme.gauge())
+            .ratio(completion_ratio(list.done, list.total))
+            .label(label);
+        f.render_widget(gauge, Rect::new(lists_area.x, lists_area.y + row as u16, lists_area.width, 1));
+    }
+
+    // items completed per day, oldest on the left
+    let max: u64 = stats.completed_per_day.iter().copied().max().unwrap_or(0);
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(format!("Completed Per Day, last {} days (max {})", STATS_DAYS, max)))
+        .data(&stats.completed_per_day)
+        .style(theme.accent());
+    f.render_widget(sparkline, dashboard_chunks[2]);
+}
+
+/*
+    This function draws the selected list as a board, one column of cards for each status
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the board will be drawn
+*/
+fn draw_board<B: Backend>(f: &mut Frame<B>, state: &State, theme: &Theme, size: Rect){
+
+    let todo_list = match state.current_list(){
+        Some(todo_list) =>{todo_list},
+        None =>{
+            let empty = Paragraph::new("No Active Lists")
+                .style(theme.base())
+                .block(Block::default().borders(Borders::ALL).title("Board"));
+            f.render_widget(empty, size);
+            return;
+        },
+    };
+
+    // split size into equal columns
+    let n: usize = todo_list.get_columns().len();
+    let constraints: Vec<Constraint> = (0..n).map(|_| Constraint::Ratio(1, n as u32)).collect();
+    let board_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(constraints)
+        .split(size);
+
+    for (column, name) in todo_list.get_columns().iter().enumerate(){
+        let selected_column: bool = column == state.board_column;
+        let indexes: Vec<usize> = todo_list.column_indexes(column as u32);
+
+        // creates ListItem for each card in the column
+        let cards: Vec<ListItem> = indexes
+            .iter()
+            .map(|index| &todo_list.list[*index])
+            .enumerate()
+            .map(|(i, m)| {
+                let content = vec![Spans::from(Span::raw(format!("{}{}", m.get_item_name(), item_details(m))))];
+                if selected_column && i == state.board_index{
+                    ListItem::new(content).style(theme.highlight())
+                }
+                else if m.get_complete(){
+                    ListItem::new(content).style(theme.completed())
+                }
+                else{
+                    ListItem::new(content)
+                }
+            })
+            .collect();
+
+        // the selected column has a thick border
+        let title: String = format!("{} [{}]", name, indexes.len());
+        let mut block = Block::default().borders(Borders::ALL);
+        if selected_column{
+            block = block.border_type(BorderType::Thick).title(Span::styled(title, theme.accent()));
+        }
+        else{
+            block = block.title(title);
+        }
+
+        let mut scroll = ListState::default();
+        scroll.select(if selected_column && !indexes.is_empty() {Some(state.board_index)} else {None});
+        let list = List::new(cards)
+            .block(block)
+            .style(theme.base());
+        f.render_stateful_widget(list, board_chunks[column], &mut scroll);
+    }
+}
+
+/*
+    This function draws the share screen, the username and access on the left,
+    the users the list is shared with on the right
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the share screen will be drawn
+*/
+fn draw_share<B: Backend>(f: &mut Frame<B>, state: &State, theme: &Theme, size: Rect){
+
+    let pane_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(60),
+                Constraint::Percentage(40),
+            ]
+            .as_ref())
+        .split(size);
+    let form_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ]
+            .as_ref())
+        .split(pane_chunks[0]);
+
+    // username box, always selected
+    let form = &state.share;
+    let list_name: String = state.current_list().map_or(String::new(), |todo_list| todo_list.get_name());
+    let title = Span::styled(format!("Share {} with Username", list_name), theme.accent());
+    let username = Paragraph::new(input_text(&form.username_input, form_chunks[0]).to_string())
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).border_type(BorderType::Thick).title(title));
+    f.render_widget(username, form_chunks[0]);
+    let (x, y) = input_cursor(&form.username_input, form_chunks[0]);
+    f.set_cursor(x, y);
+
+    // access toggle
+    let access = Paragraph::new(form.access_name())
+        .style(theme.button())
+        .block(Block::default().borders(Borders::ALL).title("Access, Tab to Change"));
+    f.render_widget(access, form_chunks[1]);
+
+    // users the list is shared with
+    let rows: Vec<ListItem> = form
+        .shares
+        .iter()
+        .map(|(username, access)| ListItem::new(format!("{}, {}", username, access.get_name())))
+        .collect();
+    let title: &str = if rows.is_empty() {"Not Shared"} else {"Shared With"};
+    let shares = List::new(rows)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(theme.base());
+    f.render_widget(shares, pane_chunks[1]);
+}
+
+/*
+    This function draws the items of every list assigned to the user
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the items will be drawn
+*/
+fn draw_assigned<B: Backend>(f: &mut Frame<B>, state: &State, theme: &Theme, size: Rect){
+
+    // creates ListItem for each item
+    let rows: Vec<ListItem> = state
+        .assigned
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let mut text = format!("{}: {}", entry.list_name, entry.item_name);
+            if let Some(due) = entry.due{
+                text.push_str(&format!(" (due {})", due.format(DATE_FORMAT)));
+            }
+            let content = Spans::from(text);
+            match (i == state.assigned_index, entry.complete){
+                (true, true) =>{ListItem::new(content).style(theme.highlight().add_modifier(Modifier::CROSSED_OUT))},
+                (true, false) =>{ListItem::new(content).style(theme.highlight())},
+                (false, true) =>{ListItem::new(content).style(theme.completed())},
+                (false, false) =>{ListItem::new(content)},
+            }
+        })
+        .collect();
+
+    let title = scroll_title("My Assigned Items", state.assigned_index, state.assigned.len());
+    let list = List::new(rows)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(theme.base());
+    let mut scroll = ListState::default();
+    scroll.select(if state.assigned.is_empty() {None} else {Some(state.assigned_index)});
+    f.render_stateful_widget(list, size, &mut scroll);
+}
+
+/*
+    This function draws the agenda, items of every list grouped by due date, and a month calendar
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+        size: Rect, space where the agenda will be drawn
+*/
+fn draw_agenda<B: Backend>(f: &mut Frame<B>, state: &State, theme: &Theme, size: Rect){
+
+    // split size into agenda items and calendar
+    let agenda_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(60),
+                Constraint::Percentage(40),
+            ]
+            .as_ref())
+        .split(size);
+
+    // creates ListItem for each item, with a header row above each group
+    let mut rows: Vec<ListItem> = Vec::new();
+    let mut selected_row: Option<usize> = None;
+    let mut group: Option<AgendaGroup> = None;
+    for (i, entry) in state.agenda.iter().enumerate(){
+        if group != Some(entry.group){
+            group = Some(entry.group);
+            rows.push(ListItem::new(Spans::from(Span::styled(entry.group.get_name(), theme.accent()))));
+        }
+        let content = Spans::from(format!("  {} {}: {}", entry.due.format(DATE_FORMAT), entry.list_name, entry.item_name));
+        if i == state.agenda_index{
+            selected_row = Some(rows.len());
+            rows.push(ListItem::new(content).style(theme.highlight()));
+        }
+        else{
+            rows.push(ListItem::new(content));
+        }
+    }
+
+    let title = scroll_title("Agenda", state.agenda_index, state.agenda.len());
+    let list = List::new(rows)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(theme.base());
+    let mut scroll = ListState::default();
+    scroll.select(selected_row);
+    f.render_stateful_widget(list, agenda_chunks[0], &mut scroll);
+
+    let calendar = draw_calendar(state, theme);
+    f.render_widget(calendar, agenda_chunks[1]);
+}
+
+/*
+    This function creates a Paragraph with the month of the selected calendar day,
+    days with open items due are marked with a *
+
+    Prams: 
+        state: State, the state of the app
+        theme: Theme, the colors to draw with
+    
+    Returns: Paragraph, with the month calendar
+*/
+fn draw_calendar<'a>(state: &State, theme: &Theme) -> Paragraph<'a>{
+
+    let due_days: HashSet<NaiveDate> = state.agenda.iter().map(|entry| entry.due).collect();
+    let today: NaiveDate = Local::now().date_naive();
+
+    let mut lines: Vec<Spans> = vec![Spans::from(" Mo  Tu  We  Th  Fr  Sa  Su")];
+    for week in month_weeks(state.calendar_day){
+        let days: Vec<Span> = week
+            .iter()
+            .map(|day| match day{
+                Some(day) =>{
+                    let marker: char = if due_days.contains(day) {'*'} else {' '};
+                    let text: String = format!("{:>3}{}", day.format("%-d"), marker);
+                    let mut style = if due_days.contains(day) {theme.accent()} else {theme.base()};
+                    if *day == today{
+                        style = style.add_modifier(Modifier::UNDERLINED);
+                    }
+                    if *day == state.calendar_day{
+                        style = theme.highlight();
+                    }
+                    Span::styled(text, style)
+                },
+                None =>{Span::raw("    ")},
+            })
+            .collect();
+        lines.push(Spans::from(days));
+    }
+
+    // the selected block shows where up and down move
+    let mut block = Block::default().borders(Borders::ALL).title(state.calendar_day.format("%B %Y").to_string());
+    if state.calendar_selected{
+        block = block.border_type(BorderType::Thick);
+    }
+
+    return Paragraph::new(lines)
+        .style(theme.base())
+        .block(block);
+}
+
+/*
+    This function finds the text shown in a bordered input box, scrolled to keep the cursor inside
+
+    Prams: 
+        editor: LineEditor, the input
+        size: Rect, space where the input box is drawn, borders included
+    
+    Returns: &str, the text from the first shown char
+*/
+pub fn input_text(editor: &LineEditor, size: Rect) -> &str{
+    editor.visible(size.width.saturating_sub(2) as usize).0
+}
+
+/*
+    This function finds where the cursor of a bordered input box is drawn
+
+    Prams: 
+        editor: LineEditor, the input
+        size: Rect, space where the input box is drawn, borders included
+    
+    Returns: (u16, u16), the column and row of the cursor
+*/
+pub fn input_cursor(editor: &LineEditor, size: Rect) -> (u16, u16){
+    let (_text, column) = editor.visible(size.width.saturating_sub(2) as usize);
+    (size.x + column + 1, size.y + 1)
+}
+
+/*
+    This function adds the scroll position to a list title
+
+    Prams: 
+        title: &str, the list title
+        index: usize, the selected row
+        n: usize, the number of rows
+    
+    Returns: String, title with the position e.g. "All Lists [3/20]"
+*/
+fn scroll_title(title: &str, index: usize, n: usize) -> String{
+    if n == 0{
+        return String::from(title);
+    }
+    format!("{} [{}/{}]", title, index.min(n - 1) + 1, n)
+}
+
+/*
+    This function creates a Paragraph where the keys of the current mode are drawn
+
+    Prams: 
+        keymap: Keymap, the active key bindings
+        context: KeyContext, the keys of the current mode
+        message: &str, the result of the last action, shown in the title
+        theme: Theme, the colors to draw with
     
-    Returns: Paragraph, with messages to the user
+    Returns: Paragraph, with the keys of the current mode
 */
-fn draw_footer(state: &State)-> Paragraph{
+fn draw_footer<'a>(keymap: &Keymap, context: KeyContext, message: &str, theme: &Theme) -> Paragraph<'a>{
 
-    let mut message_text = Text::from(Spans::from(state.footer_meaage.as_ref()));
-    message_text.patch_style(Style::default());
+    let mut message_text = Text::from(footer_text(keymap, context));
+    message_text.patch_style(theme.base());
 
     return Paragraph::new(message_text)
-    .block(Block::default().borders(Borders::ALL).title("Message"));
-}
\ No newline at end of file
+    .wrap(Wrap { trim: true })
+    .block(Block::default().borders(Borders::ALL).title(footer_title(message)));
+}
+
+/*
+    This function creates the title of the footer
+
+    Pram: message: &str, the result of the last action
+    Returns: String, "Keys" followed by the message if there is one
+*/
+fn footer_title(message: &str) -> String{
+    if message.is_empty(){
+        return String::from("Keys");
+    }
+    format!("Keys - {}", message)
+}
+
+/*
+    This function draws every key of the current mode over the app
+
+    Prams: 
+        f: Fram the terminal fram to draw in
+        keymap: Keymap, the active key bindings
+        context: KeyContext, the keys of the current mode
+        theme: Theme, the colors to draw with
+        size: Rect, the whole terminal
+*/
+fn draw_help<B: Backend>(f: &mut Frame<B>, keymap: &Keymap, context: KeyContext, theme: &Theme, size: Rect){
+
+    let area = centered_rect(70, 80, size);
+
+    // one row for each bound action
+    let mut rows: Vec<Row> = help_entries(keymap, context)
+        .into_iter()
+        .map(|entry| Row::new(vec![entry.keys, String::from(entry.description)]))
+        .collect();
+    if let Some(note) = context_note(context){
+        rows.push(Row::new(vec![String::new(), String::from(note)]));
+    }
+
+    let table = Table::new(rows)
+        .header(Row::new(vec!["Keys", "Action"]).style(theme.accent()))
+        .widths(&[Constraint::Percentage(30), Constraint::Percentage(70)])
+        .column_spacing(2)
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).title("Help - press any key to close"));
+
+    f.render_widget(Clear, area);
+    f.render_widget(table, area);
+}
+
+/*
+    This function finds a rect in the center of another
+
+    Prams: 
+        percent_x: u16, width as a percent of size
+        percent_y: u16, height as a percent of size
+        size: Rect, the rect to center in
+    
+    Returns: Rect, the centered rect
+*/
+fn centered_rect(percent_x: u16, percent_y: u16, size: Rect) -> Rect{
+    let width = size.width * percent_x / 100;
+    let height = size.height * percent_y / 100;
+    Rect::new(size.x + (size.width - width) / 2, size.y + (size.height - height) / 2, width, height)
+}
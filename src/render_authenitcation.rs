@@ -1,12 +1,18 @@
 use tui::{
     backend::Backend,
     layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
     text::{Spans, Text},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 use crate::user_authentication::user_authentication::{Authentication, SelectedChunk};
+use crate::theme::theme::Theme;
+use chrono::Local;
+use crate::render::{input_text, input_cursor};
+use crate::session::session::SESSION_DAYS;
+
+// shown for each typed password char
+pub const PASSWORD_MASK: char = '*';
 
 /*
     This function renders the user authentication login page
@@ -14,8 +20,9 @@ use crate::user_authentication::user_authentication::{Authentication, SelectedCh
     Prams:
         f: Frame useed to display widgets
         user_authenticate: Authenticationt holding the state of this page
+        theme: Theme, the colors to draw with
 */
-pub fn render_user_authentication<B: Backend>(f: &mut Frame<B>, user_authenticate: &mut Authentication) {
+pub fn render_user_authentication<B: Backend>(f: &mut Frame<B>, user_authenticate: &mut Authentication, theme: &Theme) {
 
     let size = f.size();
     // separate fram into chunks
@@ -24,76 +31,116 @@ pub fn render_user_authentication<B: Backend>(f: &mut Frame<B>, user_authenticat
         .margin(5)
         .constraints(
             [
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
-                Constraint::Percentage(20),
+                Constraint::Ratio(1, 6),
+                Constraint::Ratio(1, 6),
+                Constraint::Ratio(1, 6),
+                Constraint::Ratio(1, 6),
+                Constraint::Ratio(1, 6),
+                Constraint::Ratio(1, 6),
             ]
             .as_ref())
         .split(size);
 
     // set background
-    let block = Block::default().style(Style::default().bg(Color::White).fg(Color::Black));
+    let block = Block::default().style(theme.base());
     f.render_widget(block, size);
 
     // username input box
-    let username = Paragraph::new(user_authenticate.username_input.as_ref())
-    .style(Style::default())
+    let username = Paragraph::new(input_text(&user_authenticate.username_input, chunks[0]))
+    .style(theme.base())
     .block(Block::default().borders(Borders::ALL).title("username"));
     f.render_widget(username, chunks[0]);
 
-    // password input box
-    let password = Paragraph::new(user_authenticate.password_input.as_ref())
-    .style(Style::default())
-    .block(Block::default().borders(Borders::ALL).title("password"));
+    // password input boxes, masked unless the user shows them
+    let (password_input, confirm_input, password_title, confirm_title) = if user_authenticate.show_password{
+        (user_authenticate.password_input.clone(), user_authenticate.confirm_input.clone(), "password (shown)", "confirm password (shown)")
+    }
+    else{
+        (user_authenticate.password_input.masked(PASSWORD_MASK), user_authenticate.confirm_input.masked(PASSWORD_MASK), "password", "confirm password")
+    };
+    let password = Paragraph::new(input_text(&password_input, chunks[1]))
+    .style(theme.base())
+    .block(Block::default().borders(Borders::ALL).title(password_title));
     f.render_widget(password, chunks[1]);
 
 
     // login input button, color blue if selected
+    let login_text: String = if user_authenticate.remember_me{
+        format!("Login, remember me for {} days", SESSION_DAYS)
+    }
+    else{
+        String::from("Login")
+    };
     let login = match user_authenticate.selected_chunk{
         SelectedChunk::LoginButton=>{
-            Paragraph::new("Login")
-            .style(Style::default().fg(Color::Black).bg(Color::Blue))
+            Paragraph::new(login_text)
+            .style(theme.button())
             .block(Block::default().borders(Borders::ALL).title("Login"))},
         _ => {
-            Paragraph::new("Login")
-            .style(Style::default())
+            Paragraph::new(login_text)
+            .style(theme.base())
             .block(Block::default().borders(Borders::ALL).title("Login"))
         }
     };
     f.render_widget(login, chunks[2]);
 
+    // confirm password input box, only used to create an account,
+    // the two-factor code box takes its place while a login waits for a code
+    let confirm = if user_authenticate.code_pending(){
+        Paragraph::new(input_text(&user_authenticate.code_input, chunks[3]))
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).title("two-factor code or recovery code, Esc to cancel"))
+    }
+    else{
+        Paragraph::new(input_text(&confirm_input, chunks[3]))
+        .style(theme.base())
+        .block(Block::default().borders(Borders::ALL).title(confirm_title))
+    };
+    f.render_widget(confirm, chunks[3]);
+
     // create account input button, color blue if selected
     let create_account = match user_authenticate.selected_chunk{
         SelectedChunk::CreateAccountButton=>{
             Paragraph::new("Create Account")
-            .style(Style::default().fg(Color::Black).bg(Color::Blue))
+            .style(theme.button())
             .block(Block::default().borders(Borders::ALL).title("Create"))
         },
         _ => {
             Paragraph::new("Create Account")
-            .style(Style::default())
+            .style(theme.base())
             .block(Block::default().borders(Borders::ALL).title("Create"))
         },
     };
-    f.render_widget(create_account, chunks[3]);
+    f.render_widget(create_account, chunks[4]);
 
     // set the curser for username and password input
     match user_authenticate.selected_chunk{
         SelectedChunk::UsernameInput =>{
-            f.set_cursor(chunks[0].x + user_authenticate.username_input.len() as u16 + 1, chunks[0].y+1);
+            let (x, y) = input_cursor(&user_authenticate.username_input, chunks[0]);
+            f.set_cursor(x, y);
         },
         SelectedChunk::PasswordInput =>{
-            f.set_cursor(chunks[1].x + user_authenticate.password_input.len() as u16 + 1, chunks[1].y+1);
+            let (x, y) = input_cursor(&password_input, chunks[1]);
+            f.set_cursor(x, y);
+        },
+        SelectedChunk::ConfirmInput =>{
+            let (x, y) = input_cursor(&confirm_input, chunks[3]);
+            f.set_cursor(x, y);
+        },
+        SelectedChunk::CodeInput =>{
+            let (x, y) = input_cursor(&user_authenticate.code_input, chunks[3]);
+            f.set_cursor(x, y);
         },
         _ =>{},
     }
 
     // message output box
     let mut message_text = Text::from(Spans::from(user_authenticate.message.as_ref()));
-    message_text.patch_style(Style::default());
+    if let Some(lockout) = user_authenticate.lockout_message(Local::now().naive_local()){
+        message_text.extend(Text::from(lockout));
+    }
+    message_text.patch_style(theme.base());
     let message = Paragraph::new(message_text)
         .block(Block::default().borders(Borders::ALL).title("Message"));
-    f.render_widget(message, chunks[4]);
+    f.render_widget(message, chunks[5]);
 }
\ No newline at end of file
@@ -0,0 +1,141 @@
+pub mod search{
+
+    use crate::todo::todo::TodoList;
+    use std::cmp::Reverse;
+
+    /*
+        This structure holds one item that matched a search
+
+        Members:
+            list_index: usize, index of the list in todo_lists
+            item_index: usize, index of the item in the list
+            list_name: String, name of the list the item belongs to
+            item_name: String, name of the matching item
+            score: i64, how well the item matched, higher is better
+    */
+    pub struct SearchResult{
+        pub list_index: usize,
+        pub item_index: usize,
+        pub list_name: String,
+        pub item_name: String,
+        pub score: i64,
+    }
+
+    /*
+        This function scores how well a query matches some text.
+        A substring match always scores higher than a fuzzy match,
+        a fuzzy match requires every query char to appear in order.
+
+        Prams:
+            query: the text the user typed
+            text: the text to match against
+
+        Returns: Option<i64>
+            Some(score): the text matched, higher score is a better match
+            None: the text did not match
+    */
+    pub fn match_score(query: &str, text: &str) -> Option<i64>{
+
+        let query: String = query.trim().to_lowercase();
+        let text: String = text.to_lowercase();
+
+        // empty query matches nothing
+        if query.is_empty(){
+            return None;
+        }
+
+        // substring match, earlier matches score higher
+        if let Some(position) = text.find(&query){
+            return Some(10_000 - position as i64);
+        }
+
+        // fuzzy match, every query char must appear in order
+        let mut score: i64 = 0;
+        let mut last_match: Option<usize> = None;
+        let mut query_chars = query.chars().peekable();
+
+        for (i, c) in text.chars().enumerate(){
+            match query_chars.peek(){
+                Some(q) if *q == c =>{
+                    // consecutive matches score more than gaps
+                    score += match last_match{
+                        Some(last) if last + 1 == i => 10,
+                        Some(last) => 1 - (i - last) as i64,
+                        None => 1 - i as i64,
+                    };
+                    last_match = Some(i);
+                    query_chars.next();
+                },
+                Some(_) =>{},
+                None =>{break;},
+            }
+        }
+
+        // not all query chars were found
+        if query_chars.peek().is_some(){
+            return None;
+        }
+
+        Some(score)
+    }
+
+    /*
+        This function searches the items of every list
+
+        Prams:
+            todo_lists: all the user's todo lists
+            query: the text the user typed
+
+        Returns: Vec<SearchResult> sorted best match first
+    */
+    pub fn search_lists(todo_lists: &[TodoList], query: &str) -> Vec<SearchResult>{
+
+        let mut results: Vec<SearchResult> = Vec::new();
+
+        for (list_index, todo_list) in todo_lists.iter().enumerate(){
+            for (item_index, item) in todo_list.list.iter().enumerate(){
+                if let Some(score) = match_score(query, &item.get_item_name()){
+                    results.push(SearchResult{
+                        list_index,
+                        item_index,
+                        list_name: todo_list.get_name(),
+                        item_name: item.get_item_name(),
+                        score,
+                    });
+                }
+            }
+        }
+
+        // best match first, keep list order for ties
+        results.sort_by_key(|result| Reverse(result.score));
+
+        results
+    }
+
+    /*
+        This function turns user input into a LIKE pattern with the same rule as a fuzzy match,
+        every query char in order with anything between. The database finds candidates
+        with it and match_score ranks them, so both searches find the same items.
+
+        Prams:
+            query: the text the user typed
+
+        Returns: String, the pattern, % _ and \ are escaped with \, empty if the query is empty
+    */
+    pub fn like_pattern(query: &str) -> String{
+        let query: String = query.trim().to_lowercase();
+        if query.is_empty(){
+            return String::new();
+        }
+
+        let mut pattern: String = String::from("%");
+        for c in query.chars(){
+            if matches!(c, '%' | '_' | '\\'){
+                pattern.push('\\');
+            }
+            pattern.push(c);
+            pattern.push('%');
+        }
+        pattern
+    }
+}
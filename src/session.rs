@@ -0,0 +1,213 @@
+pub mod session{
+
+    use crate::database::database::TodoDatabase;
+    use crate::todo_item::todo_item::DATE_TIME_FORMAT;
+    use crate::user::user::User;
+    use chrono::{Duration, NaiveDateTime};
+    use sha2::{Digest, Sha256};
+    use std::fs;
+    use std::io::{self, Write};
+    use std::path::Path;
+    use thiserror::Error;
+
+    // how long a remembered login lasts
+    pub const SESSION_DAYS: i64 = 30;
+
+    // random bytes in a token
+    const TOKEN_BYTES: usize = 32;
+
+    /*
+        This enum is every reason a remembered login cannot be saved or read
+
+        Members:
+            File: the token file could not be read or written
+            Database: the sessions table could not be read or written
+    */
+    #[derive(Error, Debug)]
+    pub enum SessionError{
+        #[error("Session file error: {0}")]
+        File(#[from] io::Error),
+        #[error("Database error: {0}")]
+        Database(#[from] rusqlite::Error),
+    }
+
+    /*
+        This function creates a random token
+
+        Returns: String, TOKEN_BYTES random bytes as hex
+    */
+    pub fn new_token() -> String{
+        (0..TOKEN_BYTES).map(|_i| format!("{:02x}", rand::random::<u8>())).collect()
+    }
+
+    /*
+        This function hashes a token, only the hash is stored in the database
+
+        Pram: token: &str, the token
+        Returns: String, the SHA-256 of the token as hex
+    */
+    pub fn hash_token(token: &str) -> String{
+        Sha256::digest(token.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /*
+        This function remembers a login, the token is stored hashed in the database
+
+        Prams:
+            database: &TodoDatabase, the database to store the session in
+            user_id: u32, the user that logged in
+            now: NaiveDateTime, the current time
+
+        Returns: Result<String, SessionError>
+            Ok(String): the token to save in the session file
+            Err(SessionError): the database failed
+    */
+    pub fn issue(database: &TodoDatabase, user_id: u32, now: NaiveDateTime) -> Result<String, SessionError>{
+        let token: String = new_token();
+        let two_factor: bool = database.get_two_factor(user_id)?.is_some();
+        database.insert_session(&hash_token(&token), user_id, now + Duration::days(SESSION_DAYS), two_factor)?;
+        Ok(token)
+    }
+
+    /*
+        This function finds the user of a remembered login, expired logins are forgotten
+
+        Prams:
+            database: &TodoDatabase, the database the session is stored in
+            token: &str, the token from the session file
+            now: NaiveDateTime, the current time
+
+        Returns: Result<Option<User>, SessionError>
+            Ok(Some(User)): the token is valid, the user is logged in
+            Ok(None): the token is unknown, expired, or was issued before two-factor authentication was turned on
+            Err(SessionError): the database failed
+    */
+    pub fn resume(database: &TodoDatabase, token: &str, now: NaiveDateTime) -> Result<Option<User>, SessionError>{
+        let token_hash: String = hash_token(token);
+        let session = match database.get_session(&token_hash)?{
+            Some(session) =>{session},
+            None =>{return Ok(None);},
+        };
+
+        // an unreadable time counts as expired
+        let expired: bool = NaiveDateTime::parse_from_str(&session.expires, DATE_TIME_FORMAT).map_or(true, |expires| expires <= now);
+        if expired{
+            database.remove_session(&token_hash)?;
+            return Ok(None);
+        }
+
+        // a login from before two-factor authentication was turned on never gave a code
+        if !session.two_factor && database.get_two_factor(session.user_id)?.is_some(){
+            database.remove_session(&token_hash)?;
+            return Ok(None);
+        }
+
+        Ok(database.get_user_by_id(session.user_id)?.map(|user| User::new(user.user_id, user.username, user.password)))
+    }
+
+    /*
+        This function forgets a remembered login
+
+        Prams:
+            database: &TodoDatabase, the database the session is stored in
+            token: &str, the token from the session file
+
+        Returns: Result<(), SessionError>
+    */
+    pub fn revoke(database: &TodoDatabase, token: &str) -> Result<(), SessionError>{
+        database.remove_session(&hash_token(token))?;
+        Ok(())
+    }
+
+    /*
+        This function logs in with the token in the session file.
+        A token that is no longer valid is removed from the file.
+
+        Prams:
+            database: &TodoDatabase, the database the session is stored in
+            path: &Path, the session file
+            now: NaiveDateTime, the current time
+
+        Returns: Result<Option<User>, SessionError>
+            Ok(Some(User)): the user of the remembered login
+            Ok(None): there is no session file or the token is not valid
+            Err(SessionError): the file or database failed
+    */
+    pub fn resume_file(database: &TodoDatabase, path: &Path, now: NaiveDateTime) -> Result<Option<User>, SessionError>{
+        let token: String = match load_token(path){
+            Some(token) =>{token},
+            None =>{return Ok(None);},
+        };
+        let user: Option<User> = resume(database, &token, now)?;
+        if user.is_none(){
+            remove_token(path)?;
+        }
+        Ok(user)
+    }
+
+    /*
+        This function forgets the remembered login in the session file and removes the file
+
+        Prams:
+            database: &TodoDatabase, the database the session is stored in
+            path: &Path, the session file
+
+        Returns: Result<(), SessionError>
+    */
+    pub fn forget_file(database: &TodoDatabase, path: &Path) -> Result<(), SessionError>{
+        if let Some(token) = load_token(path){
+            revoke(database, &token)?;
+        }
+        remove_token(path)
+    }
+
+    /*
+        This function writes the token to the session file, only the user can read it
+
+        Prams:
+            path: &Path, the session file
+            token: &str, the token
+
+        Returns: Result<(), SessionError>
+    */
+    pub fn save_token(path: &Path, token: &str) -> Result<(), SessionError>{
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+            options.mode(0o600);
+            // the mode is only used when the file is created
+            if path.exists(){
+                fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+            }
+        }
+        let mut file = options.open(path)?;
+        file.write_all(token.as_bytes())?;
+        Ok(())
+    }
+
+    /*
+        This function reads the token from the session file
+
+        Pram: path: &Path, the session file
+        Returns: Option<String>, None if there is no session file
+    */
+    pub fn load_token(path: &Path) -> Option<String>{
+        let token: String = fs::read_to_string(path).ok()?.trim().to_string();
+        if token.is_empty() {None} else {Some(token)}
+    }
+
+    /*
+        This function removes the session file
+
+        Pram: path: &Path, the session file
+        Returns: Result<(), SessionError>, Ok if there was no session file
+    */
+    pub fn remove_token(path: &Path) -> Result<(), SessionError>{
+        match fs::remove_file(path){
+            Err(err) if err.kind() != io::ErrorKind::NotFound =>{Err(SessionError::File(err))},
+            _ =>{Ok(())},
+        }
+    }
+}
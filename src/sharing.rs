@@ -0,0 +1,210 @@
+pub mod sharing{
+
+    use crate::database::database::TodoDatabase;
+    use crate::line_editor::line_editor::LineEditor;
+    use crate::todo::todo::TodoList;
+    use crate::user::user::User;
+    use thiserror::Error;
+
+    /*
+        This enum is what a user can do with a list
+
+        Members:
+            Owner: the user created the list and can share or delete it
+            ReadWrite: the list is shared, the user can add, remove and change items
+            ReadOnly: the list is shared, the user can only see it
+    */
+    #[derive(PartialEq, Clone, Copy, Debug)]
+    pub enum ListAccess{
+        Owner,
+        ReadWrite,
+        ReadOnly,
+    }
+
+    impl ListAccess{
+        /*
+            Pram: can_write: u32, the can_write column of the list_shares table
+            Returns: ReadWrite for 1, ReadOnly otherwise
+        */
+        pub fn from_code(can_write: u32) -> ListAccess{
+            if can_write > 0 {ListAccess::ReadWrite} else {ListAccess::ReadOnly}
+        }
+
+        /*
+            Returns: true if items of the list can be added, removed or changed
+        */
+        pub fn can_write(self) -> bool{
+            self != ListAccess::ReadOnly
+        }
+
+        /*
+            Returns: &str, the name shown to the user
+        */
+        pub fn get_name(self) -> &'static str{
+            match self{
+                ListAccess::Owner => "owner",
+                ListAccess::ReadWrite => "read-write",
+                ListAccess::ReadOnly => "read only",
+            }
+        }
+    }
+
+    /*
+        This structure is a user with access to a list, the owner or a user it is shared with
+
+        Members:
+            user_id: u32, the user
+            username: String, the username of the user
+    */
+    #[derive(PartialEq, Clone, Debug)]
+    pub struct ListMember{
+        pub user_id: u32,
+        pub username: String,
+    }
+
+    /*
+        This structure is a share entered on the share screen
+
+        Members:
+            username: String, the user to share with
+            access: Option<ListAccess>, ReadWrite or ReadOnly, None to stop sharing
+    */
+    #[derive(PartialEq, Debug)]
+    pub struct ShareCommand{
+        pub username: String,
+        pub access: Option<ListAccess>,
+    }
+
+    /*
+        This enum is every reason a list cannot be shared
+
+        Members:
+            NotOwner: only the owner of the list can share it
+            UnknownUser: no account has the username
+            OwnList: the owner cannot share a list with themselves
+            Database: the database could not be read or written
+    */
+    #[derive(Error, Debug)]
+    pub enum ShareError{
+        #[error("Only the owner can share {0}")]
+        NotOwner(String),
+        #[error("User: {0} does not exist")]
+        UnknownUser(String),
+        #[error("{0} is already yours")]
+        OwnList(String),
+        #[error("Database error: {0}")]
+        Database(#[from] rusqlite::Error),
+    }
+
+    /*
+        This struct stores the input of the share screen
+
+        Members:
+            username_input: LineEditor, the user to share with
+            access: Option<ListAccess>, ReadOnly or ReadWrite to share, None to stop sharing
+            shares: Vec<(String, ListAccess)>, the users the list is shared with, loaded when the screen is opened
+    */
+    pub struct ShareForm{
+        pub username_input: LineEditor,
+        pub access: Option<ListAccess>,
+        pub shares: Vec<(String, ListAccess)>,
+    }
+
+    impl ShareForm{
+        /*
+            Returns: a ShareForm with an empty username that shares read only
+        */
+        pub fn new() -> ShareForm{
+            ShareForm{
+                username_input: LineEditor::new(),
+                access: Some(ListAccess::ReadOnly),
+                shares: Vec::new(),
+            }
+        }
+
+        /*
+            This method empties the username and shares read only again
+        */
+        pub fn clear(&mut self){
+            *self = ShareForm::new();
+        }
+
+        /*
+            This method changes the access from read only, to read-write, to stop sharing
+        */
+        pub fn toggle_access(&mut self){
+            self.access = match self.access{
+                Some(ListAccess::ReadOnly) =>{Some(ListAccess::ReadWrite)},
+                Some(ListAccess::ReadWrite) =>{None},
+                _ =>{Some(ListAccess::ReadOnly)},
+            };
+        }
+
+        /*
+            Returns: &str, the selected access shown to the user
+        */
+        pub fn access_name(&self) -> &'static str{
+            match self.access{
+                Some(access) =>{access.get_name()},
+                None =>{"stop sharing"},
+            }
+        }
+
+        /*
+            This method takes the typed username, the access is kept for the next share
+
+            Returns: Option<ShareCommand>, None if no username was typed
+        */
+        pub fn take_command(&mut self) -> Option<ShareCommand>{
+            let username: String = String::from(self.username_input.take().trim());
+            if username.is_empty(){
+                return None;
+            }
+            Some(ShareCommand{username, access: self.access})
+        }
+    }
+
+    impl Default for ShareForm{
+        fn default() -> ShareForm{
+            ShareForm::new()
+        }
+    }
+
+    /*
+        This function shares a list with another user or stops sharing it
+
+        Prams:
+            database: &TodoDatabase, the database the list is stored in
+            owner: &User, the user logged in, must own the list
+            todo_list: &TodoList, the list to share
+            command: &ShareCommand, who to share with and how
+
+        Returns: Result<String, ShareError>
+            Ok(String): a message for the user
+            Err(ShareError): the user is not the owner, the username is unknown, or the database failed
+    */
+    pub fn share_list(database: &TodoDatabase, owner: &User, todo_list: &TodoList, command: &ShareCommand) -> Result<String, ShareError>{
+        if todo_list.get_access() != ListAccess::Owner{
+            return Err(ShareError::NotOwner(todo_list.get_name()));
+        }
+
+        let user_id: u32 = match database.get_user_by_name(&command.username)?{
+            Some(user) =>{user.user_id},
+            None =>{return Err(ShareError::UnknownUser(command.username.clone()));},
+        };
+        if user_id == owner.get_user_id(){
+            return Err(ShareError::OwnList(todo_list.get_name()));
+        }
+
+        match command.access{
+            Some(access) =>{
+                database.share_list(todo_list.get_list_id(), user_id, access.can_write())?;
+                Ok(format!("Shared {} with {}, {}", todo_list.get_name(), command.username, access.get_name()))
+            },
+            None =>{
+                database.unshare_list(todo_list.get_list_id(), user_id)?;
+                Ok(format!("Stopped sharing {} with {}", todo_list.get_name(), command.username))
+            },
+        }
+    }
+}
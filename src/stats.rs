@@ -0,0 +1,99 @@
+pub mod stats{
+
+    use chrono::{Duration, NaiveDate};
+    use crate::database::database::{TodoDatabase, QueryListStats, QueryDayCount};
+    use crate::todo_item::todo_item::DATE_FORMAT;
+
+    // days of completed items shown on the dashboard, the last 4 weeks
+    pub const STATS_DAYS: usize = 28;
+
+    /*
+        This structure holds the numbers shown on the dashboard
+
+        Members:
+            lists: Vec<QueryListStats>, the item counts of each list
+            open: u64, items not complete in all lists
+            done: u64, items complete in all lists
+            overdue: u64, open items due before today in all lists
+            completed_per_day: Vec<u64>, items completed on each of the last STATS_DAYS days, oldest first
+    */
+    pub struct Stats{
+        pub lists: Vec<QueryListStats>,
+        pub open: u64,
+        pub done: u64,
+        pub overdue: u64,
+        pub completed_per_day: Vec<u64>,
+    }
+
+    impl Stats{
+        /*
+            This method queries the database for the dashboard numbers of a user
+
+            Prams:
+                database: TodoDatabase, the database to query
+                user_id: the unique identifier for a user
+                today: the last day of completed_per_day, items due before it are overdue
+
+            Returns: Result< Ok, Err>
+                Ok(Stats): the dashboard numbers
+                Err: there was an error while running the SQL commands
+        */
+        pub fn load(database: &TodoDatabase, user_id: u32, today: NaiveDate) -> Result<Stats, rusqlite::Error>{
+
+            let lists: Vec<QueryListStats> = database.get_list_stats(user_id, today)?;
+            let since: NaiveDate = today - Duration::days(STATS_DAYS as i64 - 1);
+            let days: Vec<QueryDayCount> = database.get_completed_per_day(user_id, since)?;
+
+            // totals of every list
+            let done: u64 = lists.iter().map(|list| list.done).sum();
+            let total: u64 = lists.iter().map(|list| list.total).sum();
+            let overdue: u64 = lists.iter().map(|list| list.overdue).sum();
+
+            Ok(Stats{
+                lists,
+                open: total - done,
+                done,
+                overdue,
+                completed_per_day: fill_days(&days, since),
+            })
+        }
+    }
+
+    /*
+        This function creates a count for every day, days without completed items are 0
+
+        Prams:
+            days: the days with completed items
+            since: the first day counted
+
+        Returns: Vec<u64>, STATS_DAYS counts, oldest first
+    */
+    pub fn fill_days(days: &[QueryDayCount], since: NaiveDate) -> Vec<u64>{
+        let mut counts: Vec<u64> = vec![0; STATS_DAYS];
+        for day in days{
+            if let Ok(date) = NaiveDate::parse_from_str(&day.day, DATE_FORMAT){
+                let offset: i64 = (date - since).num_days();
+                if (0..STATS_DAYS as i64).contains(&offset){
+                    counts[offset as usize] += day.count;
+                }
+            }
+        }
+        counts
+    }
+
+    /*
+        This function finds how much of a list is complete
+
+        Prams:
+            done: the number of completed items
+            total: the number of items
+
+        Returns: f64, from 0.0 to 1.0, 0.0 for an empty list
+    */
+    pub fn completion_ratio(done: u64, total: u64) -> f64{
+        if total == 0{
+            return 0.0;
+        }
+        (done as f64 / total as f64).clamp(0.0, 1.0)
+    }
+}
@@ -0,0 +1,259 @@
+pub mod theme{
+
+    use tui::style::{Color, Modifier, Style};
+    use crate::config::config::{ConfigError, ThemeConfig};
+
+    /*
+        This structure holds the colors every draw function uses
+
+        Members:
+            background: Color, the app background
+            foreground: Color, text and borders
+            highlight_fg: Color, text of the selected list, item or result
+            highlight_bg: Color, background of the selected list, item or result
+            button_fg: Color, text of the selected login button
+            button_bg: Color, background of the selected login button
+            completed: Color, text of crossed off items
+            accent: Color, list names in search results
+            no_color: bool, only use modifiers such as reverse, never colors
+    */
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Theme{
+        pub background: Color,
+        pub foreground: Color,
+        pub highlight_fg: Color,
+        pub highlight_bg: Color,
+        pub button_fg: Color,
+        pub button_bg: Color,
+        pub completed: Color,
+        pub accent: Color,
+        pub no_color: bool,
+    }
+
+    impl Theme{
+        /*
+            Returns: the light theme, black text on white
+        */
+        pub fn light() -> Theme{
+            Theme{
+                background: Color::White,
+                foreground: Color::Black,
+                highlight_fg: Color::Red,
+                highlight_bg: Color::Blue,
+                button_fg: Color::Black,
+                button_bg: Color::Blue,
+                completed: Color::Black,
+                accent: Color::Black,
+                no_color: false,
+            }
+        }
+
+        /*
+            Returns: the dark theme, light text on black
+        */
+        pub fn dark() -> Theme{
+            Theme{
+                background: Color::Black,
+                foreground: Color::Gray,
+                highlight_fg: Color::Black,
+                highlight_bg: Color::Cyan,
+                button_fg: Color::Black,
+                button_bg: Color::Cyan,
+                completed: Color::DarkGray,
+                accent: Color::LightBlue,
+                no_color: false,
+            }
+        }
+
+        /*
+            Returns: the high contrast theme, white on black with yellow selection
+        */
+        pub fn high_contrast() -> Theme{
+            Theme{
+                background: Color::Black,
+                foreground: Color::White,
+                highlight_fg: Color::Black,
+                highlight_bg: Color::Yellow,
+                button_fg: Color::Black,
+                button_bg: Color::Yellow,
+                completed: Color::White,
+                accent: Color::Yellow,
+                no_color: false,
+            }
+        }
+
+        /*
+            Returns: the theme used when NO_COLOR is set, the terminal's own colors
+        */
+        pub fn no_color() -> Theme{
+            Theme{
+                background: Color::Reset,
+                foreground: Color::Reset,
+                highlight_fg: Color::Reset,
+                highlight_bg: Color::Reset,
+                button_fg: Color::Reset,
+                button_bg: Color::Reset,
+                completed: Color::Reset,
+                accent: Color::Reset,
+                no_color: true,
+            }
+        }
+
+        /*
+            Pram: name of a built in theme
+            Returns: Option<Theme>, None if no theme has the name
+        */
+        pub fn from_name(name: &str) -> Option<Theme>{
+            match name{
+                "light" => Some(Theme::light()),
+                "dark" => Some(Theme::dark()),
+                "high_contrast" => Some(Theme::high_contrast()),
+                _ => None,
+            }
+        }
+
+        /*
+            This method creates the theme from the config file [theme] table.
+            NO_COLOR in the environment overrides the config.
+
+            Prams:
+                config: ThemeConfig, the [theme] table of the config file
+                no_color: bool, true if NO_COLOR is set
+
+            Returns: Result<Theme, ConfigError>
+                Ok(Theme): the theme
+                Err(ConfigError): an unknown theme, color name or color
+        */
+        pub fn from_config(config: &ThemeConfig, no_color: bool) -> Result<Theme, ConfigError>{
+
+            let mut theme: Theme = match &config.name{
+                Some(name) => Theme::from_name(name).ok_or_else(|| ConfigError::UnknownTheme(name.clone()))?,
+                None => Theme::light(),
+            };
+
+            // custom colors replace the built in ones
+            for (name, value) in &config.colors{
+                let color: Color = parse_color(value).ok_or_else(|| ConfigError::UnknownColor(value.clone()))?;
+                match name.as_str(){
+                    "background" => theme.background = color,
+                    "foreground" => theme.foreground = color,
+                    "highlight_fg" => theme.highlight_fg = color,
+                    "highlight_bg" => theme.highlight_bg = color,
+                    "button_fg" => theme.button_fg = color,
+                    "button_bg" => theme.button_bg = color,
+                    "completed" => theme.completed = color,
+                    "accent" => theme.accent = color,
+                    _ => return Err(ConfigError::UnknownColorName(name.clone())),
+                }
+            }
+
+            // the config is still checked so errors are not hidden by NO_COLOR
+            if no_color{
+                return Ok(Theme::no_color());
+            }
+
+            Ok(theme)
+        }
+
+        /*
+            Returns: Style of the app background and plain text
+        */
+        pub fn base(&self) -> Style{
+            Style::default().bg(self.background).fg(self.foreground)
+        }
+
+        /*
+            Returns: Style of the selected list, item or search result
+        */
+        pub fn highlight(&self) -> Style{
+            if self.no_color{
+                return Style::default().add_modifier(Modifier::REVERSED);
+            }
+            Style::default().fg(self.highlight_fg).bg(self.highlight_bg)
+        }
+
+        /*
+            Returns: Style of the selected login button
+        */
+        pub fn button(&self) -> Style{
+            if self.no_color{
+                return Style::default().add_modifier(Modifier::REVERSED);
+            }
+            Style::default().fg(self.button_fg).bg(self.button_bg)
+        }
+
+        /*
+            Returns: Style of crossed off items
+        */
+        pub fn completed(&self) -> Style{
+            if self.no_color{
+                return Style::default().add_modifier(Modifier::CROSSED_OUT);
+            }
+            Style::default().fg(self.completed).add_modifier(Modifier::CROSSED_OUT)
+        }
+
+        /*
+            Returns: Style of the filled part of dashboard gauges, with NO_COLOR only the label is shown
+        */
+        pub fn gauge(&self) -> Style{
+            if self.no_color{
+                return Style::default().add_modifier(Modifier::BOLD);
+            }
+            Style::default().fg(self.highlight_bg).bg(self.background)
+        }
+
+        /*
+            Returns: Style of list names in search results
+        */
+        pub fn accent(&self) -> Style{
+            if self.no_color{
+                return Style::default().add_modifier(Modifier::BOLD);
+            }
+            Style::default().fg(self.accent).add_modifier(Modifier::BOLD)
+        }
+    }
+
+    /*
+        This function parses a color such as "red", "light_blue", "#ff8800" or "208"
+
+        Pram: the color as written in the config file
+        Returns: Option<Color>, None if the color is not valid
+    */
+    pub fn parse_color(color: &str) -> Option<Color>{
+
+        // hex colors
+        if let Some(hex) = color.strip_prefix('#'){
+            if hex.len() != 6{
+                return None;
+            }
+            let value: u32 = u32::from_str_radix(hex, 16).ok()?;
+            return Some(Color::Rgb((value >> 16) as u8, (value >> 8) as u8, value as u8));
+        }
+
+        // 256 color palette index
+        if let Ok(index) = color.parse::<u8>(){
+            return Some(Color::Indexed(index));
+        }
+
+        match color.to_lowercase().as_str(){
+            "reset" => Some(Color::Reset),
+            "black" => Some(Color::Black),
+            "red" => Some(Color::Red),
+            "green" => Some(Color::Green),
+            "yellow" => Some(Color::Yellow),
+            "blue" => Some(Color::Blue),
+            "magenta" => Some(Color::Magenta),
+            "cyan" => Some(Color::Cyan),
+            "gray" => Some(Color::Gray),
+            "dark_gray" => Some(Color::DarkGray),
+            "light_red" => Some(Color::LightRed),
+            "light_green" => Some(Color::LightGreen),
+            "light_yellow" => Some(Color::LightYellow),
+            "light_blue" => Some(Color::LightBlue),
+            "light_magenta" => Some(Color::LightMagenta),
+            "light_cyan" => Some(Color::LightCyan),
+            "white" => Some(Color::White),
+            _ => None,
+        }
+    }
+}
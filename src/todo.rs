@@ -1,6 +1,15 @@
 pub mod todo{
 
     use crate::todo_item::todo_item::TodoItem;
+    use crate::item_view::item_view::{ItemFilter, ItemSort};
+    use crate::sharing::sharing::{ListAccess, ListMember};
+    use std::cmp::Reverse;
+
+    // board columns of a list that has not set its own, the last column is complete
+    pub const DEFAULT_COLUMNS: [&str; 3] = ["Todo", "Doing", "Done"];
+
+    // fewest columns a board can have, one open and one complete
+    pub const MIN_COLUMNS: usize = 2;
 
     /*
         The structure for TodoList
@@ -9,11 +18,23 @@ pub mod todo{
             name: String the name of the list
             list_id: u32 uniquly identify the todolist
             List: Vec of TodoItems, all items in the todo list
+            filter: ItemFilter, which items are shown
+            sort: ItemSort, the order items are shown in
+            columns: Vec<String>, names of the board columns, the last column is complete
+            owner: Option<String>, username of the owner of a shared list, None for the user's own lists
+            access: ListAccess, what the user logged in can do with the list
+            members: Vec<ListMember>, the users with access to the list, the owner first
     */
     pub struct TodoList{
         name: String,
         list_id: u32,
         pub list: Vec<TodoItem>,
+        filter: ItemFilter,
+        sort: ItemSort,
+        columns: Vec<String>,
+        owner: Option<String>,
+        access: ListAccess,
+        members: Vec<ListMember>,
     }
 
     impl TodoList{
@@ -30,6 +51,12 @@ pub mod todo{
                 name: list_name,
                 list_id,
                 list: Vec::new(),
+                filter: ItemFilter::All,
+                sort: ItemSort::Insertion,
+                columns: DEFAULT_COLUMNS.iter().map(|column| String::from(*column)).collect(),
+                owner: None,
+                access: ListAccess::Owner,
+                members: Vec::new(),
             }
         }
         
@@ -70,6 +97,11 @@ pub mod todo{
             }
 
             self.list[index].toggle_complete();
+
+            // complete items move to the done column, open items to the first
+            let status: u32 = if self.list[index].get_complete() {self.done_status()} else {0};
+            let complete: bool = self.list[index].get_complete();
+            self.list[index].set_status(status, complete);
             
             return true
         }
@@ -126,6 +158,244 @@ pub mod todo{
         pub fn get_item_id(&self, index: usize) ->u32{
             return self.list[index].get_item_id();
         }
+
+        /*
+            Marks the list as shared with the user logged in
+            Prams:
+                owner: String, username of the owner
+                access: ListAccess, ReadWrite or ReadOnly
+        */
+        pub fn set_shared(&mut self, owner: String, access: ListAccess){
+            self.owner = Some(owner);
+            self.access = access;
+        }
+
+        /*
+            Returns: Option<&str>, username of the owner of a shared list, None for the user's own lists
+        */
+        pub fn get_owner(&self) -> Option<&str>{
+            self.owner.as_deref()
+        }
+
+        /*
+            Returns: ListAccess, what the user logged in can do with the list
+        */
+        pub fn get_access(&self) -> ListAccess{
+            self.access
+        }
+
+        /*
+            Returns: true if items of the list can be added, removed or changed
+        */
+        pub fn can_write(&self) -> bool{
+            self.access.can_write()
+        }
+
+        /*
+            Sets the users with access to the list
+            Pram: members: Vec<ListMember>, the owner first
+        */
+        pub fn set_members(&mut self, members: Vec<ListMember>){
+            self.members = members;
+        }
+
+        /*
+            Returns: &[ListMember], the users items of the list can be assigned to
+        */
+        pub fn get_members(&self) -> &[ListMember]{
+            &self.members
+        }
+
+        /*
+            Pram: user_id: u32, a user
+            Returns: Option<&str>, the username if the user has access to the list
+        */
+        pub fn get_member_name(&self, user_id: u32) -> Option<&str>{
+            self.members.iter().find(|member| member.user_id == user_id).map(|member| member.username.as_str())
+        }
+
+        /*
+            Returns: String, the list name, with the owner and access for a shared list e.g. "chores [bob, read only]"
+        */
+        pub fn get_title(&self) -> String{
+            match &self.owner{
+                Some(owner) =>{format!("{} [{}, {}]", self.name, owner, self.access.get_name())},
+                None =>{self.name.clone()},
+            }
+        }
+
+        /*
+            Returns: the filter used to show items
+        */
+        pub fn get_filter(&self) -> ItemFilter{
+            self.filter
+        }
+
+        /*
+            Returns: the order items are shown in
+        */
+        pub fn get_sort(&self) -> ItemSort{
+            self.sort
+        }
+
+        /*
+            Sets which items are shown
+            Pram: ItemFilter, the new filter
+        */
+        pub fn set_filter(&mut self, filter: ItemFilter){
+            self.filter = filter;
+        }
+
+        /*
+            Sets the order items are shown in
+            Pram: ItemSort, the new sort
+        */
+        pub fn set_sort(&mut self, sort: ItemSort){
+            self.sort = sort;
+        }
+
+        /*
+            Returns: the names of the board columns
+        */
+        pub fn get_columns(&self) -> &[String]{
+            &self.columns
+        }
+
+        /*
+            Returns: u32 the status of complete items, the last column
+        */
+        pub fn done_status(&self) -> u32{
+            (self.columns.len() - 1) as u32
+        }
+
+        /*
+            Sets the board columns, items keep their column if it still exists.
+            Complete items stay in the last column and open items are never moved into it.
+
+            Pram: Vec<String>, the column names
+            Return: bool, false if there are fewer than MIN_COLUMNS columns
+        */
+        pub fn set_columns(&mut self, columns: Vec<String>) -> bool{
+            if columns.len() < MIN_COLUMNS{
+                return false;
+            }
+            self.columns = columns;
+
+            let done: u32 = self.done_status();
+            for item in self.list.iter_mut(){
+                let status: u32 = if item.get_complete() {done} else {item.get_status().min(done - 1)};
+                let complete: bool = item.get_complete();
+                item.set_status(status, complete);
+            }
+            true
+        }
+
+        /*
+            Moves an item to a board column, the last column marks it complete
+            Prams:
+                index: usize, index of the TodoItem to move
+                status: u32, the column to move to
+            Return: bool, true if the item is moved; false otherwise
+        */
+        pub fn move_item(&mut self, index: usize, status: u32) -> bool{
+            if index >= self.list.len() || status > self.done_status(){
+                return false;
+            }
+            let done: bool = status == self.done_status();
+            self.list[index].set_status(status, done);
+            true
+        }
+
+        /*
+            This method finds the items of a board column, in the order of the sort.
+            The filter is not used so complete items are shown in the done column.
+
+            Pram: u32, the column
+            Returns: Vec<usize> indexes into list in the order they are shown
+        */
+        pub fn column_indexes(&self, status: u32) -> Vec<usize>{
+            let mut indexes: Vec<usize> = (0..self.list.len())
+                .filter(|i| self.list[*i].get_status() == status)
+                .collect();
+            self.sort_indexes(&mut indexes);
+            indexes
+        }
+
+        /*
+            This method applies the filter and sort to the list
+
+            Returns: Vec<usize> indexes into list in the order they are shown
+        */
+        pub fn visible_indexes(&self) -> Vec<usize>{
+
+            let mut indexes: Vec<usize> = (0..self.list.len())
+                .filter(|i| self.filter.shows(self.list[*i].get_complete()))
+                .collect();
+            self.sort_indexes(&mut indexes);
+            indexes
+        }
+
+        /*
+            This method sorts item indexes with the sort of the list
+
+            Pram: Vec<usize>, indexes into list to sort
+        */
+        fn sort_indexes(&self, indexes: &mut [usize]){
+
+            // sorts are stable so ties keep insertion order
+            match self.sort{
+                ItemSort::Insertion =>{},
+                ItemSort::Alphabetical =>{
+                    indexes.sort_by_key(|i| self.list[*i].get_item_name().to_lowercase());
+                },
+                ItemSort::Created =>{
+                    // items without a created date are oldest
+                    indexes.sort_by_key(|i| self.list[*i].get_created());
+                },
+                ItemSort::Due =>{
+                    // items without a due date go last
+                    indexes.sort_by_key(|i| (self.list[*i].get_due().is_none(), self.list[*i].get_due()));
+                },
+                ItemSort::Priority =>{
+                    indexes.sort_by_key(|i| Reverse(self.list[*i].get_priority()));
+                },
+            }
+        }
+
+        /*
+            Returns: usize the number of items shown with the current filter
+        */
+        pub fn get_visible_len(&self) -> usize{
+            self.list.iter().filter(|item| self.filter.shows(item.get_complete())).count()
+        }
     }
-}
 
+    /*
+        This function splits list input into the list name and board columns.
+        A word "cols:A,B,C" sets the columns, the last is the done column.
+        A cols word with fewer than MIN_COLUMNS columns is kept in the name.
+
+        Pram: &str, the text typed in the list input box
+        Returns: (String, Option<Vec<String>>)
+            String: the list name without the cols word
+            Option<Vec<String>>: the columns, None if not given
+    */
+    pub fn parse_list_input(input: &str) -> (String, Option<Vec<String>>){
+        let mut name_words: Vec<&str> = Vec::new();
+        let mut columns: Option<Vec<String>> = None;
+
+        for word in input.split_whitespace(){
+            let names: Vec<String> = word
+                .strip_prefix("cols:")
+                .map_or(Vec::new(), |names| names.split(',').filter(|name| !name.is_empty()).map(String::from).collect());
+            if names.len() >= MIN_COLUMNS{
+                columns = Some(names);
+            }
+            else{
+                name_words.push(word);
+            }
+        }
+
+        (name_words.join(" "), columns)
+    }
+}
@@ -1,5 +1,13 @@
 pub mod todo_item{
 
+    use chrono::{Local, NaiveDate, NaiveDateTime};
+
+    // formats dates are stored in the database with
+    pub const DATE_FORMAT: &str = "%Y-%m-%d";
+    pub const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    // highest priority an item can have, 0 is no priority
+    pub const MAX_PRIORITY: u32 = 3;
 
     /*
         This structue hold data about TodoItem
@@ -8,6 +16,11 @@ pub mod todo_item{
             item_name: String, item name
             item_id: u32, unique identifier for item
             complete: bool, complete status true = complete false = not complete
+            created: Option<NaiveDateTime>, when the item was created
+            due: Option<NaiveDate>, when the item is due
+            priority: u32, 0 = none up to MAX_PRIORITY
+            status: u32, the board column of the list the item is in, the last column is complete
+            assignee: Option<u32>, user_id of the user responsible for the item
 
 
     */
@@ -15,6 +28,11 @@ pub mod todo_item{
         item_name: String,
         item_id: u32,
         complete: bool,
+        created: Option<NaiveDateTime>,
+        due: Option<NaiveDate>,
+        priority: u32,
+        status: u32,
+        assignee: Option<u32>,
     }
     impl TodoItem{
 
@@ -31,6 +49,11 @@ pub mod todo_item{
                 item_name, 
                 item_id, 
                 complete: false,
+                created: Some(Local::now().naive_local()),
+                due: None,
+                priority: 0,
+                status: 0,
+                assignee: None,
             }
         }
         /*
@@ -46,6 +69,11 @@ pub mod todo_item{
                 item_name, 
                 item_id,
                 complete,
+                created: None,
+                due: None,
+                priority: 0,
+                status: 0,
+                assignee: None,
             }
         }
 
@@ -57,6 +85,17 @@ pub mod todo_item{
             self.complete = !self.complete;
         }
 
+        /*
+            Sets the board column of the item
+            Prams:
+                status: u32, the column index
+                complete: bool, true if status is the done column of the list
+        */
+        pub fn set_status(&mut self, status: u32, complete: bool){
+            self.status = status;
+            self.complete = complete;
+        }
+
         /*
             Return: return a clone of the item name
         */
@@ -71,6 +110,13 @@ pub mod todo_item{
             return self.complete;
         }
 
+        /*
+            Return: u32 the board column of the item
+        */
+        pub fn get_status(&self) -> u32{
+            self.status
+        }
+
         /*
             Return: u32 the item id
         */
@@ -78,5 +124,96 @@ pub mod todo_item{
             return self.item_id;
         }
 
+        /*
+            Return: when the item was created, None for items created before dates were stored
+        */
+        pub fn get_created(&self) -> Option<NaiveDateTime>{
+            self.created
+        }
+
+        /*
+            Return: when the item is due, None if it has no due date
+        */
+        pub fn get_due(&self) -> Option<NaiveDate>{
+            self.due
+        }
+
+        /*
+            Return: u32 the item priority, 0 = none
+        */
+        pub fn get_priority(&self) -> u32{
+            self.priority
+        }
+
+        /*
+            Sets when the item was created
+            Pram: created, the date and time
+        */
+        pub fn set_created(&mut self, created: Option<NaiveDateTime>){
+            self.created = created;
+        }
+
+        /*
+            Sets when the item is due
+            Pram: due, the date or None to clear it
+        */
+        pub fn set_due(&mut self, due: Option<NaiveDate>){
+            self.due = due;
+        }
+
+        /*
+            Sets the item priority, capped at MAX_PRIORITY
+            Pram: priority, 0 = none
+        */
+        pub fn set_priority(&mut self, priority: u32){
+            self.priority = priority.min(MAX_PRIORITY);
+        }
+
+        /*
+            Return: Option<u32> user_id of the user responsible for the item, None if unassigned
+        */
+        pub fn get_assignee(&self) -> Option<u32>{
+            self.assignee
+        }
+
+        /*
+            Sets the user responsible for the item
+            Pram: assignee, a user_id or None to unassign
+        */
+        pub fn set_assignee(&mut self, assignee: Option<u32>){
+            self.assignee = assignee;
+        }
+
+    }
+
+    /*
+        This function splits item input into the item name, due date and priority.
+        A word "due:YYYY-MM-DD" sets the due date and "p:N" sets the priority,
+        every other word is part of the name.
+
+        Prams:
+            input: the text the user entered
+
+        Returns: (String, Option<NaiveDate>, u32) name, due date and priority
+    */
+    pub fn parse_item_input(input: &str) -> (String, Option<NaiveDate>, u32){
+
+        let mut name_words: Vec<&str> = Vec::new();
+        let mut due: Option<NaiveDate> = None;
+        let mut priority: u32 = 0;
+
+        for word in input.split_whitespace(){
+            if let Some(date) = word.strip_prefix("due:").and_then(|date| NaiveDate::parse_from_str(date, DATE_FORMAT).ok()){
+                due = Some(date);
+            }
+            else if let Some(level) = word.strip_prefix("p:").and_then(|level| level.parse::<u32>().ok()){
+                priority = level.min(MAX_PRIORITY);
+            }
+            else{
+                name_words.push(word);
+            }
+        }
+
+        (name_words.join(" "), due, priority)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,220 @@
+pub mod two_factor{
+
+    use crate::database::database::TodoDatabase;
+    use crate::session::session::hash_token;
+    use totp_rs::{Algorithm, Secret, TOTP};
+    use thiserror::Error;
+
+    // shown as the issuer in authenticator apps
+    pub const ISSUER: &str = "todo_list_rust";
+    // digits of a code
+    const TOTP_DIGITS: usize = 6;
+    // seconds each code is valid
+    const TOTP_STEP: u64 = 30;
+    // codes of the step before and after are also accepted, for clocks that are off
+    const TOTP_SKEW: u64 = 1;
+    // random bytes in a secret, 160 bits as RFC 4226 recommends
+    const SECRET_BYTES: usize = 20;
+    // recovery codes given when two-factor authentication is turned on
+    pub const RECOVERY_CODES: usize = 8;
+    // random bytes in a recovery code
+    const RECOVERY_CODE_BYTES: usize = 8;
+
+    /*
+        This enum is every reason a code cannot be checked or two-factor authentication cannot be changed
+
+        Members:
+            WrongCode: the code is not the current code or an unused recovery code
+            NotEnabled: the user has not turned on two-factor authentication
+            Secret: the secret is not valid base32 or too short
+            Database: the database could not be read or written
+    */
+    #[derive(Error, Debug)]
+    pub enum TwoFactorError{
+        #[error("Wrong code, type the code from your authenticator app or a recovery code")]
+        WrongCode,
+        #[error("Two-factor authentication is not turned on")]
+        NotEnabled,
+        #[error("Two-factor secret is not valid: {0}")]
+        Secret(String),
+        #[error("Database error: {0}")]
+        Database(#[from] rusqlite::Error),
+    }
+
+    /*
+        This function creates a random secret
+
+        Returns: String, SECRET_BYTES random bytes as base32 without padding
+    */
+    pub fn new_secret() -> String{
+        let bytes: Vec<u8> = (0..SECRET_BYTES).map(|_i| rand::random::<u8>()).collect();
+        Secret::Raw(bytes).to_encoded().to_string()
+    }
+
+    /*
+        This function creates the RFC 6238 code generator of a secret
+
+        Prams:
+            secret: &str, the secret as base32
+            username: &str, the account name shown in authenticator apps
+
+        Returns: Result<TOTP, TwoFactorError>
+    */
+    pub fn totp(secret: &str, username: &str) -> Result<TOTP, TwoFactorError>{
+        let bytes: Vec<u8> = Secret::Encoded(String::from(secret))
+            .to_bytes()
+            .map_err(|err| TwoFactorError::Secret(err.to_string()))?;
+        TOTP::new(Algorithm::SHA1, TOTP_DIGITS, TOTP_SKEW as u8, TOTP_STEP, bytes, Some(String::from(ISSUER)), String::from(username))
+            .map_err(|err| TwoFactorError::Secret(err.to_string()))
+    }
+
+    /*
+        This function creates the otpauth URI authenticator apps read to add the secret
+
+        Prams:
+            secret: &str, the secret as base32
+            username: &str, the account name shown in authenticator apps
+
+        Returns: Result<String, TwoFactorError>, e.g. "otpauth://totp/todo_list_rust:alice?secret=...&issuer=todo_list_rust"
+    */
+    pub fn otpauth_uri(secret: &str, username: &str) -> Result<String, TwoFactorError>{
+        Ok(totp(secret, username)?.get_url())
+    }
+
+    /*
+        This function finds the time step a code was made for
+
+        Prams:
+            secret: &str, the secret as base32
+            code: &str, the code typed
+            time: u64, seconds since the unix epoch
+
+        Returns: Option<u64>, the step of the code, None if the code is not valid within TOTP_SKEW steps
+    */
+    pub fn code_step(secret: &str, code: &str, time: u64) -> Option<u64>{
+        let totp: TOTP = totp(secret, "").ok()?;
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        let step: u64 = time / TOTP_STEP;
+        (step.saturating_sub(TOTP_SKEW)..=step + TOTP_SKEW).find(|step| totp.generate(step * TOTP_STEP) == code)
+    }
+
+    /*
+        This function creates the code for a time, what an authenticator app shows
+
+        Prams:
+            secret: &str, the secret as base32
+            time: u64, seconds since the unix epoch
+
+        Returns: Result<String, TwoFactorError>, TOTP_DIGITS digits
+    */
+    pub fn code_at(secret: &str, time: u64) -> Result<String, TwoFactorError>{
+        Ok(totp(secret, "")?.generate(time))
+    }
+
+    /*
+        This function creates one time recovery codes
+
+        Returns: Vec<String>, RECOVERY_CODES codes such as "3f2a-9c41-07be-d5e8"
+    */
+    pub fn new_recovery_codes() -> Vec<String>{
+        (0..RECOVERY_CODES)
+            .map(|_i|{
+                let hex: Vec<String> = (0..RECOVERY_CODE_BYTES / 2)
+                    .map(|_j| format!("{:04x}", rand::random::<u16>()))
+                    .collect();
+                hex.join("-")
+            })
+            .collect()
+    }
+
+    /*
+        This function hashes a recovery code, only the hash is stored in the database.
+        Dashes, spaces and case are ignored.
+
+        Pram: code: &str, the recovery code
+        Returns: String, the SHA-256 of the code as hex
+    */
+    pub fn hash_recovery_code(code: &str) -> String{
+        let code: String = code.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase();
+        hash_token(&code)
+    }
+
+    /*
+        This function turns on two-factor authentication once the user types a code made with the new secret
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user_id: u32, the user
+            secret: &str, the new secret as base32
+            code: &str, the code from the authenticator app
+            time: u64, seconds since the unix epoch
+
+        Returns: Result<Vec<String>, TwoFactorError>
+            Ok(Vec<String>): the recovery codes, shown to the user once, every remembered login is forgotten
+            Err(TwoFactorError): the code is wrong, or the database failed
+    */
+    pub fn enable(database: &TodoDatabase, user_id: u32, secret: &str, code: &str, time: u64) -> Result<Vec<String>, TwoFactorError>{
+        let step: u64 = code_step(secret, code, time).ok_or(TwoFactorError::WrongCode)?;
+
+        let codes: Vec<String> = new_recovery_codes();
+        let hashes: Vec<String> = codes.iter().map(|code| hash_recovery_code(code)).collect();
+        database.update_totp_secret(user_id, Some(secret))?;
+        database.update_totp_step(user_id, step)?;
+        database.replace_recovery_codes(user_id, &hashes)?;
+        // remembered logins never gave a code, they have to log in again
+        database.remove_user_sessions(user_id)?;
+        Ok(codes)
+    }
+
+    /*
+        This function turns off two-factor authentication, a code or recovery code is needed
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user_id: u32, the user
+            code: &str, a code from the authenticator app or a recovery code
+            time: u64, seconds since the unix epoch
+
+        Returns: Result<(), TwoFactorError>
+            Ok(()): two-factor authentication is off and the recovery codes are removed
+            Err(TwoFactorError): it is not on, the code is wrong, or the database failed
+    */
+    pub fn disable(database: &TodoDatabase, user_id: u32, code: &str, time: u64) -> Result<(), TwoFactorError>{
+        verify(database, user_id, code, time)?;
+        database.update_totp_secret(user_id, None)?;
+        database.replace_recovery_codes(user_id, &[])?;
+        Ok(())
+    }
+
+    /*
+        This function checks the second factor of a login.
+        A code is only accepted once, a recovery code is removed when it is used.
+
+        Prams:
+            database: &TodoDatabase, the database the user is stored in
+            user_id: u32, the user logging in
+            code: &str, a code from the authenticator app or a recovery code
+            time: u64, seconds since the unix epoch
+
+        Returns: Result<(), TwoFactorError>
+            Ok(()): the code is valid
+            Err(TwoFactorError): it is not on, the code is wrong or used, or the database failed
+    */
+    pub fn verify(database: &TodoDatabase, user_id: u32, code: &str, time: u64) -> Result<(), TwoFactorError>{
+        let two_factor = database.get_two_factor(user_id)?.ok_or(TwoFactorError::NotEnabled)?;
+
+        // a code cannot be used again, or a code older than the last one used
+        if let Some(step) = code_step(&two_factor.secret, code, time){
+            if two_factor.last_step.is_none_or(|last_step| step > last_step){
+                database.update_totp_step(user_id, step)?;
+                return Ok(());
+            }
+            return Err(TwoFactorError::WrongCode);
+        }
+
+        if database.use_recovery_code(user_id, &hash_recovery_code(code))?{
+            return Ok(());
+        }
+        Err(TwoFactorError::WrongCode)
+    }
+}
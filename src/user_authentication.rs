@@ -1,7 +1,15 @@
 pub mod user_authentication{
 
     use crate::database::database::{TodoDatabase, QueryUser};
+    use thiserror::Error;
+    use chrono::{DateTime, Duration, Local, NaiveDateTime};
+    use crate::todo_item::todo_item::DATE_TIME_FORMAT;
     use crate::user::user::User;
+    use crate::keymap::keymap::{Action, KeyContext};
+    use crate::line_editor::line_editor::LineEditor;
+    use crate::session::session::{self, SESSION_DAYS};
+    use crate::two_factor::two_factor::{self, TwoFactorError};
+    use std::path::PathBuf;
     use rand;
 
     /*
@@ -18,6 +26,87 @@ pub mod user_authentication{
         LoggedIn,
     }
 
+    // failed logins before each login has to wait
+    const BACKOFF_FAILURES: u32 = 3;
+    // failed logins before the username is locked
+    const LOCKOUT_FAILURES: u32 = 5;
+    // how long a locked username waits
+    const LOCKOUT_SECONDS: i64 = 15 * 60;
+    // audit reason when the password is right but a two-factor code is still needed
+    const CODE_REQUIRED: &str = "password correct, code required";
+
+    /*
+        This function finds how long a username waits after failed logins,
+        doubling from BACKOFF_FAILURES and locked for LOCKOUT_SECONDS from LOCKOUT_FAILURES
+
+        Pram: failures: u32, failed logins since the last successful login
+        Returns: Option<Duration>, None if the next login can be tried now
+    */
+    pub fn throttle_delay(failures: u32) -> Option<Duration>{
+        if failures < BACKOFF_FAILURES{
+            None
+        }
+        else if failures < LOCKOUT_FAILURES{
+            Some(Duration::seconds(1 << (failures - BACKOFF_FAILURES + 1)))
+        }
+        else{
+            Some(Duration::seconds(LOCKOUT_SECONDS))
+        }
+    }
+
+    /*
+        This function formats a wait for the login screen
+
+        Pram: seconds: i64, the time left
+        Returns: String, e.g. "42s" or "14m 5s"
+    */
+    pub fn format_wait(seconds: i64) -> String{
+        let seconds: i64 = seconds.max(1);
+        if seconds < 60{
+            format!("{}s", seconds)
+        }
+        else{
+            format!("{}m {}s", seconds / 60, seconds % 60)
+        }
+    }
+
+    /*
+        This enum is every reason logging in or creating an account fails,
+        the message is shown to the user
+
+        Members:
+            EmptyInput: the username or password is empty
+            UnknownUser: no account has the username
+            WrongPassword: the password does not match the account
+            UsernameTaken: an account already has the username
+            PasswordMismatch: the confirm password is not the same as the password
+            Locked: the username failed too many logins and must wait the seconds left
+            DeleteNotConfirmed: the username was not typed to confirm deleting the account
+            TwoFactor: the two-factor code is wrong or could not be checked
+            Database: the database could not be read or written
+    */
+    #[derive(Error, Debug)]
+    pub enum AuthError{
+        #[error("Enter a username and password")]
+        EmptyInput,
+        #[error("User: {0} does not exist. Create an account!")]
+        UnknownUser(String),
+        #[error("Wrong password for User: {0}")]
+        WrongPassword(String),
+        #[error("Username {0} is taken, pick a new username")]
+        UsernameTaken(String),
+        #[error("Passwords do not match, type the password again to confirm")]
+        PasswordMismatch,
+        #[error("Too many failed logins for User: {0}, try again in {}", format_wait(*.1))]
+        Locked(String, i64),
+        #[error("Type {0} in the delete box to delete the account")]
+        DeleteNotConfirmed(String),
+        #[error("{0}")]
+        TwoFactor(#[from] TwoFactorError),
+        #[error("Database error: {0}")]
+        Database(#[from] rusqlite::Error),
+    }
+
     /*
         This enum sets the current input box the user selects
 
@@ -25,13 +114,17 @@ pub mod user_authentication{
             UsernameInput: Allow user to enter keys for username
             PasswordInput:  Allow user to enter keys for password
             LoginButton: Allow user to press enter to login
+            ConfirmInput: Allow user to enter the password again to create an account
             CreateAccountButton: Allow user to press enter to create an account
+            CodeInput: Allow user to enter a two-factor code, only while a login waits for one
     */
     pub enum SelectedChunk{
         UsernameInput,
         PasswordInput,
         LoginButton,
+        ConfirmInput,
         CreateAccountButton,
+        CodeInput,
     }
 
     /*
@@ -39,8 +132,14 @@ pub mod user_authentication{
 
         Members:
             authentication_state: AuthenticationState state of the app
-            username_input: String, username input box
-            password_input: String, password input box
+            username_input: LineEditor, username input box
+            password_input: LineEditor, password input box
+            confirm_input: LineEditor, password confirmation input box, used to create an account
+            code_input: LineEditor, two-factor code input box, used after the password of a user with two-factor authentication
+            show_password: bool, the password boxes show the typed chars instead of a mask
+            remember_me: bool, a login is remembered so the next launch skips the login page
+            session_file: Option<PathBuf>, where a remembered login is saved, None if logins cannot be remembered
+            lockout: Option<(String, NaiveDateTime)>, the username that must wait and until when
             index: u32, the index use to select SelectedChunk
             database: TodoDatabase, the database for login and create account
             selected_chunk: SelectedChunk, the currently selected input box
@@ -49,11 +148,18 @@ pub mod user_authentication{
             user_id: u32, the user_id from login
             username: String, the username from login
             password: String, the password from login
+            pending: Option<QueryUser>, the user whose password was right and still needs a two-factor code
     */
     pub struct Authentication <'a>{
         pub authentication_state: AuthenticationState,
-        pub username_input: String,
-        pub password_input: String,
+        pub username_input: LineEditor,
+        pub password_input: LineEditor,
+        pub confirm_input: LineEditor,
+        pub code_input: LineEditor,
+        pub show_password: bool,
+        pub remember_me: bool,
+        pub session_file: Option<PathBuf>,
+        pub lockout: Option<(String, NaiveDateTime)>,
         pub index: u32,
         pub database: &'a TodoDatabase, 
         pub selected_chunk: SelectedChunk, 
@@ -62,7 +168,7 @@ pub mod user_authentication{
         user_id: u32,
         username: String,
         password: String,
-        
+        pending: Option<QueryUser>,
     }
 
     impl Authentication <'_>{
@@ -78,16 +184,23 @@ pub mod user_authentication{
         pub fn new(database: &TodoDatabase)->Authentication{
             Authentication{
                 authentication_state: AuthenticationState::Default,
-                username_input: String::new(),
-                password_input: String::new(),
+                username_input: LineEditor::new(),
+                password_input: LineEditor::new(),
+                confirm_input: LineEditor::new(),
+                code_input: LineEditor::new(),
+                show_password: false,
+                remember_me: false,
+                session_file: None,
+                lockout: None,
                 message: String::from("Create an account or login!"),
                 index: 0,
                 database,
                 selected_chunk: SelectedChunk::UsernameInput,
-                max_index: 4,
+                max_index: 5,
                 user_id: u32::MAX,
                 username: String::new(),
                 password: String::new(),
+                pending: None,
             }
         }
 
@@ -96,35 +209,250 @@ pub mod user_authentication{
         */
         pub fn login(&mut self){
 
-            // drain username
-            let username: String = self.username_input.drain(..).collect();
-            let password: String = self.password_input.drain(..).collect();
+            // take username and password
+            let username: String = self.username_input.take();
+            let password: String = self.password_input.take();
+            self.show_password = false;
+
+            let result: Result<QueryUser, AuthError> = self.check_login(&username, &password, Local::now().naive_local());
+            match result.and_then(|user| Ok((self.code_required(user.user_id)?, user))){
+                // the password is right, ask for the code
+                Ok((true, user)) =>{
+                    self.message = format!("Enter the 6 digit code from your authenticator app or a recovery code for User: {}", username);
+                    self.pending = Some(user);
+                    self.selected_chunk = SelectedChunk::CodeInput;
+                },
+                Ok((false, user)) =>{self.finish_login(user);},
+                Err(err) =>{self.message = err.to_string();},
+            }
+        }
+
+        /*
+            This method takes the two-factor code a user enters. Then tries to finish the login waiting for it
+        */
+        pub fn enter_code(&mut self){
+            let code: String = self.code_input.take();
+
+            match self.check_code(&code, Local::now()){
+                Ok(user) =>{self.finish_login(user);},
+                Err(err) =>{
+                    self.message = err.to_string();
+                    // a locked username starts the login again
+                    if self.pending.is_none(){
+                        self.selected_box();
+                    }
+                },
+            }
+        }
+
+        /*
+            This method logs in a user whose password, and code if needed, are right
+
+            Pram: user: QueryUser, the user logging in
+        */
+        fn finish_login(&mut self, user: QueryUser){
+            // store information from query
+            self.user_id = user.user_id;
+            self.message = String::from("User: ") + &user.username + " Logged in! Press any key to proceed."; // Login message
+            self.username = user.username;
+            self.password = user.password;
+            self.authentication_state = AuthenticationState::LoggedIn;
+            if self.remember_me{
+                self.remember_login();
+            }
+        }
+
+        /*
+            Pram: user_id: u32, the user logging in
+            Returns: Result<bool, AuthError>, true if the user turned on two-factor authentication
+        */
+        fn code_required(&self, user_id: u32) -> Result<bool, AuthError>{
+            Ok(self.database.get_two_factor(user_id)?.is_some())
+        }
+
+        /*
+            This method finds the user with a username and checks the password.
+            Failed logins make the username wait before the next login,
+            every login is added to the auth audit table.
+
+            Prams:
+                username: &str, the username entered
+                password: &str, the password entered
+                now: NaiveDateTime, the current time
+
+            Returns: Result<QueryUser, AuthError>
+                Ok(QueryUser): the user logging in
+                Err(AuthError): the username is waiting, the username or password is wrong, or the database failed
+        */
+        pub fn check_login(&mut self, username: &str, password: &str, now: NaiveDateTime) -> Result<QueryUser, AuthError>{
 
             // empty strings are not allowed
-            if username == "" || password == ""{
-                return;
+            if username.is_empty() || password.is_empty(){
+                return Err(AuthError::EmptyInput);
+            }
+
+            // refuse logins while the username waits
+            let failures: u32 = self.check_lockout(username, now)?;
+
+            let result: Result<QueryUser, AuthError> = match self.database.get_user_by_name(username)?{
+                None =>{Err(AuthError::UnknownUser(String::from(username)))},
+                Some(user) if user.password != password =>{Err(AuthError::WrongPassword(String::from(username)))},
+                Some(user) =>{Ok(user)},
+            };
+
+            match &result{
+                // failures are kept until the code is right too
+                Ok(user) if self.code_required(user.user_id)? =>{
+                    self.database.insert_auth_audit(username, true, CODE_REQUIRED, now)?;
+                },
+                _ =>{self.record_attempt(username, failures, &result, now)?;},
             }
-            
-            // query database for the user
-            let user_query: Vec<QueryUser> = match self.database.get_user_id(&username, &password){
-                Ok(res) => {res},
+            result
+        }
+
+        /*
+            This method checks the two-factor code of the login waiting for one.
+            A wrong code counts as a failed login, a code tried while the username waits cancels the login.
+
+            Prams:
+                code: &str, a code from the authenticator app or a recovery code
+                now: DateTime<Local>, the current time
+
+            Returns: Result<QueryUser, AuthError>
+                Ok(QueryUser): the user logging in
+                Err(AuthError): no login waits for a code, the username is waiting, the code is wrong, or the database failed
+        */
+        pub fn check_code(&mut self, code: &str, now: DateTime<Local>) -> Result<QueryUser, AuthError>{
+            let (user_id, username): (u32, String) = match &self.pending{
+                Some(user) =>{(user.user_id, user.username.clone())},
+                None =>{return Err(AuthError::TwoFactor(TwoFactorError::NotEnabled));},
+            };
+
+            let failures: u32 = match self.check_lockout(&username, now.naive_local()){
+                Ok(failures) =>{failures},
                 Err(err) =>{
-                    panic!("{}", err)
+                    self.pending = None;
+                    return Err(err);
                 },
             };
 
-            // only one user should be found
-            if user_query.len() == 1{
-                // store information from query
-                self.user_id = user_query[0].user_id;
-                self.username = user_query[0].username.clone();
-                self.password = user_query[0].password.clone();
-                self.authentication_state = AuthenticationState::LoggedIn;
-                self.message = String::from("User: ") + &username.clone() + &String::from(" Logged in! Press any key to proceed."); // Login message
+            let result: Result<(), AuthError> = two_factor::verify(self.database, user_id, code, now.timestamp() as u64).map_err(AuthError::from);
+            self.record_attempt(&username, failures, &result, now.naive_local())?;
+            result?;
+            self.pending.take().ok_or(AuthError::TwoFactor(TwoFactorError::NotEnabled))
+        }
+
+        /*
+            This method refuses logins for a username that must wait after failed logins
+
+            Prams:
+                username: &str, the username entered
+                now: NaiveDateTime, the current time
+
+            Returns: Result<u32, AuthError>
+                Ok(u32): the failed logins since the last successful login
+                Err(AuthError): the username is locked, or the database failed
+        */
+        fn check_lockout(&mut self, username: &str, now: NaiveDateTime) -> Result<u32, AuthError>{
+            let attempts = self.database.get_login_attempts(username)?;
+            let failures: u32 = attempts.as_ref().map_or(0, |attempts| attempts.failures);
+            let locked_until: Option<NaiveDateTime> = attempts
+                .and_then(|attempts| attempts.locked_until)
+                .and_then(|time| NaiveDateTime::parse_from_str(&time, DATE_TIME_FORMAT).ok());
+            if let Some(until) = locked_until.filter(|until| *until > now){
+                let err = AuthError::Locked(String::from(username), (until - now).num_seconds());
+                self.database.insert_auth_audit(username, false, &err.to_string(), now)?;
+                self.lockout = Some((String::from(username), until));
+                return Err(err);
+            }
+            Ok(failures)
+        }
+
+        /*
+            This method stores the result of a login in the attempts and auth audit tables
+
+            Prams:
+                username: &str, the username entered
+                failures: u32, the failed logins before this one
+                result: &Result<T, AuthError>, the result of the login
+                now: NaiveDateTime, the current time
+
+            Returns: Result<(), AuthError>, Err if the database failed
+        */
+        fn record_attempt<T>(&mut self, username: &str, failures: u32, result: &Result<T, AuthError>, now: NaiveDateTime) -> Result<(), AuthError>{
+            match result{
+                Ok(_value) =>{
+                    self.database.clear_login_attempts(username)?;
+                    self.database.insert_auth_audit(username, true, "", now)?;
+                    self.lockout = None;
+                },
+                Err(err) =>{
+                    // wait longer after each failure
+                    let locked_until: Option<NaiveDateTime> = throttle_delay(failures + 1).map(|delay| now + delay);
+                    self.database.update_login_attempts(username, failures + 1, locked_until)?;
+                    self.database.insert_auth_audit(username, false, &err.to_string(), now)?;
+                    self.lockout = locked_until.map(|until| (String::from(username), until));
+                },
+            }
+            Ok(())
+        }
+
+        /*
+            Returns: true if a login waits for a two-factor code
+        */
+        pub fn code_pending(&self) -> bool{
+            self.pending.is_some()
+        }
+
+        /*
+            This method cancels the login waiting for a two-factor code
+        */
+        fn cancel_code(&mut self){
+            if self.pending.take().is_some(){
+                self.code_input = LineEditor::new();
+                self.message = String::from("Login cancelled, enter the password again");
+                self.selected_box();
+            }
+        }
+
+        /*
+            This method saves a token for the user logged in to the session file
+        */
+        fn remember_login(&mut self){
+            let path: PathBuf = match &self.session_file{
+                Some(path) =>{path.clone()},
+                None =>{return;},
+            };
+            let saved = session::issue(self.database, self.user_id, Local::now().naive_local())
+                .and_then(|token| session::save_token(&path, &token));
+            match saved{
+                Ok(()) =>{self.message += &format!(" Login remembered for {} days.", SESSION_DAYS);},
+                Err(err) =>{self.message += &format!(" Login not remembered: {}", err);},
+            }
+        }
+
+        /*
+            This method turns remembering the next login on or off, only if logins can be remembered
+        */
+        pub fn toggle_remember(&mut self){
+            if self.session_file.is_some(){
+                self.remember_me = !self.remember_me;
+            }
+        }
+
+        /*
+            This method finds how long the last username tried must wait
+
+            Pram: now: NaiveDateTime, the current time
+            Returns: Option<String>, the message shown on the login screen, None if no username waits
+        */
+        pub fn lockout_message(&self, now: NaiveDateTime) -> Option<String>{
+            match &self.lockout{
+                Some((username, until)) if *until > now =>{
+                    Some(format!("Login for User: {} locked for {}", username, format_wait((*until - now).num_seconds())))
+                },
+                _ =>{None},
             }
-            else{
-                self.message = String::from("User: ") + &username.clone() + &String::from(" does not exist. Create an account!"); // user not found message
-            }       
         }
 
         /* 
@@ -132,47 +460,61 @@ pub mod user_authentication{
         */
         pub fn create_account(&mut self){
 
-            // drain username and password
-            let username:String = self.username_input.drain(..).collect();
-            let password:String = self.password_input.drain(..).collect();
+            // take username and password
+            let username:String = self.username_input.take();
+            let password:String = self.password_input.take();
+            let confirm:String = self.confirm_input.take();
+            self.show_password = false;
+
+            match self.register(&username, &password, &confirm){
+                Ok(_user_id) =>{self.message = String::from("Account create for User: ") + &username;}, // success message
+                Err(err) =>{self.message = err.to_string();},
+            }
+        }
+
+        /*
+            This method creates an account with a new random user_id
+
+            Prams:
+                username: &str, the username entered
+                password: &str, the password entered
+                confirm: &str, the password entered again
+
+            Returns: Result<u32, AuthError>
+                Ok(u32): the user_id of the new account
+                Err(AuthError): the input is not valid, the username is taken, or the database failed
+        */
+        pub fn register(&self, username: &str, password: &str, confirm: &str) -> Result<u32, AuthError>{
 
             // empty strings are not allowed
-            if username == "" || password == ""{
-                return;
+            if username.is_empty() || password.is_empty(){
+                return Err(AuthError::EmptyInput);
             }
 
-            // check if a user already exists with the same username and password
-            let user_query: Vec<QueryUser> = match self.database.get_user_id(&username, &password){
-                Ok(res) => {
-                    res},
-                Err(err) =>{panic!("{}", err)}, 
-            };
+            // the password must be typed the same twice
+            if password != confirm{
+                return Err(AuthError::PasswordMismatch);
+            }
 
-            // zero users should be found
-            if user_query.len() == 0{
-
-                let mut user_id: u32 ;
-
-                loop {
-                    // generate a random user_id
-                    user_id = rand::random::<u32>();
-                    if user_id == u32::MAX {continue;}
-                    // try to create an account, err when the user_id already exists
-                    match self.database.create_user_account(&username, &password, user_id){
-                        Ok(()) =>{break;},
-                        Err(err) => {
-                            match err.sqlite_error_code().unwrap(){
-                                rusqlite::ErrorCode::ConstraintViolation => { continue;}
-                                _ =>{panic!("{}", err)}
-                            }
-                        },
-                    }
-                }
-                self.message = String::from("Account create for User: ") + &username.clone(); // success message
+            // usernames are unique
+            if self.database.get_user_by_name(username)?.is_some(){
+                return Err(AuthError::UsernameTaken(String::from(username)));
             }
-            else{
-                // user already exis message
-                self.message = String::from("User: ") + &username.clone() + &String::from(" alredy exists, pick a new username and password");
+
+            loop {
+                // generate a random user_id
+                let user_id: u32 = rand::random::<u32>();
+                if user_id == u32::MAX {continue;}
+                // try to create an account, err when the user_id or username already exists
+                match self.database.create_user_account(username, password, user_id){
+                    Ok(()) =>{return Ok(user_id);},
+                    // only a taken user_id is tried again
+                    Err(rusqlite::Error::SqliteFailure(err, _)) if err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_PRIMARYKEY =>{continue;},
+                    Err(rusqlite::Error::SqliteFailure(err, _)) if err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE =>{
+                        return Err(AuthError::UsernameTaken(String::from(username)));
+                    },
+                    Err(err) =>{return Err(AuthError::Database(err));},
+                }
             }
         }
 
@@ -180,6 +522,10 @@ pub mod user_authentication{
             Update the index to the input box below the current box
         */
         pub fn next_index(&mut self){
+            // the code box stays selected until the code is entered or the login is cancelled
+            if self.code_pending(){
+                return;
+            }
             if self.index < self.max_index - 1{
                 self.index += 1;
             }
@@ -194,6 +540,9 @@ pub mod user_authentication{
             Update the index to the input box above the current box
         */
         pub fn previous_index(&mut self){
+            if self.code_pending(){
+                return;
+            }
             if self.index > 0{
                 self.index -= 1;
             }
@@ -217,6 +566,9 @@ pub mod user_authentication{
                 SelectedChunk::CreateAccountButton =>{
                     self.create_account();
                 },
+                SelectedChunk::CodeInput =>{
+                    self.enter_code();
+                },
                 _=> {},
             }
         }
@@ -228,16 +580,10 @@ pub mod user_authentication{
                 c: char the character the user entered
         */
         pub fn add_input(&mut self, c: char){
-            // if SelectedChunk::UsernameInput then push to username_input
-            // if SelectedChunk::PasswordInput then push to password_input
-            match self.selected_chunk{
-                SelectedChunk::UsernameInput =>{
-                    self.username_input.push(c);
-                },
-                SelectedChunk::PasswordInput =>{
-                    self.password_input.push(c);
-                },
-                _=> {},
+            // if SelectedChunk::UsernameInput then type into username_input
+            // if SelectedChunk::PasswordInput then type into password_input
+            if let Some(editor) = self.selected_input(){
+                editor.insert(c);
             }
         }
 
@@ -245,16 +591,47 @@ pub mod user_authentication{
             This method handels user input of delete/backspace
         */
         pub fn remove_input(&mut self){
-            // if SelectedChunk::UsernameInput then pop from username_input
-            // if SelectedChunk::PasswordInput then pop from password_input
+            // if SelectedChunk::UsernameInput then remove from username_input
+            // if SelectedChunk::PasswordInput then remove from password_input
+            if let Some(editor) = self.selected_input(){
+                editor.backspace();
+            }
+        }
+
+        /*
+            This method moves the cursor or edits the text of the selected input box
+
+            prams:
+                action: Action, an editing action such as CursorLeft or DeleteWord
+        */
+        pub fn edit_input(&mut self, action: Action){
+            if let Some(editor) = self.selected_input(){
+                editor.edit(action);
+            }
+        }
+
+        /*
+            This method types pasted text into the selected input box
+
+            prams:
+                text: &str, the pasted text
+        */
+        pub fn paste(&mut self, text: &str){
+            if let Some(editor) = self.selected_input(){
+                editor.paste(text);
+            }
+        }
+
+        /*
+            Returns: Option<&mut LineEditor>, the selected input box, None if a button is selected
+        */
+        fn selected_input(&mut self) -> Option<&mut LineEditor>{
             match self.selected_chunk{
-                SelectedChunk::UsernameInput =>{
-                    self.username_input.pop();
-                },
-                SelectedChunk::PasswordInput =>{
-                    self.password_input.pop();
-                },
-                _=> {},
+                SelectedChunk::UsernameInput =>{Some(&mut self.username_input)},
+                SelectedChunk::PasswordInput =>{Some(&mut self.password_input)},
+                SelectedChunk::ConfirmInput =>{Some(&mut self.confirm_input)},
+                SelectedChunk::CodeInput =>{Some(&mut self.code_input)},
+                _ =>{None},
             }
         }
 
@@ -266,9 +643,30 @@ pub mod user_authentication{
                 0 =>{self.selected_chunk = SelectedChunk::UsernameInput;},
                 1 =>{self.selected_chunk = SelectedChunk::PasswordInput;},
                 2 =>{self.selected_chunk = SelectedChunk::LoginButton;},
-                3 =>{self.selected_chunk = SelectedChunk::CreateAccountButton;},
+                3 =>{self.selected_chunk = SelectedChunk::ConfirmInput;},
+                4 =>{self.selected_chunk = SelectedChunk::CreateAccountButton;},
                 _ => {},
             };
+            // the password is only shown while it is being typed
+            if !self.password_selected(){
+                self.show_password = false;
+            }
+        }
+
+        /*
+            This method shows or hides the typed password, only while a password box is selected
+        */
+        pub fn toggle_password(&mut self){
+            if self.password_selected(){
+                self.show_password = !self.show_password;
+            }
+        }
+
+        /*
+            Returns: true if the password or confirm box is selected
+        */
+        pub fn password_selected(&self) -> bool{
+            matches!(self.selected_chunk, SelectedChunk::PasswordInput | SelectedChunk::ConfirmInput)
         }
 
         /*
@@ -284,17 +682,31 @@ pub mod user_authentication{
         }
 
         /*
-            This method enters changes the authentication_state to AuthenticationState::Default
+            This method enters changes the authentication_state to AuthenticationState::Default,
+            a login waiting for a two-factor code is cancelled
         */
         pub fn default(&mut self){
             match self.authentication_state {
                 AuthenticationState::UserInput => {
+                    self.cancel_code();
                     self.authentication_state = AuthenticationState::Default;
                 },
                 _ => {},
             };
         }
 
+        /*
+            This method gets the key bindings that are active in the current authentication_state
+
+            Returns: KeyContext
+        */
+        pub fn get_key_context(&self) -> KeyContext{
+            match self.authentication_state{
+                AuthenticationState::UserInput => KeyContext::LoginInput,
+                _ => KeyContext::Login,
+            }
+        }
+
         /*
             This method creates a user and returns a option
 
@@ -0,0 +1,138 @@
+use todo_list_rust::account::account::{AccountForm, AccountField, change_username, change_password, delete_account, load_two_factor, update_two_factor};
+use todo_list_rust::two_factor::two_factor::{code_at, RECOVERY_CODES};
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::user::user::User;
+use todo_list_rust::user_authentication::user_authentication::AuthError;
+
+fn new_user(database: &TodoDatabase, user_id: u32, username: &str) -> User{
+    database.create_user_account(username, "secret", user_id).unwrap();
+    User::new(user_id, String::from(username), String::from("secret"))
+}
+
+#[test]
+fn test_change_username(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut alice = new_user(&database, 1, "alice");
+    new_user(&database, 2, "bob");
+
+    assert!(matches!(change_username(&database, &mut alice, ""), Err(AuthError::EmptyInput)));
+    assert!(matches!(change_username(&database, &mut alice, "bob"), Err(AuthError::UsernameTaken(name)) if name == "bob"));
+    assert_eq!("alice", alice.get_username());
+
+    change_username(&database, &mut alice, "carol").unwrap();
+    assert_eq!("carol", alice.get_username());
+    assert_eq!(1, database.get_user_by_name("carol").unwrap().unwrap().user_id);
+    assert!(database.get_user_by_name("alice").unwrap().is_none());
+}
+
+#[test]
+fn test_change_password(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut alice = new_user(&database, 1, "alice");
+
+    assert!(matches!(change_password(&database, &mut alice, "wrong", "new", "new"), Err(AuthError::WrongPassword(_))));
+    assert!(matches!(change_password(&database, &mut alice, "secret", "new", "nwe"), Err(AuthError::PasswordMismatch)));
+    assert!(matches!(change_password(&database, &mut alice, "secret", "", ""), Err(AuthError::EmptyInput)));
+    assert_eq!("secret", database.get_user_by_name("alice").unwrap().unwrap().password);
+
+    change_password(&database, &mut alice, "secret", "new", "new").unwrap();
+    assert_eq!("new", alice.get_password());
+    assert_eq!("new", database.get_user_by_name("alice").unwrap().unwrap().password);
+    // the old password no longer works
+    assert!(matches!(change_password(&database, &mut alice, "secret", "other", "other"), Err(AuthError::WrongPassword(_))));
+}
+
+#[test]
+fn test_delete_account(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let alice = new_user(&database, 1, "alice");
+    let bob = new_user(&database, 2, "bob");
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    database.insert_into_list(String::from("Groceries"), 20, 2).unwrap();
+    database.update_preferences(1, Some(10), Some(100)).unwrap();
+
+    assert!(matches!(delete_account(&database, &alice, "bob"), Err(AuthError::DeleteNotConfirmed(name)) if name == "alice"));
+    assert!(database.get_user_by_name("alice").unwrap().is_some());
+
+    delete_account(&database, &alice, "alice").unwrap();
+    assert!(database.get_user_by_name("alice").unwrap().is_none());
+    assert!(database.load_user_data(1).is_empty());
+    assert!(database.get_preferences(1).unwrap().is_none());
+    // other users keep their lists
+    assert_eq!(1, database.load_user_data(bob.get_user_id()).len());
+}
+
+#[test]
+fn test_account_form(){
+    let mut form = AccountForm::new();
+    assert_eq!(AccountField::Username, form.selected_field());
+
+    // passwords are only shown while a password box is selected
+    form.toggle_password();
+    assert!(!form.show_password);
+    form.next_field();
+    form.toggle_password();
+    assert!(form.show_password);
+    form.next_field();
+    form.next_field();
+    assert_eq!(AccountField::ConfirmPassword, form.selected_field());
+    assert!(form.show_password);
+    form.next_field();
+    assert_eq!(AccountField::ForgetSessions, form.selected_field());
+    assert!(!form.show_password);
+    // a button has no input box
+    assert!(form.selected_input().is_none());
+    form.next_field();
+    assert_eq!(AccountField::TwoFactor, form.selected_field());
+    form.next_field();
+    assert_eq!(AccountField::DeleteConfirm, form.selected_field());
+
+    // wraps around
+    form.next_field();
+    assert_eq!(AccountField::Username, form.selected_field());
+    form.previous_field();
+    assert_eq!(AccountField::DeleteConfirm, form.selected_field());
+
+    form.selected_input().unwrap().insert('a');
+    assert_eq!("a", form.input(AccountField::DeleteConfirm).unwrap().get_text());
+    form.clear();
+    assert!(form.input(AccountField::DeleteConfirm).unwrap().is_empty());
+    assert_eq!(AccountField::Username, form.selected_field());
+}
+
+#[test]
+fn test_update_two_factor(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let alice = new_user(&database, 1, "alice");
+    let mut form = AccountForm::new();
+    let time: u64 = 1234567890;
+
+    // the first enter shows a new secret
+    update_two_factor(&database, &alice, &mut form, "", time).unwrap();
+    let secret = form.totp_secret.clone().unwrap();
+    assert!(form.totp_uri.contains(&secret));
+    assert!(!form.two_factor);
+
+    // a wrong code keeps the secret waiting
+    assert!(matches!(update_two_factor(&database, &alice, &mut form, "000000", time), Err(AuthError::TwoFactor(_))));
+    assert_eq!(Some(secret.clone()), form.totp_secret);
+
+    update_two_factor(&database, &alice, &mut form, &code_at(&secret, time).unwrap(), time).unwrap();
+    assert!(form.two_factor);
+    assert!(form.totp_secret.is_none());
+    assert_eq!(RECOVERY_CODES, form.recovery_codes.len());
+
+    let mut loaded = AccountForm::new();
+    load_two_factor(&database, &alice, &mut loaded).unwrap();
+    assert!(loaded.two_factor);
+    assert_eq!(RECOVERY_CODES as u32, loaded.recovery_left);
+    // the codes are only shown once
+    assert!(loaded.recovery_codes.is_empty());
+
+    // a recovery code turns it off
+    let code = form.recovery_codes[0].clone();
+    update_two_factor(&database, &alice, &mut form, &code, time).unwrap();
+    assert!(!form.two_factor);
+    assert!(database.get_two_factor(1).unwrap().is_none());
+}
@@ -0,0 +1,57 @@
+use todo_list_rust::agenda::agenda::{AgendaGroup, build_agenda, month_weeks, add_months};
+use todo_list_rust::todo::todo::TodoList;
+use chrono::NaiveDate;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate{
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn test_agenda_groups(){
+    let today = date(2026, 10, 19);
+    assert_eq!(AgendaGroup::Overdue, AgendaGroup::from_due(date(2026, 10, 18), today));
+    assert_eq!(AgendaGroup::Today, AgendaGroup::from_due(today, today));
+    assert_eq!(AgendaGroup::Tomorrow, AgendaGroup::from_due(date(2026, 10, 20), today));
+    assert_eq!(AgendaGroup::ThisWeek, AgendaGroup::from_due(date(2026, 10, 25), today));
+    assert_eq!(AgendaGroup::Later, AgendaGroup::from_due(date(2026, 10, 26), today));
+}
+
+#[test]
+fn test_build_agenda(){
+    let today = date(2026, 10, 19);
+    let mut work = TodoList::new("work".to_string(), 1);
+    work.add("later".to_string(), 10);
+    work.add("no due".to_string(), 11);
+    work.add("done".to_string(), 12);
+    work.list[0].set_due(Some(date(2026, 12, 1)));
+    work.list[2].set_due(Some(today));
+    work.set_item_complete(2);
+    let mut home = TodoList::new("home".to_string(), 2);
+    home.add("late".to_string(), 20);
+    home.list[0].set_due(Some(date(2026, 10, 1)));
+
+    // only open items with a due date, soonest first
+    let agenda = build_agenda(&[work, home], today);
+    assert_eq!(2, agenda.len());
+    assert_eq!("late", agenda[0].item_name);
+    assert_eq!("home", agenda[0].list_name);
+    assert_eq!((1, 0), (agenda[0].list_index, agenda[0].item_index));
+    assert_eq!(AgendaGroup::Overdue, agenda[0].group);
+    assert_eq!("later", agenda[1].item_name);
+    assert_eq!(AgendaGroup::Later, agenda[1].group);
+}
+
+#[test]
+fn test_calendar(){
+    // october 2026 starts on a thursday and ends on a saturday
+    let weeks = month_weeks(date(2026, 10, 19));
+    assert_eq!(5, weeks.len());
+    assert_eq!([None, None, None, Some(date(2026, 10, 1)), Some(date(2026, 10, 2)), Some(date(2026, 10, 3)), Some(date(2026, 10, 4))], weeks[0]);
+    assert_eq!(Some(date(2026, 10, 31)), weeks[4][5]);
+    assert_eq!(None, weeks[4][6]);
+
+    // months keep the day when it exists
+    assert_eq!(date(2026, 11, 19), add_months(date(2026, 10, 19), 1));
+    assert_eq!(date(2026, 2, 28), add_months(date(2026, 1, 31), 1));
+    assert_eq!(date(2025, 12, 31), add_months(date(2026, 1, 31), -1));
+}
@@ -0,0 +1,426 @@
+use todo_list_rust::app::app::{run_user_authentication, run_app, run_sessions};
+use todo_list_rust::app_state::app_state::State;
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::event_source::event_source::{ReplayEvents, ScriptError};
+use todo_list_rust::keymap::keymap::{Keymap, Preset};
+use todo_list_rust::theme::theme::Theme;
+use todo_list_rust::two_factor::two_factor;
+use todo_list_rust::user::user::User;
+use todo_list_rust::user_authentication::user_authentication::Authentication;
+use std::{env, fs, path::Path};
+use tui::{backend::TestBackend, Terminal};
+
+// run with UPDATE_SNAPSHOTS=1 to write the snapshots again
+const SNAPSHOT_DIR: &str = "tests/snapshots";
+
+/*
+    Creates an empty database, each test uses its own file so tests can run at once
+*/
+fn new_database(name: &str) -> TodoDatabase{
+    let file_path = format!("database/test_app_{}.db", name);
+    let _ = fs::remove_file(&file_path);
+    TodoDatabase::new(file_path)
+}
+
+fn remove_database(name: &str){
+    let _ = fs::remove_file(format!("database/test_app_{}.db", name));
+}
+
+fn new_terminal() -> Terminal<TestBackend>{
+    Terminal::new(TestBackend::new(80, 30)).unwrap()
+}
+
+/*
+    Returns: the text of the last drawn screen, one line per row
+*/
+fn screen(terminal: &Terminal<TestBackend>) -> String{
+    let buffer = terminal.backend().buffer();
+    let mut lines: Vec<String> = Vec::new();
+    for y in 0..buffer.area.height{
+        let line: String = (0..buffer.area.width).map(|x| buffer.get(x, y).symbol.clone()).collect();
+        lines.push(String::from(line.trim_end()));
+    }
+    lines.join("\n") + "\n"
+}
+
+/*
+    Compares a screen to tests/snapshots/<name>.txt
+*/
+fn assert_snapshot(name: &str, screen: &str){
+    let file_path = format!("{}/{}.txt", SNAPSHOT_DIR, name);
+    if env::var("UPDATE_SNAPSHOTS").is_ok(){
+        fs::create_dir_all(SNAPSHOT_DIR).unwrap();
+        fs::write(&file_path, screen).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&file_path)
+        .unwrap_or_else(|_| panic!("missing snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it", file_path));
+    assert_eq!(expected, screen, "screen does not match snapshot {}", file_path);
+}
+
+/*
+    Runs the todo app for a new user with a script of keys
+*/
+fn run_script(database: TodoDatabase, script: &str) -> (State, Terminal<TestBackend>){
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let mut state = State::new(User::new(1, String::from("alice"), String::from("secret")), database);
+    let mut terminal = new_terminal();
+    let mut events = ReplayEvents::from_script(script).unwrap();
+    run_app(&mut terminal, &mut state, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    (state, terminal)
+}
+
+// adds two lists and two items to the selected first list, fixing typos on the way
+const ADD_SCRIPT: &str = "
+1
+type Grocries
+Left Left Left Left
+type e
+End
+Enter
+type Chores x
+Ctrl-w Backspace
+Enter
+Tab
+type sweep p:2
+Enter
+type dishes due:2030-01-31
+Enter
+Esc
+";
+
+#[test]
+fn test_replay_script(){
+    let events = ReplayEvents::from_script("# comment\n\n1\ntype ab c\nDown Down Enter\nd d").unwrap();
+    assert_eq!(1 + 4 + 3 + 2, events.remaining());
+
+    match ReplayEvents::from_script("1\nNope") {
+        Err(ScriptError::UnknownKey{line, keys}) => {
+            assert_eq!(2, line);
+            assert_eq!("Nope", keys);
+        },
+        _ => {panic!("expected an unknown key")},
+    }
+    assert!(matches!(ReplayEvents::from_file("tests/no_such_script.txt"), Err(ScriptError::Read(_))));
+}
+
+#[test]
+fn test_login_screen(){
+    let database = new_database("login");
+    let authen = Authentication::new(&database);
+    let mut terminal = new_terminal();
+
+    // create an account then log in with it
+    let mut events = ReplayEvents::from_script("
+1
+type alice
+Down
+type secret
+Down Down
+type secret
+Down
+Enter
+Up Up Up Up
+type alice
+Down
+type secret
+Down
+Enter
+").unwrap();
+    let user = run_user_authentication(&mut terminal, authen, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+
+    assert_eq!("alice", user.unwrap().get_username());
+    assert_snapshot("login", &screen(&terminal));
+    remove_database("login");
+}
+
+#[test]
+fn test_login_two_factor_screen(){
+    let database = new_database("two_factor");
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let codes = two_factor::enable(&database, 1, "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ", "005924", 1234567890).unwrap();
+    let mut terminal = new_terminal();
+
+    // the password asks for a code, Down stays in the code box, a recovery code logs in
+    let script = format!("1\ntype alice\nDown\ntype secret\nDown\nEnter\nDown\ntype {}\nEnter\n", codes[0]);
+    let mut events = ReplayEvents::from_script(&script).unwrap();
+    let user = run_user_authentication(&mut terminal, Authentication::new(&database), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+
+    assert_eq!("alice", user.unwrap().get_username());
+    assert_eq!(two_factor::RECOVERY_CODES as u32 - 1, database.count_recovery_codes(1).unwrap());
+    remove_database("two_factor");
+}
+
+#[test]
+fn test_password_masked(){
+    let database = new_database("masked");
+    let mut terminal = new_terminal();
+
+    // the password is masked until revealed, the confirm box must match to create an account
+    let mut events = ReplayEvents::from_script("
+1
+type bob
+Down
+type hunter2
+Down Down
+type hunter3
+Down
+Enter
+").unwrap();
+    let user = run_user_authentication(&mut terminal, Authentication::new(&database), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(user.is_none());
+    assert!(screen(&terminal).contains("Passwords do not match"));
+    assert!(database.get_user_id("bob", "hunter2").unwrap().is_empty());
+
+    let mut events = ReplayEvents::from_script("
+1
+Down
+type hunter2
+").unwrap();
+    run_user_authentication(&mut terminal, Authentication::new(&database), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(screen(&terminal).contains("*******"));
+    assert!(!screen(&terminal).contains("hunter2"));
+
+    let mut events = ReplayEvents::from_script("
+1
+Down
+type hunter2
+Ctrl-r
+").unwrap();
+    run_user_authentication(&mut terminal, Authentication::new(&database), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(screen(&terminal).contains("hunter2"));
+    remove_database("masked");
+}
+
+#[test]
+fn test_add_lists_screen(){
+    let (state, terminal) = run_script(new_database("add"), ADD_SCRIPT);
+
+    assert_eq!(2, state.todo_lists.len());
+    assert_eq!(2, state.todo_lists[0].get_list_len());
+    assert_snapshot("add_lists", &screen(&terminal));
+    remove_database("add");
+}
+
+#[test]
+fn test_navigate_screen(){
+    // select the second item of the first list and cross it off
+    let script = format!("{}\n2\nRight\nDown\nEnter\n", ADD_SCRIPT);
+    let (state, terminal) = run_script(new_database("navigate"), &script);
+
+    assert!(state.todo_lists[0].get_item_complete_status(1));
+    assert_snapshot("navigate", &screen(&terminal));
+    remove_database("navigate");
+}
+
+#[test]
+fn test_delete_screen(){
+    // delete the first item of the first list, then the second list
+    let script = format!("{}\n2\nRight\nDelete\nLeft\nDown\nDelete\n", ADD_SCRIPT);
+    let (state, terminal) = run_script(new_database("delete"), &script);
+
+    assert_eq!(1, state.todo_lists.len());
+    assert_eq!("Groceries", state.todo_lists[0].get_name());
+    assert_eq!(1, state.todo_lists[0].get_list_len());
+    // the database has the same lists
+    assert_eq!(1, state.database.load_user_data(1).len());
+    assert_snapshot("delete", &screen(&terminal));
+    remove_database("delete");
+}
+
+#[test]
+fn test_selection_restored(){
+    // select the second item of the first list, quit, then log in again
+    let script = format!("{}\n2\nRight\nDown\nq\n", ADD_SCRIPT);
+    let (state, _terminal) = run_script(new_database("restore"), &script);
+    let item_id = state.todo_lists[0].get_item_id(1);
+
+    let state = State::new(User::new(1, String::from("alice"), String::from("secret")), state.database);
+    assert_eq!(Some(0), state.get_list_index());
+    assert_eq!(Some(1), state.selected_item());
+    assert_eq!(Some(item_id), state.selected_item().map(|index| state.todo_lists[0].get_item_id(index)));
+    remove_database("restore");
+}
+
+#[test]
+fn test_account_screen(){
+    // change the username, then the password
+    let script = "6\ntype bob\nEnter\nDown\ntype secret\nDown\ntype hunter2\nDown\ntype hunter2\nEnter\n";
+    let (state, terminal) = run_script(new_database("account"), script);
+
+    assert_eq!("bob", state.get_username());
+    assert_eq!("hunter2", state.database.get_user_by_name("bob").unwrap().unwrap().password);
+    assert_eq!("Password changed", state.account.message);
+    assert_snapshot("account", &screen(&terminal));
+    remove_database("account");
+}
+
+#[test]
+fn test_account_two_factor(){
+    // enter in the two-factor box shows a new secret
+    let script = "6\nUp\nUp\nEnter\n";
+    let (state, terminal) = run_script(new_database("account_two_factor"), script);
+
+    let secret = state.account.totp_secret.clone().unwrap();
+    assert!(screen(&terminal).contains(&secret[..20]));
+    assert!(state.account.message.starts_with("Add the secret to your authenticator app"));
+    remove_database("account_two_factor");
+}
+
+#[test]
+fn test_delete_account_screen(){
+    // the delete box is above the username box, the user is logged out once the account is deleted
+    let script = format!("{}\n2\nDown\nEsc\n6\nUp\ntype alice\nEnter\n1\ntype after\nEnter\n", ADD_SCRIPT);
+    let (state, _terminal) = run_script(new_database("delete_account"), &script);
+
+    assert!(state.logged_out());
+    assert!(state.todo_lists.is_empty());
+    assert!(state.database.get_user_by_name("alice").unwrap().is_none());
+    assert!(state.database.load_user_data(1).is_empty());
+    assert!(state.database.get_preferences(1).unwrap().is_none());
+    remove_database("delete_account");
+}
+
+#[test]
+fn test_logout_switch_user(){
+    let database = new_database("switch");
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.create_user_account("bob", "hunter2", 2).unwrap();
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    let mut terminal = new_terminal();
+
+    // log in as alice, log out, then log in as bob without restarting
+    let mut events = ReplayEvents::from_script("
+1
+type alice
+Down
+type secret
+Down
+Enter
+Enter
+L
+1
+type bob
+Down
+type hunter2
+Down
+Enter
+Enter
+").unwrap();
+    let database = run_sessions(&mut terminal, database, None, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+
+    // bob sees none of alice's lists
+    let screen = screen(&terminal);
+    assert!(screen.contains("bob"));
+    assert!(!screen.contains("Chores"));
+    // alice's selection was saved when she logged out
+    assert_eq!(Some(10), database.get_preferences(1).unwrap().unwrap().list_id);
+    remove_database("switch");
+}
+
+#[test]
+fn test_remembered_login(){
+    let database = new_database("remember");
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let session_file = Path::new("database/test_app_remember_session");
+    let _ = fs::remove_file(session_file);
+    let mut terminal = new_terminal();
+
+    // log in with remember me on
+    let mut events = ReplayEvents::from_script("
+1
+type alice
+Down
+type secret
+Ctrl-s
+Down
+Enter
+Enter
+q
+").unwrap();
+    let database = run_sessions(&mut terminal, database, Some(session_file), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(session_file.exists());
+
+    // the next launch skips the login page
+    let mut events = ReplayEvents::from_script("6").unwrap();
+    let database = run_sessions(&mut terminal, database, Some(session_file), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(screen(&terminal).contains("alice"));
+    assert!(screen(&terminal).contains("Forget 1 remembered logins"));
+
+    // logging out forgets the login
+    let mut events = ReplayEvents::from_script("L").unwrap();
+    let database = run_sessions(&mut terminal, database, Some(session_file), &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(!session_file.exists());
+    assert_eq!(0, database.count_sessions(1, chrono::Local::now().naive_local()).unwrap());
+    remove_database("remember");
+}
+
+#[test]
+fn test_shared_list_screen(){
+    // alice shares the selected list read only with bob
+    let database = new_database("shared");
+    database.create_user_account("bob", "hunter2", 2).unwrap();
+    let script = format!("{}\n2\nS\ntype bob\nEnter\n", ADD_SCRIPT);
+    let (state, terminal) = run_script(database, &script);
+    assert_eq!("Shared Groceries with bob, read only", state.message);
+    assert_snapshot("share", &screen(&terminal));
+
+    // bob cannot add, delete or cross off items
+    let mut state = State::new(User::new(2, String::from("bob"), String::from("hunter2")), state.database);
+    let mut terminal = new_terminal();
+    let mut events = ReplayEvents::from_script("1\nTab\ntype milk\nEnter\nEsc\n2\nRight\nDelete\nEnter\n").unwrap();
+    run_app(&mut terminal, &mut state, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+
+    assert_eq!(1, state.todo_lists.len());
+    assert_eq!(2, state.todo_lists[0].get_list_len());
+    assert!(!state.todo_lists[0].get_item_complete_status(0));
+    assert_eq!("Groceries is shared read only by alice", state.message);
+    // items load in database order, so the screen is not a snapshot
+    assert!(screen(&terminal).contains("0: Groceries [alice, read only]"));
+
+    // deleting the list only removes it for bob
+    let mut events = ReplayEvents::from_script("Left\nDelete\n").unwrap();
+    run_app(&mut terminal, &mut state, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert!(state.todo_lists.is_empty());
+    assert!(state.database.load_user_data(2).is_empty());
+    assert_eq!(2, state.database.load_user_data(1).len());
+    remove_database("shared");
+}
+
+#[test]
+fn test_list_named_share(){
+    // the list input only adds lists, even with a name like a share
+    let (state, _terminal) = run_script(new_database("share_name"), "1\ntype share:bob\nEnter\n");
+    assert_eq!(1, state.todo_lists.len());
+    assert_eq!("share:bob", state.todo_lists[0].get_name());
+    remove_database("share_name");
+}
+
+#[test]
+fn test_assigned_items_screen(){
+    // alice shares the selected list read-write with bob and assigns its first item to him
+    let database = new_database("assigned");
+    database.create_user_account("bob", "hunter2", 2).unwrap();
+    let script = format!("{}\n2\nS\ntype bob\nTab\nEnter\nEsc\nRight\na\na\n", ADD_SCRIPT);
+    let (state, terminal) = run_script(database, &script);
+    let item_name = state.todo_lists[0].list[state.selected_item().unwrap()].get_item_name();
+    assert_eq!(format!("Assigned {} to bob", item_name), state.message);
+    assert!(screen(&terminal).contains(&format!("0: {}", item_name)));
+    assert!(screen(&terminal).contains("@BO"));
+
+    // bob finds the item in his assigned items and jumps to it
+    let mut state = State::new(User::new(2, String::from("bob"), String::from("hunter2")), state.database);
+    let mut terminal = new_terminal();
+    let mut events = ReplayEvents::from_script("7\n").unwrap();
+    run_app(&mut terminal, &mut state, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    assert_eq!(1, state.assigned.len());
+    assert_eq!(item_name, state.assigned[0].item_name);
+    assert!(screen(&terminal).contains(&format!("Groceries: {}", item_name)));
+    assert_snapshot("assigned", &screen(&terminal));
+
+    let mut events = ReplayEvents::from_script("Enter\n").unwrap();
+    run_app(&mut terminal, &mut state, &mut events, &mut Keymap::new(Preset::Default), &Theme::light()).unwrap();
+    let todo_list = state.current_list().unwrap();
+    assert_eq!(item_name, todo_list.list[state.selected_item().unwrap()].get_item_name());
+    remove_database("assigned");
+}
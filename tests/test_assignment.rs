@@ -0,0 +1,79 @@
+use todo_list_rust::assignment::assignment::{build_assigned, initials, next_assignee};
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::sharing::sharing::ListMember;
+use todo_list_rust::todo::todo::TodoList;
+use todo_list_rust::todo_item::todo_item::TodoItem;
+
+fn member(user_id: u32, username: &str) -> ListMember{
+    ListMember{user_id, username: String::from(username)}
+}
+
+#[test]
+fn test_initials(){
+    assert_eq!("BO", initials("bob"));
+    assert_eq!("MJ", initials("mary_jane"));
+    assert_eq!("AS", initials("Ann Smith Jones"));
+    assert_eq!("A", initials("a"));
+    assert_eq!("?", initials("__"));
+}
+
+#[test]
+fn test_next_assignee(){
+    let members = vec![member(1, "alice"), member(2, "bob")];
+    assert_eq!(Some(&members[0]), next_assignee(&members, None));
+    assert_eq!(Some(&members[1]), next_assignee(&members, Some(1)));
+    assert_eq!(None, next_assignee(&members, Some(2)));
+    // a user that lost access is unassigned
+    assert_eq!(None, next_assignee(&members, Some(3)));
+    assert_eq!(None, next_assignee(&[], None));
+}
+
+#[test]
+fn test_build_assigned(){
+    let mut chores = TodoList::new(String::from("Chores"), 10);
+    let mut groceries = TodoList::new(String::from("Groceries"), 20);
+    for (todo_list, names) in [(&mut chores, ["sweep", "dishes"]), (&mut groceries, ["milk", "eggs"])]{
+        for (i, name) in names.iter().enumerate(){
+            let mut item = TodoItem::new(String::from(*name), i as u32);
+            item.set_assignee(Some(if i == 0 {1} else {2}));
+            todo_list.list.push(item);
+        }
+    }
+    chores.set_item_complete(0);
+
+    let entries = build_assigned(&[chores, groceries], 1);
+    let names: Vec<&str> = entries.iter().map(|entry| entry.item_name.as_str()).collect();
+    // open items first
+    assert_eq!(vec!["milk", "sweep"], names);
+    assert_eq!((1, 0), (entries[0].list_index, entries[0].item_index));
+    assert!(entries[1].complete);
+    assert!(build_assigned(&[], 1).is_empty());
+}
+
+#[test]
+fn test_item_assignee_database(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.create_user_account("bob", "secret", 2).unwrap();
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    database.share_list(10, 2, true).unwrap();
+
+    // the owner first
+    assert_eq!(vec![member(1, "alice"), member(2, "bob")], database.get_list_members(10).unwrap());
+
+    database.update_item_assignee(100, 10, Some(2)).unwrap();
+    let lists = database.load_user_data(1);
+    assert_eq!(Some(2), lists[0].list[0].get_assignee());
+    assert_eq!(Some("bob"), lists[0].get_member_name(2));
+    assert_eq!(Some(2), database.load_user_data(2)[0].list[0].get_assignee());
+
+    // bob loses access, the item is unassigned
+    database.unshare_list(10, 2).unwrap();
+    assert_eq!(None, database.load_user_data(1)[0].list[0].get_assignee());
+    assert_eq!(vec![member(1, "alice")], database.get_list_members(10).unwrap());
+
+    database.update_item_assignee(100, 10, Some(1)).unwrap();
+    database.update_item_assignee(100, 10, None).unwrap();
+    assert_eq!(None, database.load_user_data(1)[0].list[0].get_assignee());
+}
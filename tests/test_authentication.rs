@@ -0,0 +1,196 @@
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::user_authentication::user_authentication::{Authentication, AuthError, SelectedChunk, throttle_delay, format_wait};
+use todo_list_rust::two_factor::two_factor::{self, TwoFactorError};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+fn time(minute: u32, second: u32) -> NaiveDateTime{
+    NaiveDate::from_ymd_opt(2026, 10, 19).unwrap().and_hms_opt(12, minute, second).unwrap()
+}
+
+#[test]
+fn test_register(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let authen = Authentication::new(&database);
+
+    let user_id = authen.register("alice", "secret", "secret").unwrap();
+    assert_eq!(user_id, database.get_user_by_name("alice").unwrap().unwrap().user_id);
+
+    assert!(matches!(authen.register("", "secret", "secret"), Err(AuthError::EmptyInput)));
+    assert!(matches!(authen.register("bob", "secret", "secrte"), Err(AuthError::PasswordMismatch)));
+    // taken with any password
+    assert!(matches!(authen.register("alice", "other", "other"), Err(AuthError::UsernameTaken(name)) if name == "alice"));
+}
+
+#[test]
+fn test_check_login(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut authen = Authentication::new(&database);
+    authen.register("alice", "secret", "secret").unwrap();
+
+    assert_eq!("alice", authen.check_login("alice", "secret", time(0, 0)).unwrap().username);
+    assert!(matches!(authen.check_login("alice", "wrong", time(0, 0)), Err(AuthError::WrongPassword(_))));
+    assert!(matches!(authen.check_login("bob", "secret", time(0, 0)), Err(AuthError::UnknownUser(_))));
+    assert!(matches!(authen.check_login("alice", "", time(0, 0)), Err(AuthError::EmptyInput)));
+}
+
+#[test]
+fn test_login_messages(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut authen = Authentication::new(&database);
+    authen.register("alice", "secret", "secret").unwrap();
+
+    authen.username_input = "alice".into();
+    authen.password_input = "wrong".into();
+    authen.login();
+    assert_eq!("Wrong password for User: alice", authen.message);
+    assert!(authen.get_user().is_none());
+
+    authen.username_input = "alice".into();
+    authen.password_input = "secret".into();
+    authen.confirm_input = "secret".into();
+    authen.create_account();
+    assert_eq!("Username alice is taken, pick a new username", authen.message);
+
+    authen.username_input = "alice".into();
+    authen.password_input = "secret".into();
+    authen.login();
+    assert_eq!("alice", authen.get_user().unwrap().get_username());
+}
+
+#[test]
+fn test_constraint_codes(){
+    // register tells a taken user_id from a taken username by these codes
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    match database.create_user_account("bob", "secret", 1){
+        Err(rusqlite::Error::SqliteFailure(err, _)) => {assert_eq!(rusqlite::ffi::SQLITE_CONSTRAINT_PRIMARYKEY, err.extended_code)},
+        _ => {panic!("expected a primary key error")},
+    }
+    match database.create_user_account("alice", "secret", 2){
+        Err(rusqlite::Error::SqliteFailure(err, _)) => {assert_eq!(rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE, err.extended_code)},
+        _ => {panic!("expected a unique error")},
+    }
+}
+
+#[test]
+fn test_throttle_delay(){
+    assert_eq!(None, throttle_delay(2));
+    assert_eq!(Some(Duration::seconds(2)), throttle_delay(3));
+    assert_eq!(Some(Duration::seconds(4)), throttle_delay(4));
+    assert_eq!(Some(Duration::minutes(15)), throttle_delay(5));
+    assert_eq!(Some(Duration::minutes(15)), throttle_delay(9));
+    assert_eq!("42s", format_wait(42));
+    assert_eq!("14m 5s", format_wait(845));
+}
+
+#[test]
+fn test_login_throttled(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut authen = Authentication::new(&database);
+    authen.register("alice", "secret", "secret").unwrap();
+
+    // two failures do not wait, the third waits 2 seconds
+    for _ in 0..3{
+        assert!(matches!(authen.check_login("alice", "wrong", time(0, 0)), Err(AuthError::WrongPassword(_))));
+    }
+    assert!(matches!(authen.check_login("alice", "secret", time(0, 1)), Err(AuthError::Locked(_, 1))));
+    assert_eq!(Some(String::from("Login for User: alice locked for 1s")), authen.lockout_message(time(0, 1)));
+
+    // the fourth waits 4 seconds, the fifth locks the username
+    assert!(matches!(authen.check_login("alice", "wrong", time(0, 2)), Err(AuthError::WrongPassword(_))));
+    assert!(matches!(authen.check_login("alice", "wrong", time(0, 6)), Err(AuthError::WrongPassword(_))));
+    assert!(matches!(authen.check_login("alice", "secret", time(10, 0)), Err(AuthError::Locked(_, 306))));
+    assert_eq!(5, database.get_login_attempts("alice").unwrap().unwrap().failures);
+
+    // after the lockout a good login clears the failures
+    assert!(authen.check_login("alice", "secret", time(15, 6)).is_ok());
+    assert!(database.get_login_attempts("alice").unwrap().is_none());
+    assert_eq!(None, authen.lockout_message(time(15, 6)));
+
+    // every login is audited
+    let audit = database.get_auth_audit("alice").unwrap();
+    assert_eq!(8, audit.len());
+    assert_eq!(1, audit[7].success);
+    assert_eq!("Wrong password for User: alice", audit[0].reason);
+    assert_eq!("2026-10-19 12:15:06", audit[7].time);
+
+    // unknown usernames are throttled the same
+    for _ in 0..5{
+        let _ = authen.check_login("mallory", "guess", time(20, 0));
+    }
+    assert!(matches!(authen.check_login("mallory", "guess", time(20, 0)), Err(AuthError::Locked(..))));
+}
+
+// the RFC 6238 test secret "12345678901234567890" as base32
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+fn unix_time(seconds: i64) -> DateTime<Local>{
+    Local.timestamp_opt(seconds, 0).unwrap()
+}
+
+#[test]
+fn test_login_two_factor(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut authen = Authentication::new(&database);
+    let user_id = authen.register("alice", "secret", "secret").unwrap();
+    let codes = two_factor::enable(&database, user_id, RFC_SECRET, "005924", 1234567890).unwrap();
+
+    // the password alone does not log in
+    authen.username_input = "alice".into();
+    authen.password_input = "secret".into();
+    authen.login();
+    assert!(authen.code_pending());
+    assert!(matches!(authen.selected_chunk, SelectedChunk::CodeInput));
+    assert!(authen.get_user().is_none());
+    assert_eq!("password correct, code required", database.get_auth_audit("alice").unwrap()[0].reason);
+
+    // the code box stays selected
+    authen.next_index();
+    assert!(matches!(authen.selected_chunk, SelectedChunk::CodeInput));
+
+    let now = unix_time(1234567920);
+    assert!(matches!(authen.check_code("000000", now), Err(AuthError::TwoFactor(TwoFactorError::WrongCode))));
+    assert!(authen.code_pending());
+    let code = two_factor::code_at(RFC_SECRET, 1234567920).unwrap();
+    assert_eq!("alice", authen.check_code(&code, now).unwrap().username);
+    assert!(!authen.code_pending());
+    assert!(database.get_login_attempts("alice").unwrap().is_none());
+
+    // a recovery code finishes the login
+    authen.username_input = "alice".into();
+    authen.password_input = "secret".into();
+    authen.login();
+    authen.code_input = codes[0].as_str().into();
+    authen.process_enter();
+    assert_eq!("alice", authen.get_user().unwrap().get_username());
+}
+
+#[test]
+fn test_login_two_factor_cancel_and_lockout(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut authen = Authentication::new(&database);
+    let user_id = authen.register("alice", "secret", "secret").unwrap();
+    two_factor::enable(&database, user_id, RFC_SECRET, "005924", 1234567890).unwrap();
+
+    authen.user_input();
+    authen.username_input = "alice".into();
+    authen.password_input = "secret".into();
+    authen.login();
+    authen.default();
+    assert!(!authen.code_pending());
+    assert!(matches!(authen.selected_chunk, SelectedChunk::UsernameInput));
+    assert!(matches!(authen.check_code("005924", unix_time(1234567890)), Err(AuthError::TwoFactor(TwoFactorError::NotEnabled))));
+
+    // wrong codes count as failed logins, the lockout cancels the login
+    authen.username_input = "alice".into();
+    authen.password_input = "secret".into();
+    authen.login();
+    let now = unix_time(1234567890);
+    for _ in 0..3{
+        assert!(matches!(authen.check_code("000000", now), Err(AuthError::TwoFactor(TwoFactorError::WrongCode))));
+    }
+    assert!(authen.code_pending());
+    assert_eq!(3, database.get_login_attempts("alice").unwrap().unwrap().failures);
+    assert!(matches!(authen.check_code("005924", now), Err(AuthError::Locked(_, 2))));
+    assert!(!authen.code_pending());
+}
@@ -0,0 +1,231 @@
+use todo_list_rust::backup::backup::{backup_name, backup_time, list_backups, create_backup, create_backup_if_due, rotate, find_backup, restore, restore_user, BackupError, DAILY_BACKUPS};
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::encryption::encryption::{is_encrypted, open_database};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use std::fs;
+use std::path::Path;
+
+fn time(minute: u32) -> NaiveDateTime{
+    NaiveDate::from_ymd_opt(2026, 10, 19).unwrap().and_hms_opt(12, minute, 0).unwrap()
+}
+
+fn day_time(day: u32, hour: u32) -> NaiveDateTime{
+    NaiveDate::from_ymd_opt(2026, 10, day).unwrap().and_hms_opt(hour, 0, 0).unwrap()
+}
+
+fn new_dir(name: &str) -> &Path{
+    let dir = Path::new(name);
+    let _ = fs::remove_dir_all(dir);
+    dir
+}
+
+fn list_names(database: &TodoDatabase, user_id: u32) -> Vec<String>{
+    let mut names: Vec<String> = database.load_user_data(user_id).iter().map(|list| list.get_name()).collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_backup_names(){
+    assert_eq!("data-20261019-120500.db", backup_name(time(5)));
+    assert_eq!(Some(time(5)), backup_time(Path::new("database/backups/data-20261019-120500.db")));
+    assert_eq!(None, backup_time(Path::new("database/backups/data-20261019-120500.txt")));
+    assert_eq!(None, backup_time(Path::new("database/backups/notes.db")));
+}
+
+#[test]
+fn test_create_and_rotate(){
+    let dir = new_dir("database/test_backups_rotate");
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+
+    assert!(list_backups(dir).unwrap().is_empty());
+    for minute in [1, 3, 2]{
+        create_backup(&database, dir, 2, time(minute)).unwrap();
+    }
+    fs::write(dir.join("notes.txt"), "not a backup").unwrap();
+
+    // the newest two are kept, newest first
+    let backups = list_backups(dir).unwrap();
+    assert_eq!(vec![dir.join(backup_name(time(3))), dir.join(backup_name(time(2)))], backups);
+    assert_eq!(vec![dir.join(backup_name(time(2)))], rotate(dir, 1).unwrap());
+
+    assert_eq!(dir.join(backup_name(time(3))), find_backup(dir, "1").unwrap());
+    assert_eq!(dir.join(backup_name(time(3))), find_backup(dir, "data-20261019-120300.db").unwrap());
+    assert!(matches!(find_backup(dir, "2"), Err(BackupError::NotFound(_))));
+    assert!(matches!(find_backup(dir, "0"), Err(BackupError::NotFound(_))));
+
+    let copy = TodoDatabase::new(dir.join(backup_name(time(3))).display().to_string());
+    assert!(copy.get_user_by_name("alice").unwrap().is_some());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_startup_keeps_history(){
+    let dir = new_dir("database/test_backups_history");
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    for day in 1..=3{
+        create_backup(&database, dir, 2, day_time(day, 12)).unwrap();
+    }
+
+    // starting again soon after a backup makes none
+    let interval = Duration::minutes(30);
+    assert!(create_backup_if_due(&database, dir, 2, interval, day_time(3, 12) + Duration::minutes(10)).unwrap().is_none());
+    assert_eq!(3, list_backups(dir).unwrap().len());
+
+    // many startups in a day keep the newest backup of the days before
+    for hour in 13..=20{
+        assert!(create_backup_if_due(&database, dir, 2, interval, day_time(4, hour)).unwrap().is_some());
+    }
+    let backups = list_backups(dir).unwrap();
+    assert_eq!(5, backups.len());
+    assert_eq!(dir.join(backup_name(day_time(4, 20))), backups[0]);
+    assert_eq!(dir.join(backup_name(day_time(4, 19))), backups[1]);
+    for day in 1..=3{
+        assert!(backups.contains(&dir.join(backup_name(day_time(day, 12)))));
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_rotate_days(){
+    let dir = new_dir("database/test_backups_days");
+    let database = TodoDatabase::new(String::from(":memory:"));
+    for day in 1..=31{
+        for hour in [9, 17]{
+            create_backup(&database, dir, 1, NaiveDate::from_ymd_opt(2026, 8, day).unwrap().and_hms_opt(hour, 0, 0).unwrap()).unwrap();
+        }
+    }
+
+    // the newest of each of the last days, the oldest days are removed
+    let backups = list_backups(dir).unwrap();
+    assert_eq!(DAILY_BACKUPS, backups.len());
+    assert!(backups.iter().all(|path| backup_time(path).unwrap().format("%H").to_string() == "17"));
+    assert_eq!(Some(NaiveDate::from_ymd_opt(2026, 8, 2).unwrap()), backup_time(backups.last().unwrap()).map(|time| time.date()));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_restore(){
+    let dir = new_dir("database/test_backups_restore");
+    let mut database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    create_backup(&database, dir, 10, time(1)).unwrap();
+
+    // a mistaken delete
+    database.remove_list(10, 1).unwrap();
+    assert!(database.load_user_data(1).is_empty());
+
+    let undo = restore(&mut database, dir, "1", 10, time(2)).unwrap();
+    let lists = database.load_user_data(1);
+    assert_eq!("Chores", lists[0].get_name());
+    assert_eq!("sweep", lists[0].list[0].get_item_name());
+
+    // the database before the restore is kept
+    let before = TodoDatabase::new(undo.display().to_string());
+    assert!(before.load_user_data(1).is_empty());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_restore_user(){
+    let dir = new_dir("database/test_backups_user");
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.create_user_account("bob", "hunter2", 2).unwrap();
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    database.insert_into_list(String::from("Groceries"), 20, 2).unwrap();
+    create_backup(&database, dir, 10, time(1)).unwrap();
+
+    // alice deletes her account, bob keeps working
+    database.remove_user(1).unwrap();
+    database.insert_into_list(String::from("Garden"), 30, 2).unwrap();
+
+    assert_eq!(1, restore_user(&database, dir, "1", "alice", 10, time(2)).unwrap());
+    assert_eq!(vec!["Chores"], list_names(&database, 1));
+    assert_eq!("sweep", database.load_user_data(1)[0].list[0].get_item_name());
+    assert_eq!("secret", database.get_user_by_name("alice").unwrap().unwrap().password);
+    // bob is not changed
+    assert_eq!(vec!["Garden", "Groceries"], list_names(&database, 2));
+
+    // the restore of alice was backed up first
+    assert_eq!(2, list_backups(dir).unwrap().len());
+
+    assert!(matches!(restore_user(&database, dir, "1", "mallory", 10, time(3)), Err(BackupError::UnknownUser(_))));
+    // nothing was restored, so no backup is added
+    assert_eq!(2, list_backups(dir).unwrap().len());
+    // a new account with the username is not replaced
+    database.remove_user(1).unwrap();
+    database.create_user_account("alice", "other", 3).unwrap();
+    assert!(matches!(restore_user(&database, dir, "data-20261019-120100.db", "alice", 10, time(4)), Err(BackupError::UnknownUser(_))));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_restore_user_conflict(){
+    let dir = new_dir("database/test_backups_conflict");
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    database.insert_into_list(String::from("Errands"), 11, 1).unwrap();
+    create_backup(&database, dir, 10, time(1)).unwrap();
+
+    // after alice leaves, bob gets her old ids
+    database.remove_user(1).unwrap();
+    database.create_user_account("bob", "hunter2", 1).unwrap();
+    database.insert_into_list(String::from("Groceries"), 10, 1).unwrap();
+    database.insert_into_items(String::from("milk"), 100, 10, 0).unwrap();
+
+    let err = restore_user(&database, dir, "1", "alice", 1, time(2)).unwrap_err();
+    match &err{
+        BackupError::Conflict(username, ids) =>{
+            assert_eq!("alice", username);
+            assert_eq!("user 1, list 10, item 100", ids);
+        },
+        _ =>{panic!("expected a conflict, got {}", err);},
+    }
+    // nothing is changed, no backup is added or removed
+    assert_eq!(vec![dir.join(backup_name(time(1)))], list_backups(dir).unwrap());
+    assert!(database.get_user_by_name("alice").unwrap().is_none());
+    assert_eq!(vec!["Groceries"], list_names(&database, 1));
+    assert_eq!("milk", database.load_user_data(1)[0].list[0].get_item_name());
+
+    // only the list with a reused id conflicts when alice still has her account
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.create_user_account("bob", "hunter2", 2).unwrap();
+    database.insert_into_list(String::from("Groceries"), 10, 2).unwrap();
+    let err = restore_user(&database, dir, "data-20261019-120100.db", "alice", 10, time(3)).unwrap_err();
+    assert!(matches!(err, BackupError::Conflict(_, ref ids) if ids == "list 10"));
+    assert!(list_names(&database, 1).is_empty());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_encrypted_backup(){
+    let dir = new_dir("database/test_backups_encrypted");
+    let path = Path::new("database/test_backup_encrypted.db");
+    let _ = fs::remove_file(path);
+    let mut database = open_database(path, Some("key")).unwrap();
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.insert_into_list(String::from("Secret Plans"), 10, 1).unwrap();
+
+    // the backup is encrypted with the same key
+    let backup = create_backup(&database, dir, 10, time(1)).unwrap();
+    assert!(is_encrypted(&backup));
+    database.remove_list(10, 1).unwrap();
+    restore_user(&database, dir, "1", "alice", 10, time(2)).unwrap();
+    assert_eq!(vec!["Secret Plans"], list_names(&database, 1));
+    database.remove_list(10, 1).unwrap();
+    restore(&mut database, dir, "data-20261019-120100.db", 10, time(3)).unwrap();
+    assert_eq!(vec!["Secret Plans"], list_names(&database, 1));
+
+    drop(database);
+    fs::remove_file(path).unwrap();
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -1,5 +1,8 @@
 use todo_list_rust::database::database::TodoDatabase;
 use todo_list_rust::todo::todo::TodoList;
+use todo_list_rust::item_view::item_view::{ItemFilter, ItemSort};
+use todo_list_rust::stats::stats::{Stats, STATS_DAYS};
+use chrono::{Local, NaiveDate};
 
 #[test]
 fn test_all(){
@@ -10,6 +13,24 @@ fn test_all(){
     print!("Test: test_list_items_insert...");
     test_list_items_insert(&test_db);
     println!("Pass");
+    print!("Test: test_list_view_item_details...");
+    test_list_view_item_details(&test_db);
+    println!("Pass");
+    print!("Test: test_match_items...");
+    test_match_items(&test_db);
+    println!("Pass");
+    print!("Test: test_stats...");
+    test_stats(&test_db);
+    println!("Pass");
+    print!("Test: test_board...");
+    test_board(&test_db);
+    println!("Pass");
+    print!("Test: test_preferences...");
+    test_preferences(&test_db);
+    println!("Pass");
+    print!("Test: test_load_order...");
+    test_load_order(&test_db);
+    println!("Pass");
 
     match test_db.close_connection() {
         Err(err) => {panic!("{:?}", err);}
@@ -149,4 +170,270 @@ fn test_list_items_insert(test_db: &TodoDatabase){
     };
     let user_removed: Vec<TodoList> = test_db.load_user_data(2);
     assert_eq!(0, user_removed.len());
-}
\ No newline at end of file
+}
+
+fn test_match_items(test_db: &TodoDatabase){
+
+    match test_db.create_user_account("user_three", "pass", 3){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.insert_into_list(String::from("groceries"), 50, 3){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    for (item_id, item_name) in [(5000, "buy milk"), (5001, "buy eggs"), (5002, "bake bread")]{
+        match test_db.insert_into_items(String::from(item_name), item_id, 50, 0){
+            Err(err) => {panic!("{:?}", err)},
+            Ok(())=>{},
+        };
+    }
+
+    // fuzzy search
+    match test_db.match_items(3, "%b%u%"){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(result)=>{
+            assert_eq!(2, result.len());
+            assert_eq!("groceries", result[0].list_name);
+        },
+    };
+
+    // other users lists are not searched
+    match test_db.match_items(2, "%b%u%"){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(result)=>{assert_eq!(0, result.len());},
+    };
+
+    // removed items are not found
+    match test_db.remove_item(5000, 50){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.match_items(3, "%m%i%l%k%"){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(result)=>{assert_eq!(0, result.len());},
+    };
+
+    match test_db.remove_user(3){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+}
+
+
+fn test_list_view_item_details(test_db: &TodoDatabase){
+
+    match test_db.create_user_account("user_four", "pass", 4){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.insert_into_list(String::from("view"), 60, 4){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.insert_into_items(String::from("item"), 6000, 60, 0){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+
+    // store view and details
+    match test_db.update_list_view(60, 4, ItemFilter::HideCompleted, ItemSort::Due){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.update_item_details(6000, 60, NaiveDate::from_ymd_opt(2026, 10, 20), 2){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+
+    // load them back
+    let loaded: Vec<TodoList> = test_db.load_user_data(4);
+    assert_eq!(1, loaded.len());
+    assert_eq!(ItemFilter::HideCompleted, loaded[0].get_filter());
+    assert_eq!(ItemSort::Due, loaded[0].get_sort());
+    assert_eq!(NaiveDate::from_ymd_opt(2026, 10, 20), loaded[0].list[0].get_due());
+    assert_eq!(2, loaded[0].list[0].get_priority());
+    assert!(loaded[0].list[0].get_created().is_some());
+
+    match test_db.remove_user(4){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+}
+
+fn test_stats(test_db: &TodoDatabase){
+
+    match test_db.create_user_account("user_five", "pass", 5){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.insert_into_list(String::from("work"), 70, 5){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.insert_into_list(String::from("empty"), 71, 5){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    // one done, one open and overdue, one open without a due date
+    for item_id in [7000, 7001, 7002]{
+        match test_db.insert_into_items(String::from("item"), item_id, 70, 0){
+            Err(err) => {panic!("{:?}", err)},
+            Ok(())=>{},
+        };
+    }
+    match test_db.update_item(7000, 70, 1){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.update_item_details(7001, 70, NaiveDate::from_ymd_opt(2000, 1, 1), 0){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+
+    let today: NaiveDate = Local::now().date_naive();
+    let stats = match Stats::load(test_db, 5, today){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(stats)=>{stats},
+    };
+
+    // per list counts, lists without items are counted
+    assert_eq!(2, stats.lists.len());
+    assert_eq!("work", stats.lists[0].list_name);
+    assert_eq!(3, stats.lists[0].total);
+    assert_eq!(1, stats.lists[0].done);
+    assert_eq!(1, stats.lists[0].overdue);
+    assert_eq!(0, stats.lists[1].total);
+
+    // totals and today's completed count
+    assert_eq!(2, stats.open);
+    assert_eq!(1, stats.done);
+    assert_eq!(1, stats.overdue);
+    assert_eq!(STATS_DAYS, stats.completed_per_day.len());
+    assert_eq!(1, stats.completed_per_day[STATS_DAYS - 1]);
+
+    // un completing an item removes it from the count
+    match test_db.update_item(7000, 70, 0){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    let stats = Stats::load(test_db, 5, today).unwrap();
+    assert_eq!(0, stats.completed_per_day.iter().sum::<u64>());
+
+    match test_db.remove_user(5){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+}
+
+fn test_board(test_db: &TodoDatabase){
+
+    match test_db.create_user_account("user_six", "pass", 6){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.insert_into_list(String::from("project"), 80, 6){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    for item_id in [8000, 8001, 8002]{
+        match test_db.insert_into_items(String::from("card"), item_id, 80, 0){
+            Err(err) => {panic!("{:?}", err)},
+            Ok(())=>{},
+        };
+    }
+
+    // store custom columns and move cards
+    let columns: Vec<String> = vec![String::from("Backlog"), String::from("Doing"), String::from("Review"), String::from("Done")];
+    match test_db.update_list_columns(80, 6, &columns){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.update_item_status(8000, 80, 2, 0){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.update_item_status(8001, 80, 3, 1){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    // completed with the old boolean only, loads into the done column
+    match test_db.update_item(8002, 80, 1){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+
+    let loaded: Vec<TodoList> = test_db.load_user_data(6);
+    assert_eq!(columns, loaded[0].get_columns());
+    assert_eq!(2, loaded[0].list[0].get_status());
+    assert!(!loaded[0].list[0].get_complete());
+    assert_eq!(3, loaded[0].list[1].get_status());
+    assert!(loaded[0].list[1].get_complete());
+    assert_eq!(3, loaded[0].list[2].get_status());
+
+    // moving to the done column counts as completed today
+    let stats = Stats::load(test_db, 6, Local::now().date_naive()).unwrap();
+    assert_eq!(2, stats.lists[0].done);
+    assert_eq!(2, stats.completed_per_day[STATS_DAYS - 1]);
+
+    match test_db.remove_user(6){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+}
+
+fn test_preferences(test_db: &TodoDatabase){
+
+    match test_db.create_user_account("user_seven", "pass", 7){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+
+    // nothing stored yet
+    assert!(test_db.get_preferences(7).unwrap().is_none());
+
+    // stored then replaced
+    match test_db.update_preferences(7, Some(90), Some(9000)){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    match test_db.update_preferences(7, Some(91), None){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    let preferences = test_db.get_preferences(7).unwrap().unwrap();
+    assert_eq!(7, preferences.user_id);
+    assert_eq!(Some(91), preferences.list_id);
+    assert_eq!(None, preferences.item_id);
+
+    // removed with the user
+    match test_db.remove_user(7){
+        Err(err) => {panic!("{:?}", err)},
+        Ok(())=>{},
+    };
+    assert!(test_db.get_preferences(7).unwrap().is_none());
+}
+
+
+fn test_load_order(test_db: &TodoDatabase){
+    test_db.create_user_account("user_order", "pass", 90).unwrap();
+
+    // ids count down, lists and items still load in the order they were added
+    test_db.insert_into_list(String::from("first"), 9003, 90).unwrap();
+    test_db.insert_into_list(String::from("second"), 9002, 90).unwrap();
+    test_db.insert_into_list(String::from("third"), 9001, 90).unwrap();
+    // items added to other lists between them do not change the order
+    for (item_id, item_name, list_id) in [(9105, "a", 9002), (9201, "x", 9001), (9101, "b", 9002), (9109, "c", 9002), (9202, "y", 9001), (9100, "d", 9002)]{
+        test_db.insert_into_items(String::from(item_name), item_id, list_id, 0).unwrap();
+    }
+
+    let lists: Vec<TodoList> = test_db.load_user_data(90);
+    let list_names: Vec<String> = lists.iter().map(|list| list.get_name()).collect();
+    assert_eq!(vec!["first", "second", "third"], list_names);
+    let item_names: Vec<String> = lists[1].list.iter().map(|item| item.get_item_name()).collect();
+    assert_eq!(vec!["a", "b", "c", "d"], item_names);
+    let item_names: Vec<String> = lists[2].list.iter().map(|item| item.get_item_name()).collect();
+    assert_eq!(vec!["x", "y"], item_names);
+
+    test_db.remove_user(90).unwrap();
+}
@@ -0,0 +1,66 @@
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::encryption::encryption::{is_encrypted, open_database, encrypt_file, decrypt_file, EncryptionError};
+use std::fs;
+use std::path::Path;
+
+fn new_database(path: &Path){
+    let _ = fs::remove_file(path);
+    let database = TodoDatabase::new(path.display().to_string());
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.insert_into_list(String::from("Secret Plans"), 10, 1).unwrap();
+    database.insert_into_items(String::from("buy a volcano lair"), 100, 10, 0).unwrap();
+}
+
+fn contains(path: &Path, text: &str) -> bool{
+    let bytes = fs::read(path).unwrap();
+    bytes.windows(text.len()).any(|window| window == text.as_bytes())
+}
+
+#[test]
+fn test_encrypt_decrypt(){
+    let path = Path::new("database/test_encrypt.db");
+    new_database(path);
+    assert!(!is_encrypted(path));
+    assert!(contains(path, "buy a volcano lair"));
+
+    encrypt_file(path, "correct horse").unwrap();
+    assert!(is_encrypted(path));
+    // copying the file does not leak the lists
+    assert!(!contains(path, "buy a volcano lair"));
+    assert!(!contains(path, "Secret Plans"));
+    assert!(matches!(encrypt_file(path, "correct horse"), Err(EncryptionError::AlreadyEncrypted(_))));
+
+    let database = open_database(path, Some("correct horse")).unwrap();
+    let lists = database.load_user_data(1);
+    assert_eq!("Secret Plans", lists[0].get_name());
+    assert_eq!("buy a volcano lair", lists[0].list[0].get_item_name());
+    drop(database);
+
+    assert!(matches!(decrypt_file(path, "wrong"), Err(EncryptionError::WrongKey(_))));
+    decrypt_file(path, "correct horse").unwrap();
+    assert!(!is_encrypted(path));
+    assert!(matches!(decrypt_file(path, "correct horse"), Err(EncryptionError::NotEncrypted(_))));
+    assert_eq!(1, open_database(path, None).unwrap().load_user_data(1).len());
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_open_database(){
+    let path = Path::new("database/test_open_encrypted.db");
+    new_database(path);
+
+    assert!(matches!(open_database(path, Some("key")), Err(EncryptionError::NotEncrypted(_))));
+    encrypt_file(path, "key").unwrap();
+    assert!(matches!(open_database(path, None), Err(EncryptionError::NoKey(_))));
+    assert!(matches!(open_database(path, Some("not the key")), Err(EncryptionError::WrongKey(_))));
+    assert!(open_database(path, Some("key")).is_ok());
+    fs::remove_file(path).unwrap();
+
+    // a new database is encrypted from the start
+    let path = Path::new("database/test_new_encrypted.db");
+    let _ = fs::remove_file(path);
+    open_database(path, Some("key")).unwrap().create_user_account("alice", "secret", 1).unwrap();
+    assert!(is_encrypted(path));
+    assert!(!contains(path, "alice"));
+    fs::remove_file(path).unwrap();
+}
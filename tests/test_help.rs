@@ -0,0 +1,48 @@
+use todo_list_rust::keymap::keymap::{Keymap, KeyContext, Action, Preset};
+use todo_list_rust::config::config::parse_config;
+use todo_list_rust::help::help::{help_entries, footer_text, context_note};
+
+#[test]
+fn test_help_entries(){
+    let keymap = Keymap::new(Preset::Default);
+
+    // every binding of the context is listed once
+    let entries = help_entries(&keymap, KeyContext::Navigate);
+    let actions: Vec<Action> = entries.iter().map(|entry| entry.action).collect();
+    assert!(actions.contains(&Action::Delete));
+    assert!(actions.contains(&Action::Help));
+    assert!(!actions.contains(&Action::Add));
+    let delete = entries.iter().find(|entry| entry.action == Action::Delete).unwrap();
+    assert_eq!("Backspace / Delete", delete.keys);
+
+    // actions of other contexts are not listed
+    let actions: Vec<Action> = help_entries(&keymap, KeyContext::Input).iter().map(|entry| entry.action).collect();
+    assert!(actions.contains(&Action::Add));
+    assert!(!actions.contains(&Action::Delete));
+    assert!(!actions.contains(&Action::Quit));
+}
+
+#[test]
+fn test_help_follows_keymap(){
+    let default_footer = footer_text(&Keymap::new(Preset::Default), KeyContext::Default);
+    assert!(default_footer.contains("q: quit"));
+    assert!(default_footer.contains("?"));
+
+    // the vim preset shows vim keys
+    let vim = Keymap::new(Preset::Vim);
+    let delete = help_entries(&vim, KeyContext::Navigate).into_iter().find(|entry| entry.action == Action::Delete).unwrap();
+    assert!(delete.keys.contains("d d"));
+
+    // rebinding a key changes the footer
+    let config = parse_config("[keys.default]\nquit = [\"Ctrl-c\"]\n").unwrap();
+    let keymap = Keymap::from_config(&config.keys).unwrap();
+    let footer = footer_text(&keymap, KeyContext::Default);
+    assert!(footer.contains("Ctrl-c: quit"));
+    assert!(!footer.contains("q: quit"));
+}
+
+#[test]
+fn test_context_note(){
+    assert!(context_note(KeyContext::Input).unwrap().contains("due:YYYY-MM-DD"));
+    assert_eq!(None, context_note(KeyContext::Navigate));
+}
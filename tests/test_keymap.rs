@@ -0,0 +1,109 @@
+use todo_list_rust::keymap::keymap::{Keymap, KeyPress, KeyResult, KeyContext, Action, Preset, parse_sequence};
+use todo_list_rust::config::config::{parse_config, ConfigError};
+use todo_list_rust::theme::theme::Theme;
+use crossterm::event::KeyCode;
+
+fn key(c: char) -> KeyPress{
+    KeyPress{code: KeyCode::Char(c), ctrl: false, alt: false}
+}
+
+#[test]
+fn test_parse_keys(){
+    assert_eq!(Some(key('q')), KeyPress::parse("q"));
+    assert_eq!(Some(key(' ')), KeyPress::parse("Space"));
+    assert_eq!(Some(key('-')), KeyPress::parse("-"));
+    assert_eq!(Some(KeyPress{code: KeyCode::Enter, ctrl: false, alt: false}), KeyPress::parse("Enter"));
+    assert_eq!(Some(KeyPress{code: KeyCode::Char('w'), ctrl: true, alt: false}), KeyPress::parse("Ctrl-w"));
+    assert_eq!(Some(KeyPress{code: KeyCode::Char('x'), ctrl: true, alt: true}), KeyPress::parse("Ctrl-Alt-x"));
+    assert_eq!(Some(KeyPress{code: KeyCode::F(5), ctrl: false, alt: false}), KeyPress::parse("F5"));
+    assert_eq!(None, KeyPress::parse("F13"));
+    assert_eq!(None, KeyPress::parse("Nope"));
+
+    assert_eq!(Some(vec![key('d'), key('d')]), parse_sequence("d d"));
+    assert_eq!(None, parse_sequence(""));
+    assert_eq!(None, parse_sequence("d Nope"));
+}
+
+#[test]
+fn test_default_keymap(){
+    let mut keymap = Keymap::new(Preset::Default);
+    assert_eq!(KeyResult::Action(Action::Quit), keymap.resolve(KeyContext::Default, key('q')));
+    assert_eq!(KeyResult::Action(Action::Input), keymap.resolve(KeyContext::Default, key('1')));
+    // bindings only apply in their context
+    assert_eq!(KeyResult::Unbound(key('q')), keymap.resolve(KeyContext::Input, key('q')));
+    // vim keys are not bound
+    assert_eq!(KeyResult::Unbound(key('j')), keymap.resolve(KeyContext::Navigate, key('j')));
+}
+
+#[test]
+fn test_vim_keymap(){
+    let mut keymap = Keymap::new(Preset::Vim);
+    assert_eq!(KeyResult::Action(Action::Next), keymap.resolve(KeyContext::Navigate, key('j')));
+    assert_eq!(KeyResult::Action(Action::Toggle), keymap.resolve(KeyContext::Navigate, key('x')));
+    // default bindings are kept
+    assert_eq!(KeyResult::Action(Action::Input), keymap.resolve(KeyContext::Default, key('1')));
+
+    // sequences wait for the next key
+    assert_eq!(KeyResult::Pending, keymap.resolve(KeyContext::Navigate, key('d')));
+    assert_eq!(KeyResult::Action(Action::Delete), keymap.resolve(KeyContext::Navigate, key('d')));
+
+    // a broken sequence runs the new key on its own
+    assert_eq!(KeyResult::Pending, keymap.resolve(KeyContext::Navigate, key('d')));
+    assert_eq!(KeyResult::Action(Action::Prev), keymap.resolve(KeyContext::Navigate, key('k')));
+
+    // clearing forgets held keys
+    assert_eq!(KeyResult::Pending, keymap.resolve(KeyContext::Navigate, key('g')));
+    keymap.clear_pending();
+    assert_eq!(KeyResult::Pending, keymap.resolve(KeyContext::Navigate, key('g')));
+    assert_eq!(KeyResult::Action(Action::First), keymap.resolve(KeyContext::Navigate, key('g')));
+}
+
+#[test]
+fn test_config_keymap(){
+    let config = parse_config("
+        [keys]
+        preset = \"vim\"
+
+        [keys.navigate]
+        delete = [\"Ctrl-x\"]
+        quit = [\"Q\"]
+    ").unwrap();
+    let mut keymap = Keymap::from_config(&config.keys).unwrap();
+
+    // replaced bindings
+    assert_eq!(KeyResult::Unbound(key('d')), keymap.resolve(KeyContext::Navigate, key('d')));
+    assert_eq!(KeyResult::Action(Action::Delete), keymap.resolve(KeyContext::Navigate, KeyPress{code: KeyCode::Char('x'), ctrl: true, alt: false}));
+    // new bindings
+    assert_eq!(KeyResult::Action(Action::Quit), keymap.resolve(KeyContext::Navigate, key('Q')));
+    // preset bindings for other actions are kept
+    assert_eq!(KeyResult::Action(Action::Next), keymap.resolve(KeyContext::Navigate, key('j')));
+
+    // empty config is the default preset
+    let mut keymap = Keymap::from_config(&parse_config("").unwrap().keys).unwrap();
+    assert_eq!(KeyResult::Unbound(key('j')), keymap.resolve(KeyContext::Navigate, key('j')));
+}
+
+#[test]
+fn test_config_errors(){
+    let bad_preset = parse_config("[keys]\npreset = \"emacs\"").unwrap();
+    assert!(matches!(Keymap::from_config(&bad_preset.keys), Err(ConfigError::UnknownPreset(_))));
+
+    let bad_context = parse_config("[keys.nowhere]\nquit = [\"q\"]").unwrap();
+    assert!(matches!(Keymap::from_config(&bad_context.keys), Err(ConfigError::UnknownContext(_))));
+
+    let bad_action = parse_config("[keys.default]\nfly = [\"q\"]").unwrap();
+    assert!(matches!(Keymap::from_config(&bad_action.keys), Err(ConfigError::UnknownAction(_))));
+
+    let bad_key = parse_config("[keys.default]\nquit = [\"Hyper-q\"]").unwrap();
+    assert!(matches!(Keymap::from_config(&bad_key.keys), Err(ConfigError::UnknownKey(_))));
+
+    assert!(matches!(parse_config("[keys"), Err(ConfigError::Parse(_))));
+    assert!(matches!(parse_config("[colors]"), Err(ConfigError::Parse(_))));
+}
+
+#[test]
+fn test_example_config(){
+    let config = parse_config(include_str!("../config/config.example.toml")).unwrap();
+    assert!(Keymap::from_config(&config.keys).is_ok());
+    assert!(Theme::from_config(&config.theme, false).is_ok());
+}
@@ -0,0 +1,87 @@
+use todo_list_rust::line_editor::line_editor::LineEditor;
+use todo_list_rust::keymap::keymap::Action;
+
+#[test]
+fn test_insert_and_move(){
+    let mut editor = LineEditor::new();
+    for c in "helo".chars(){
+        editor.insert(c);
+    }
+    editor.move_left();
+    editor.insert('l');
+    assert_eq!("hello", editor.get_text());
+
+    editor.move_start();
+    editor.insert('>');
+    editor.move_end();
+    editor.insert('!');
+    assert_eq!(">hello!", editor.get_text());
+
+    // the cursor stops at both ends
+    editor.move_right();
+    editor.move_start();
+    editor.move_left();
+    assert_eq!(0, editor.get_cursor());
+
+    assert_eq!(">hello!", editor.take());
+    assert!(editor.is_empty());
+    assert_eq!(0, editor.get_cursor());
+}
+
+#[test]
+fn test_delete(){
+    let mut editor = LineEditor::from("buy  milk now");
+    editor.delete_word();
+    assert_eq!("buy  milk ", editor.get_text());
+    editor.delete_word();
+    assert_eq!("buy  ", editor.get_text());
+
+    editor.move_start();
+    editor.delete();
+    editor.backspace();
+    assert_eq!("uy  ", editor.get_text());
+
+    // at the ends nothing is removed
+    editor.move_end();
+    editor.delete();
+    editor.move_start();
+    editor.backspace();
+    editor.delete_word();
+    assert_eq!("uy  ", editor.get_text());
+}
+
+#[test]
+fn test_unicode(){
+    // é is two bytes, 日本 are two columns wide
+    let mut editor = LineEditor::from("café 日本");
+    editor.backspace();
+    editor.move_left();
+    editor.move_left();
+    editor.backspace();
+    assert_eq!("caf 日", editor.get_text());
+    editor.move_end();
+    let (text, column) = editor.visible(20);
+    assert_eq!("caf 日", text);
+    assert_eq!(6, column);
+
+    // long text scrolls so the cursor stays inside the box
+    let editor = LineEditor::from("日本日本日本");
+    let (text, column) = editor.visible(5);
+    assert_eq!("日本", text);
+    assert_eq!(4, column);
+}
+
+#[test]
+fn test_paste_and_edit_actions(){
+    let mut editor = LineEditor::from("ac");
+    editor.move_left();
+    editor.paste("b\r\nb\t\u{7}");
+    assert_eq!("ab b c", editor.get_text());
+
+    assert!(editor.edit(Action::LineStart));
+    assert!(editor.edit(Action::DeleteChar));
+    assert!(editor.edit(Action::LineEnd));
+    assert!(editor.edit(Action::DeleteWord));
+    assert_eq!("b b ", editor.get_text());
+    assert!(!editor.edit(Action::Quit));
+}
@@ -0,0 +1,57 @@
+use todo_list_rust::mouse::mouse::{ScreenLayout, MouseTarget, scroll_offset};
+use tui::layout::Rect;
+
+#[test]
+fn test_target(){
+    let mut layout = ScreenLayout::default();
+    layout.lists = Rect::new(0, 0, 20, 10);
+    layout.list_input = Rect::new(0, 10, 20, 3);
+    layout.items = Rect::new(20, 0, 20, 10);
+    layout.item_input = Rect::new(20, 10, 20, 3);
+    layout.list_count = 3;
+    layout.item_count = 20;
+    layout.item_offset = 5;
+
+    // rows inside the borders
+    assert_eq!(MouseTarget::List(0), layout.target(5, 1));
+    assert_eq!(MouseTarget::List(2), layout.target(5, 3));
+    // past the last list
+    assert_eq!(MouseTarget::Nothing, layout.target(5, 4));
+    // on the border
+    assert_eq!(MouseTarget::Nothing, layout.target(0, 1));
+    assert_eq!(MouseTarget::Nothing, layout.target(5, 0));
+    // items are offset by scrolling
+    assert_eq!(MouseTarget::Item(5), layout.target(25, 1));
+    assert_eq!(MouseTarget::Item(12), layout.target(25, 8));
+    // input boxes
+    assert_eq!(MouseTarget::ListInput, layout.target(5, 11));
+    assert_eq!(MouseTarget::ItemInput, layout.target(25, 12));
+    // outside everything
+    assert_eq!(MouseTarget::Nothing, layout.target(50, 50));
+
+    // panes for the scroll wheel
+    assert_eq!(MouseTarget::List(0), layout.pane(0, 0));
+    assert_eq!(MouseTarget::Item(0), layout.pane(39, 9));
+    assert_eq!(MouseTarget::Nothing, layout.pane(5, 11));
+
+    // cleared areas are not hit, offsets are kept
+    layout.clear_areas();
+    assert_eq!(MouseTarget::Nothing, layout.target(5, 1));
+    assert_eq!(5, layout.item_offset);
+}
+
+#[test]
+fn test_scroll_offset(){
+    // selection already visible
+    assert_eq!(0, scroll_offset(0, Some(3), 20, 5));
+    assert_eq!(2, scroll_offset(2, Some(3), 20, 5));
+    // selection below the pane
+    assert_eq!(6, scroll_offset(0, Some(10), 20, 5));
+    // selection above the pane
+    assert_eq!(1, scroll_offset(8, Some(1), 20, 5));
+    // list shrank
+    assert_eq!(2, scroll_offset(10, Some(2), 3, 5));
+    // empty list or pane
+    assert_eq!(0, scroll_offset(4, Some(2), 0, 5));
+    assert_eq!(0, scroll_offset(4, None, 10, 0));
+}
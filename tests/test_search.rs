@@ -0,0 +1,114 @@
+use todo_list_rust::app_state::app_state::State;
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::search::search::{match_score, search_lists, like_pattern, SearchResult};
+use todo_list_rust::todo::todo::TodoList;
+use todo_list_rust::user::user::User;
+
+// list index, item index and score of each result
+type ResultKeys = Vec<(usize, usize, i64)>;
+
+fn result_keys(results: &[SearchResult]) -> ResultKeys{
+    results.iter().map(|result| (result.list_index, result.item_index, result.score)).collect()
+}
+
+/*
+    Searches with the state, then searches the same lists in memory
+*/
+fn search_both(state: &mut State, query: &str) -> (ResultKeys, ResultKeys){
+    state.search_input.take();
+    state.search_input.paste(query);
+    state.update_search();
+    (result_keys(&state.search_results), result_keys(&search_lists(&state.todo_lists, query)))
+}
+
+#[test]
+fn test_match_score(){
+    // substring match
+    assert!(match_score("milk", "Buy milk").is_some());
+    // case insensitive
+    assert!(match_score("MILK", "buy milk").is_some());
+    // fuzzy match
+    assert!(match_score("bml", "buy milk").is_some());
+    // substring scores higher than fuzzy
+    assert!(match_score("milk", "buy milk").unwrap() > match_score("bmk", "buy milk").unwrap());
+    // earlier substring scores higher
+    assert!(match_score("milk", "milk tea").unwrap() > match_score("milk", "buy milk").unwrap());
+    // no match
+    assert_eq!(None, match_score("xyz", "buy milk"));
+    assert_eq!(None, match_score("klim", "buy milk"));
+    // empty query
+    assert_eq!(None, match_score("", "buy milk"));
+}
+
+#[test]
+fn test_search_lists(){
+    let mut groceries: TodoList = TodoList::new(String::from("Groceries"), 1);
+    groceries.add(String::from("eggs"), 10);
+    groceries.add(String::from("buy milk"), 11);
+    let mut work: TodoList = TodoList::new(String::from("Work"), 2);
+    work.add(String::from("email boss"), 20);
+    work.add(String::from("milestone review"), 21);
+    let todo_lists = vec![groceries, work];
+
+    let results = search_lists(&todo_lists, "mil");
+    assert_eq!(3, results.len());
+    // best match first
+    assert_eq!("Work", results[0].list_name);
+    assert_eq!(1, results[0].list_index);
+    assert_eq!(1, results[0].item_index);
+    assert_eq!("buy milk", results[1].item_name);
+    assert_eq!(0, results[1].list_index);
+    // fuzzy match last
+    assert_eq!("email boss", results[2].item_name);
+
+    assert_eq!(0, search_lists(&todo_lists, "zzz").len());
+    assert_eq!(0, search_lists(&todo_lists, "").len());
+}
+
+#[test]
+fn test_like_pattern(){
+    assert_eq!("%m%i%l%k%", like_pattern(" Milk "));
+    assert_eq!("%1%0%\\%%", like_pattern("10%"));
+    assert_eq!("%a%\\_%\\\\%", like_pattern("a_\\"));
+    assert_eq!("", like_pattern("   "));
+}
+
+#[test]
+fn test_search_same_results(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    database.insert_into_list(String::from("Groceries"), 1, 1).unwrap();
+    database.insert_into_list(String::from("Work"), 2, 1).unwrap();
+    database.insert_into_items(String::from("buy milk"), 1, 1, 0).unwrap();
+    database.insert_into_items(String::from("Milestone review"), 2, 2, 0).unwrap();
+    database.insert_into_items(String::from("email boss"), 3, 2, 0).unwrap();
+    database.insert_into_items(String::from("save 10% on tea"), 4, 1, 0).unwrap();
+    database.insert_into_items(String::from("save 10 on tea"), 5, 1, 0).unwrap();
+
+    let queries = ["mil", "ilk", "bml", "MILK", "10%", "view", "zzz", ""];
+
+    // a few items are searched in memory
+    let mut state = State::new(User::new(1, String::from("alice"), String::from("secret")), database);
+    let mut small = Vec::new();
+    for query in queries{
+        let (results, expected) = search_both(&mut state, query);
+        assert_eq!(expected, results, "query {:?}", query);
+        small.push(results);
+    }
+    // infix and fuzzy matches are found
+    assert_eq!(1, small[1].len());
+    assert_eq!(1, small[2].len());
+    assert_eq!(1, small[4].len());
+
+    // enough items that the database finds the candidates
+    for item_id in 100..1100{
+        state.database.insert_into_items(format!("filler {}", item_id), item_id, 2, 0).unwrap();
+    }
+    let mut state = State::new(User::new(1, String::from("alice"), String::from("secret")), state.database);
+    for (query, small) in queries.iter().zip(small){
+        let (results, expected) = search_both(&mut state, query);
+        assert_eq!(expected, results, "query {:?}", query);
+        // the filler items do not match
+        assert_eq!(small.len(), results.len(), "query {:?}", query);
+    }
+}
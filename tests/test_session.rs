@@ -0,0 +1,89 @@
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::session::session::{issue, resume, revoke, hash_token, save_token, load_token, resume_file, forget_file, SESSION_DAYS};
+use todo_list_rust::two_factor::two_factor::enable;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use std::fs;
+use std::path::Path;
+
+fn time(day: u32) -> NaiveDateTime{
+    NaiveDate::from_ymd_opt(2026, 10, day).unwrap().and_hms_opt(12, 0, 0).unwrap()
+}
+
+#[test]
+fn test_issue_resume(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+
+    let token = issue(&database, 1, time(1)).unwrap();
+    // only the hash is stored
+    assert!(database.get_session(&token).unwrap().is_none());
+    assert_eq!(1, database.get_session(&hash_token(&token)).unwrap().unwrap().user_id);
+    assert_eq!(1, database.count_sessions(1, time(1)).unwrap());
+
+    assert_eq!("alice", resume(&database, &token, time(2)).unwrap().unwrap().get_username());
+    assert!(resume(&database, "not a token", time(2)).unwrap().is_none());
+
+    // expired tokens are refused and forgotten
+    let expired = time(1) + Duration::days(SESSION_DAYS);
+    assert!(resume(&database, &token, expired).unwrap().is_none());
+    assert!(database.get_session(&hash_token(&token)).unwrap().is_none());
+
+    let token = issue(&database, 1, time(1)).unwrap();
+    revoke(&database, &token).unwrap();
+    assert!(resume(&database, &token, time(2)).unwrap().is_none());
+}
+
+#[test]
+fn test_session_file(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let path = Path::new("database/test_session_file");
+    let _ = fs::remove_file(path);
+
+    assert!(resume_file(&database, path, time(1)).unwrap().is_none());
+
+    let token = issue(&database, 1, time(1)).unwrap();
+    save_token(path, &token).unwrap();
+    assert_eq!(Some(token.clone()), load_token(path));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(0o600, fs::metadata(path).unwrap().permissions().mode() & 0o777);
+    }
+    assert_eq!(1, resume_file(&database, path, time(2)).unwrap().unwrap().get_user_id());
+
+    // logging out forgets the token and removes the file
+    forget_file(&database, path).unwrap();
+    assert!(!path.exists());
+    assert!(resume(&database, &token, time(2)).unwrap().is_none());
+
+    // a token that is no longer valid removes the file
+    save_token(path, "stale").unwrap();
+    assert!(resume_file(&database, path, time(2)).unwrap().is_none());
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_two_factor_revokes(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let path = Path::new("database/test_session_two_factor");
+    let _ = fs::remove_file(path);
+
+    let token = issue(&database, 1, time(1)).unwrap();
+    save_token(path, &token).unwrap();
+    enable(&database, 1, "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ", "005924", 1234567890).unwrap();
+    assert_eq!(0, database.count_sessions(1, time(2)).unwrap());
+    assert!(resume_file(&database, path, time(2)).unwrap().is_none());
+    assert!(!path.exists());
+
+    // a login from before two-factor authentication was on is refused even if it was not removed
+    database.insert_session(&hash_token("old"), 1, time(9), false).unwrap();
+    assert!(resume(&database, "old", time(2)).unwrap().is_none());
+    assert!(database.get_session(&hash_token("old")).unwrap().is_none());
+
+    // logins after it was turned on gave a code
+    let token = issue(&database, 1, time(1)).unwrap();
+    assert!(database.get_session(&hash_token(&token)).unwrap().unwrap().two_factor);
+    assert_eq!(1, resume(&database, &token, time(2)).unwrap().unwrap().get_user_id());
+}
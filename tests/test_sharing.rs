@@ -0,0 +1,130 @@
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::item_view::item_view::{ItemFilter, ItemSort};
+use todo_list_rust::sharing::sharing::{ListAccess, ShareCommand, ShareError, ShareForm, share_list};
+use todo_list_rust::todo::todo::TodoList;
+use todo_list_rust::user::user::User;
+
+fn new_user(database: &TodoDatabase, user_id: u32, username: &str) -> User{
+    database.create_user_account(username, "secret", user_id).unwrap();
+    User::new(user_id, String::from(username), String::from("secret"))
+}
+
+fn share(username: &str, access: Option<ListAccess>) -> ShareCommand{
+    ShareCommand{username: String::from(username), access}
+}
+
+#[test]
+fn test_share_form(){
+    let mut form = ShareForm::new();
+    assert_eq!(Some(ListAccess::ReadOnly), form.access);
+    assert_eq!("read only", form.access_name());
+    form.toggle_access();
+    assert_eq!(Some(ListAccess::ReadWrite), form.access);
+    form.toggle_access();
+    assert_eq!(None, form.access);
+    assert_eq!("stop sharing", form.access_name());
+    form.toggle_access();
+    assert_eq!(Some(ListAccess::ReadOnly), form.access);
+
+    // nothing typed is not a share
+    assert_eq!(None, form.take_command());
+    form.toggle_access();
+    form.username_input.paste(" bob ");
+    assert_eq!(Some(share("bob", Some(ListAccess::ReadWrite))), form.take_command());
+    // the username is taken, the access is kept
+    assert!(form.username_input.is_empty());
+    assert_eq!(Some(ListAccess::ReadWrite), form.access);
+}
+
+#[test]
+fn test_share_list(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let alice = new_user(&database, 1, "alice");
+    let bob = new_user(&database, 2, "bob");
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    let chores = TodoList::new(String::from("Chores"), 10);
+
+    assert!(matches!(share_list(&database, &alice, &chores, &share("carol", Some(ListAccess::ReadOnly))), Err(ShareError::UnknownUser(name)) if name == "carol"));
+    assert!(matches!(share_list(&database, &alice, &chores, &share("alice", Some(ListAccess::ReadOnly))), Err(ShareError::OwnList(_))));
+    assert!(database.load_user_data(2).is_empty());
+
+    // bob sees the list and its items, marked with the owner
+    share_list(&database, &alice, &chores, &share("bob", Some(ListAccess::ReadOnly))).unwrap();
+    let lists = database.load_user_data(2);
+    assert_eq!(1, lists.len());
+    assert_eq!(1, lists[0].get_list_len());
+    assert_eq!(Some("alice"), lists[0].get_owner());
+    assert_eq!(ListAccess::ReadOnly, lists[0].get_access());
+    assert_eq!("Chores [alice, read only]", lists[0].get_title());
+    // alice still owns it
+    assert_eq!(ListAccess::Owner, database.load_user_data(1)[0].get_access());
+    assert_eq!(1, database.match_items(2, "%sweep%").unwrap().len());
+
+    // sharing again changes the access
+    share_list(&database, &alice, &chores, &share("bob", Some(ListAccess::ReadWrite))).unwrap();
+    assert_eq!(ListAccess::ReadWrite, database.load_user_data(2)[0].get_access());
+    assert_eq!(1, database.get_list_shares(10).unwrap().len());
+
+    // only the owner can share
+    assert!(matches!(share_list(&database, &bob, &database.load_user_data(2)[0], &share("alice", None)), Err(ShareError::NotOwner(_))));
+
+    share_list(&database, &alice, &chores, &share("bob", None)).unwrap();
+    assert!(database.load_user_data(2).is_empty());
+    assert!(database.match_items(2, "%sweep%").unwrap().is_empty());
+}
+
+#[test]
+fn test_remove_shared_list(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    new_user(&database, 1, "alice");
+    new_user(&database, 2, "bob");
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.insert_into_items(String::from("sweep"), 100, 10, 0).unwrap();
+    database.share_list(10, 2, true).unwrap();
+
+    // bob cannot remove alice's list
+    database.remove_list(10, 2).unwrap();
+    assert_eq!(1, database.load_user_data(1)[0].get_list_len());
+    assert_eq!(1, database.load_user_data(2).len());
+
+    // deleting bob removes the share, not the list
+    database.remove_user(2).unwrap();
+    assert!(database.get_list_shares(10).unwrap().is_empty());
+    assert_eq!(1, database.load_user_data(1).len());
+
+    // removing the list removes its shares
+    new_user(&database, 3, "carol");
+    database.share_list(10, 3, false).unwrap();
+    database.remove_list(10, 1).unwrap();
+    assert!(database.load_user_data(3).is_empty());
+    assert!(database.get_list_shares(10).unwrap().is_empty());
+}
+
+#[test]
+fn test_shared_list_view(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    new_user(&database, 1, "alice");
+    new_user(&database, 2, "bob");
+    new_user(&database, 3, "carol");
+    database.insert_into_list(String::from("Chores"), 10, 1).unwrap();
+    database.share_list(10, 2, false).unwrap();
+    database.update_list_view(10, 1, ItemFilter::HideCompleted, ItemSort::Alphabetical).unwrap();
+
+    // bob keeps his own filter and sort, even on a read only list
+    database.update_list_view(10, 2, ItemFilter::OnlyCompleted, ItemSort::Due).unwrap();
+    let lists = database.load_user_data(2);
+    assert_eq!(ItemFilter::OnlyCompleted, lists[0].get_filter());
+    assert_eq!(ItemSort::Due, lists[0].get_sort());
+    let lists = database.load_user_data(1);
+    assert_eq!(ItemFilter::HideCompleted, lists[0].get_filter());
+    assert_eq!(ItemSort::Alphabetical, lists[0].get_sort());
+
+    // sharing again keeps bob's view
+    database.share_list(10, 2, true).unwrap();
+    assert_eq!(ItemSort::Due, database.load_user_data(2)[0].get_sort());
+
+    // carol cannot see the list, nothing is stored
+    assert!(matches!(database.update_list_view(10, 3, ItemFilter::All, ItemSort::Priority), Err(rusqlite::Error::QueryReturnedNoRows)));
+    assert!(matches!(database.update_list_columns(10, 2, &[String::from("Todo"), String::from("Done")]), Err(rusqlite::Error::QueryReturnedNoRows)));
+}
@@ -0,0 +1,150 @@
+use todo_list_rust::app::app::run_app_action;
+use todo_list_rust::app_state::app_state::State;
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::keymap::keymap::{Action, Keymap, Preset};
+use todo_list_rust::render::render_ui;
+use todo_list_rust::theme::theme::Theme;
+use todo_list_rust::user::user::User;
+use proptest::prelude::*;
+use tui::{backend::TestBackend, Terminal};
+
+/*
+    This enum is one step a user can take
+*/
+#[derive(Debug, Clone)]
+enum Step{
+    Act(Action),
+    Type(char),
+    Click(u16, u16),
+    Scroll(u16, u16, bool),
+}
+
+fn step() -> impl Strategy<Value = Step>{
+    prop_oneof![
+        4 => prop::sample::select(Action::all()).prop_filter("quit ends the run", |action| *action != Action::Quit).prop_map(Step::Act),
+        // names, columns, due dates and priorities
+        2 => prop::sample::select(vec!['a', 'b', ' ', ':', ',', 'c', 'o', 'l', 's', 'p', '2']).prop_map(Step::Type),
+        1 => (0u16..80, 0u16..30).prop_map(|(column, row)| Step::Click(column, row)),
+        1 => (0u16..80, 0u16..30, any::<bool>()).prop_map(|(column, row, down)| Step::Scroll(column, row, down)),
+    ]
+}
+
+/*
+    Checks the selection points at a list and a shown item whenever there is one
+*/
+fn assert_selection(state: &State){
+    match state.get_list_index(){
+        Some(index) =>{assert!(index < state.todo_lists.len())},
+        None =>{assert!(state.todo_lists.is_empty())},
+    }
+    let shown: usize = state.current_list().map_or(0, |todo_list| todo_list.get_visible_len());
+    match state.get_item_index(){
+        Some(index) =>{assert!(index < shown)},
+        None =>{assert_eq!(0, shown)},
+    }
+    assert_eq!(state.get_item_index().is_some(), state.selected_item().is_some());
+}
+
+proptest!{
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_random_steps_never_panic(steps in prop::collection::vec(step(), 1..120)){
+        let database = TodoDatabase::new(String::from(":memory:"));
+        database.create_user_account("alice", "secret", 1).unwrap();
+        let mut state = State::new(User::new(1, String::from("alice"), String::from("secret")), database);
+        let keymap = Keymap::new(Preset::Default);
+        let theme = Theme::light();
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+
+        for step in steps{
+            match step{
+                Step::Act(action) =>{run_app_action(&mut state, action);},
+                Step::Type(c) =>{state.add_input(c);},
+                Step::Click(column, row) =>{state.click(column, row);},
+                Step::Scroll(column, row, down) =>{state.scroll(column, row, down);},
+            }
+            assert_selection(&state);
+            terminal.draw(|f| render_ui(f, &mut state, &keymap, &theme)).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_empty_state(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut state = State::new(User::new(1, String::from("alice"), String::from("secret")), database);
+    assert_eq!(None, state.get_list_index());
+    assert_eq!(None, state.get_item_index());
+
+    // moving and editing with no lists does nothing
+    state.navigate_state();
+    for action in [Action::Next, Action::Prev, Action::Switch, Action::Next, Action::Prev, Action::Delete, Action::Toggle, Action::First, Action::Last]{
+        run_app_action(&mut state, action);
+    }
+    state.capture_input_state();
+    run_app_action(&mut state, Action::Switch);
+    state.add_input('x');
+    run_app_action(&mut state, Action::Add);
+    assert!(state.todo_lists.is_empty());
+    assert_eq!("No Active Lists", state.get_list_name());
+
+    // the first list is selected, with no item until one is added
+    run_app_action(&mut state, Action::Switch);
+    state.add_input('a');
+    run_app_action(&mut state, Action::Add);
+    assert_eq!(Some(0), state.get_list_index());
+    assert_eq!(None, state.get_item_index());
+    run_app_action(&mut state, Action::Switch);
+    state.add_input('b');
+    run_app_action(&mut state, Action::Add);
+    assert_eq!(Some(0), state.get_item_index());
+
+    // deleting the only list clears the selection
+    state.navigate_state();
+    run_app_action(&mut state, Action::Delete);
+    assert_eq!(None, state.get_list_index());
+    assert_eq!(None, state.get_item_index());
+}
+
+#[test]
+fn test_list_cursors(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    let mut state = State::new(User::new(1, String::from("alice"), String::from("secret")), database);
+
+    // two lists, three items in the first and one in the second
+    state.capture_input_state();
+    for name in ['a', 'b']{
+        state.add_input(name);
+        run_app_action(&mut state, Action::Add);
+    }
+    run_app_action(&mut state, Action::Switch);
+    for name in ['x', 'y', 'z']{
+        state.add_input(name);
+        run_app_action(&mut state, Action::Add);
+    }
+    state.navigate_state();
+    run_app_action(&mut state, Action::Next);
+    run_app_action(&mut state, Action::Switch);
+    assert_eq!(None, state.get_item_index());
+    run_app_action(&mut state, Action::Switch);
+    run_app_action(&mut state, Action::Prev);
+
+    // select the last item, switch away and back
+    run_app_action(&mut state, Action::Switch);
+    run_app_action(&mut state, Action::Last);
+    assert_eq!(Some(2), state.get_item_index());
+    run_app_action(&mut state, Action::Switch);
+    run_app_action(&mut state, Action::Next);
+    assert_eq!(Some(1), state.get_list_index());
+    assert_eq!(None, state.get_item_index());
+    run_app_action(&mut state, Action::Prev);
+    assert_eq!(Some(2), state.get_item_index());
+
+    // the remembered item is clamped after deletes
+    run_app_action(&mut state, Action::Switch);
+    run_app_action(&mut state, Action::Delete);
+    assert_eq!(Some(1), state.get_item_index());
+    run_app_action(&mut state, Action::Toggle);
+    assert!(state.todo_lists[0].get_item_complete_status(1));
+}
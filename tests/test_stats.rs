@@ -0,0 +1,27 @@
+use todo_list_rust::stats::stats::{fill_days, completion_ratio, STATS_DAYS};
+use todo_list_rust::database::database::QueryDayCount;
+use chrono::NaiveDate;
+
+#[test]
+fn test_fill_days(){
+    let since = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+    let days = vec![
+        QueryDayCount{day: String::from("2026-10-01"), count: 2},
+        QueryDayCount{day: String::from("2026-10-03"), count: 5},
+        // outside the range or not a date
+        QueryDayCount{day: String::from("2026-09-30"), count: 9},
+        QueryDayCount{day: String::from("2026-12-01"), count: 9},
+        QueryDayCount{day: String::from("nope"), count: 9},
+    ];
+    let counts = fill_days(&days, since);
+    assert_eq!(STATS_DAYS, counts.len());
+    assert_eq!(vec![2, 0, 5, 0], counts[0..4].to_vec());
+    assert_eq!(7, counts.iter().sum::<u64>());
+}
+
+#[test]
+fn test_completion_ratio(){
+    assert_eq!(0.0, completion_ratio(0, 0));
+    assert_eq!(0.5, completion_ratio(1, 2));
+    assert_eq!(1.0, completion_ratio(3, 3));
+}
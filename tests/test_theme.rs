@@ -0,0 +1,49 @@
+use todo_list_rust::theme::theme::{Theme, parse_color};
+use todo_list_rust::config::config::{parse_config, ConfigError};
+use tui::style::{Color, Modifier};
+
+#[test]
+fn test_parse_color(){
+    assert_eq!(Some(Color::Red), parse_color("red"));
+    assert_eq!(Some(Color::LightBlue), parse_color("Light_Blue"));
+    assert_eq!(Some(Color::Rgb(255, 136, 0)), parse_color("#ff8800"));
+    assert_eq!(Some(Color::Indexed(208)), parse_color("208"));
+    assert_eq!(None, parse_color("#ff88"));
+    assert_eq!(None, parse_color("#gggggg"));
+    assert_eq!(None, parse_color("256"));
+    assert_eq!(None, parse_color("purple"));
+}
+
+#[test]
+fn test_theme_config(){
+    // default is the light theme
+    let config = parse_config("").unwrap();
+    assert_eq!(Theme::light(), Theme::from_config(&config.theme, false).unwrap());
+
+    // built in theme with a custom color
+    let config = parse_config("
+        [theme]
+        name = \"dark\"
+
+        [theme.colors]
+        highlight_bg = \"#112233\"
+    ").unwrap();
+    let theme = Theme::from_config(&config.theme, false).unwrap();
+    assert_eq!(Color::Black, theme.background);
+    assert_eq!(Color::Rgb(0x11, 0x22, 0x33), theme.highlight_bg);
+    assert_eq!(Some(Color::Rgb(0x11, 0x22, 0x33)), theme.highlight().bg);
+
+    // NO_COLOR uses modifiers instead of colors
+    let theme = Theme::from_config(&config.theme, true).unwrap();
+    assert_eq!(Theme::no_color(), theme);
+    assert_eq!(None, theme.highlight().bg);
+    assert!(theme.highlight().add_modifier.contains(Modifier::REVERSED));
+
+    // errors
+    let config = parse_config("[theme]\nname = \"neon\"").unwrap();
+    assert!(matches!(Theme::from_config(&config.theme, false), Err(ConfigError::UnknownTheme(_))));
+    let config = parse_config("[theme.colors]\nborder = \"red\"").unwrap();
+    assert!(matches!(Theme::from_config(&config.theme, false), Err(ConfigError::UnknownColorName(_))));
+    let config = parse_config("[theme.colors]\nbackground = \"purple\"").unwrap();
+    assert!(matches!(Theme::from_config(&config.theme, true), Err(ConfigError::UnknownColor(_))));
+}
@@ -1,4 +1,6 @@
-use todo_list_rust::todo::todo::TodoList;
+use todo_list_rust::todo::todo::{TodoList, parse_list_input};
+use todo_list_rust::item_view::item_view::{ItemFilter, ItemSort};
+use chrono::NaiveDate;
 
 #[test]
 fn test_todo(){
@@ -50,4 +52,93 @@ fn test_todo(){
     
     //set_item_complete
     //gets
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_todo_view(){
+    let mut test_todo: TodoList = TodoList::new("view".to_string(), 1);
+    test_todo.add(String::from("charlie"), 0);
+    test_todo.add(String::from("alpha"), 1);
+    test_todo.add(String::from("bravo"), 2);
+    test_todo.set_item_complete(1);
+    test_todo.list[0].set_priority(1);
+    test_todo.list[2].set_priority(3);
+    test_todo.list[0].set_due(NaiveDate::from_ymd_opt(2026, 1, 2));
+    test_todo.list[1].set_due(NaiveDate::from_ymd_opt(2026, 1, 1));
+
+    // default shows every item in insertion order
+    assert_eq!(ItemFilter::All, test_todo.get_filter());
+    assert_eq!(ItemSort::Insertion, test_todo.get_sort());
+    assert_eq!(vec![0, 1, 2], test_todo.visible_indexes());
+
+    // filters
+    test_todo.set_filter(ItemFilter::HideCompleted);
+    assert_eq!(vec![0, 2], test_todo.visible_indexes());
+    assert_eq!(2, test_todo.get_visible_len());
+    test_todo.set_filter(ItemFilter::OnlyCompleted);
+    assert_eq!(vec![1], test_todo.visible_indexes());
+    assert_eq!(1, test_todo.get_visible_len());
+    test_todo.set_filter(ItemFilter::All);
+
+    // sorts
+    test_todo.set_sort(ItemSort::Alphabetical);
+    assert_eq!(vec![1, 2, 0], test_todo.visible_indexes());
+    test_todo.set_sort(ItemSort::Due);
+    assert_eq!(vec![1, 0, 2], test_todo.visible_indexes());
+    test_todo.set_sort(ItemSort::Priority);
+    assert_eq!(vec![2, 0, 1], test_todo.visible_indexes());
+
+    // filter and sort together
+    test_todo.set_filter(ItemFilter::HideCompleted);
+    test_todo.set_sort(ItemSort::Alphabetical);
+    assert_eq!(vec![2, 0], test_todo.visible_indexes());
+
+    // cycling wraps and codes round trip
+    assert_eq!(ItemFilter::All, ItemFilter::OnlyCompleted.next());
+    assert_eq!(ItemSort::Insertion, ItemSort::Priority.next());
+    assert_eq!(ItemSort::Due, ItemSort::from_code(ItemSort::Due.to_code()));
+    assert_eq!(ItemFilter::HideCompleted, ItemFilter::from_code(ItemFilter::HideCompleted.to_code()));
+}
+
+#[test]
+fn test_todo_board(){
+    let mut test_todo: TodoList = TodoList::new("board".to_string(), 300);
+    test_todo.add("a".to_string(), 1);
+    test_todo.add("b".to_string(), 2);
+    test_todo.add("c".to_string(), 3);
+
+    // new items start in the first of the default columns
+    assert_eq!(vec!["Todo", "Doing", "Done"], test_todo.get_columns());
+    assert_eq!(vec![0, 1, 2], test_todo.column_indexes(0));
+
+    // moving to the last column completes an item
+    assert!(test_todo.move_item(0, 1));
+    assert!(!test_todo.get_item_complete_status(0));
+    assert!(test_todo.move_item(1, 2));
+    assert!(test_todo.get_item_complete_status(1));
+    assert!(!test_todo.move_item(1, 3));
+    assert_eq!(vec![0], test_todo.column_indexes(1));
+    assert_eq!(vec![1], test_todo.column_indexes(2));
+
+    // checking off moves to the done column and back to the first
+    test_todo.set_item_complete(2);
+    assert_eq!(2, test_todo.list[2].get_status());
+    test_todo.set_item_complete(2);
+    assert_eq!(0, test_todo.list[2].get_status());
+
+    // fewer columns keep done items done and open items open
+    assert!(!test_todo.set_columns(vec!["Only".to_string()]));
+    assert!(test_todo.set_columns(vec!["Open".to_string(), "Closed".to_string()]));
+    assert_eq!(0, test_todo.list[0].get_status());
+    assert_eq!(1, test_todo.list[1].get_status());
+    assert!(test_todo.get_item_complete_status(1));
+}
+
+#[test]
+fn test_parse_list_input(){
+    assert_eq!(("work".to_string(), Some(vec!["A".to_string(), "B".to_string(), "C".to_string()])), parse_list_input("work cols:A,B,C"));
+    assert_eq!(("".to_string(), Some(vec!["Open".to_string(), "Done".to_string()])), parse_list_input("cols:Open,Done"));
+    // one column is not a board
+    assert_eq!(("work cols:A".to_string(), None), parse_list_input("work cols:A"));
+    assert_eq!(("work".to_string(), None), parse_list_input("work"));
+}
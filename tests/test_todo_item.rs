@@ -1,4 +1,5 @@
-use todo_list_rust::todo_item::todo_item::TodoItem;
+use todo_list_rust::todo_item::todo_item::{TodoItem, parse_item_input, MAX_PRIORITY};
+use chrono::NaiveDate;
 
 
 #[test]
@@ -18,3 +19,40 @@ fn test_todo_item(){
     assert_eq!(false, item_two.get_complete());
 }
 
+
+
+#[test]
+fn test_todo_item_details(){
+    let mut item: TodoItem = TodoItem::new(String::from("item"), 1);
+    assert!(item.get_created().is_some());
+    assert_eq!(None, item.get_due());
+    assert_eq!(0, item.get_priority());
+
+    item.set_due(NaiveDate::from_ymd_opt(2026, 10, 20));
+    assert_eq!(NaiveDate::from_ymd_opt(2026, 10, 20), item.get_due());
+    // priority is capped
+    item.set_priority(10);
+    assert_eq!(MAX_PRIORITY, item.get_priority());
+
+    let loaded: TodoItem = TodoItem::new_from_load(String::from("loaded"), 2, false);
+    assert_eq!(None, loaded.get_created());
+}
+
+#[test]
+fn test_parse_item_input(){
+    let (name, due, priority) = parse_item_input("buy milk due:2026-10-20 p:2");
+    assert_eq!("buy milk", name);
+    assert_eq!(NaiveDate::from_ymd_opt(2026, 10, 20), due);
+    assert_eq!(2, priority);
+
+    // invalid dates and priorities stay in the name
+    let (name, due, priority) = parse_item_input("due:tomorrow p:high call mom");
+    assert_eq!("due:tomorrow p:high call mom", name);
+    assert_eq!(None, due);
+    assert_eq!(0, priority);
+
+    // priority is capped
+    let (name, _due, priority) = parse_item_input("p:9 urgent");
+    assert_eq!("urgent", name);
+    assert_eq!(MAX_PRIORITY, priority);
+}
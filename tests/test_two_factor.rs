@@ -0,0 +1,102 @@
+use todo_list_rust::database::database::TodoDatabase;
+use todo_list_rust::two_factor::two_factor::{code_at, code_step, otpauth_uri, new_secret, new_recovery_codes, hash_recovery_code, enable, disable, verify, TwoFactorError, RECOVERY_CODES};
+
+// the RFC 6238 test secret "12345678901234567890" as base32
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+#[test]
+fn test_rfc_6238_codes(){
+    // the last 6 digits of the SHA1 test vectors
+    assert_eq!("287082", code_at(RFC_SECRET, 59).unwrap());
+    assert_eq!("081804", code_at(RFC_SECRET, 1111111109).unwrap());
+    assert_eq!("050471", code_at(RFC_SECRET, 1111111111).unwrap());
+    assert_eq!("005924", code_at(RFC_SECRET, 1234567890).unwrap());
+    assert_eq!("279037", code_at(RFC_SECRET, 2000000000).unwrap());
+
+    assert!(matches!(code_at("not base32!", 59), Err(TwoFactorError::Secret(_))));
+}
+
+#[test]
+fn test_code_step(){
+    let time: u64 = 1234567890;
+    let step: u64 = time / 30;
+    let code = code_at(RFC_SECRET, time).unwrap();
+
+    assert_eq!(Some(step), code_step(RFC_SECRET, &code, time));
+    assert_eq!(Some(step), code_step(RFC_SECRET, "005 924", time));
+    // a clock one step off is accepted, two steps off is not
+    assert_eq!(Some(step), code_step(RFC_SECRET, &code, time + 30));
+    assert_eq!(Some(step), code_step(RFC_SECRET, &code, time - 30));
+    assert_eq!(None, code_step(RFC_SECRET, &code, time + 60));
+    assert_eq!(None, code_step(RFC_SECRET, "000000", time));
+}
+
+#[test]
+fn test_secret_and_uri(){
+    let secret = new_secret();
+    // 20 bytes is 32 base32 chars
+    assert_eq!(32, secret.len());
+    assert_ne!(secret, new_secret());
+
+    let uri = otpauth_uri(RFC_SECRET, "alice").unwrap();
+    assert!(uri.starts_with("otpauth://totp/todo_list_rust:alice?"));
+    assert!(uri.contains(&format!("secret={}", RFC_SECRET)));
+    assert!(uri.contains("issuer=todo_list_rust"));
+}
+
+#[test]
+fn test_recovery_codes(){
+    let codes = new_recovery_codes();
+    assert_eq!(RECOVERY_CODES, codes.len());
+    assert_eq!(19, codes[0].len());
+    assert_ne!(codes[0], codes[1]);
+
+    // dashes, spaces and case are ignored
+    assert_eq!(hash_recovery_code("3f2a-9c41-07be-d5e8"), hash_recovery_code("3F2A 9C41 07BE D5E8"));
+    assert_ne!(hash_recovery_code("3f2a-9c41-07be-d5e8"), hash_recovery_code("3f2a-9c41-07be-d5e9"));
+}
+
+#[test]
+fn test_enable_verify(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let time: u64 = 1234567890;
+
+    assert!(matches!(verify(&database, 1, "005924", time), Err(TwoFactorError::NotEnabled)));
+    assert!(matches!(enable(&database, 1, RFC_SECRET, "000000", time), Err(TwoFactorError::WrongCode)));
+    assert!(database.get_two_factor(1).unwrap().is_none());
+
+    let codes = enable(&database, 1, RFC_SECRET, "005924", time).unwrap();
+    assert_eq!(RECOVERY_CODES as u32, database.count_recovery_codes(1).unwrap());
+    assert_eq!(RFC_SECRET, database.get_two_factor(1).unwrap().unwrap().secret);
+
+    // the code used to turn it on cannot log in, nor can any code again
+    assert!(matches!(verify(&database, 1, "005924", time), Err(TwoFactorError::WrongCode)));
+    let next = code_at(RFC_SECRET, time + 30).unwrap();
+    verify(&database, 1, &next, time + 30).unwrap();
+    assert!(matches!(verify(&database, 1, &next, time + 30), Err(TwoFactorError::WrongCode)));
+
+    // each recovery code works once
+    verify(&database, 1, &codes[0].to_uppercase(), time + 30).unwrap();
+    assert!(matches!(verify(&database, 1, &codes[0], time + 30), Err(TwoFactorError::WrongCode)));
+    assert_eq!(RECOVERY_CODES as u32 - 1, database.count_recovery_codes(1).unwrap());
+}
+
+#[test]
+fn test_disable(){
+    let database = TodoDatabase::new(String::from(":memory:"));
+    database.create_user_account("alice", "secret", 1).unwrap();
+    let time: u64 = 1234567890;
+    let codes = enable(&database, 1, RFC_SECRET, "005924", time).unwrap();
+
+    assert!(matches!(disable(&database, 1, "000000", time), Err(TwoFactorError::WrongCode)));
+    disable(&database, 1, &codes[1], time).unwrap();
+    assert!(database.get_two_factor(1).unwrap().is_none());
+    assert_eq!(0, database.count_recovery_codes(1).unwrap());
+    assert!(matches!(disable(&database, 1, &codes[2], time), Err(TwoFactorError::NotEnabled)));
+
+    // deleting the account removes the codes
+    enable(&database, 1, RFC_SECRET, &code_at(RFC_SECRET, time + 60).unwrap(), time + 60).unwrap();
+    database.remove_user(1).unwrap();
+    assert_eq!(0, database.count_recovery_codes(1).unwrap());
+}